@@ -4,12 +4,14 @@
 //!
 //! ```rust
 //! use fixed::types::U34F30;
-//! use fixed_exp::FixedPowF;
+//! use solana_pastel_oracle_program::fixed_exp::FixedPowF;
 //!
 //! let x = U34F30::from_num(4.0);
 //! assert_eq!(U34F30::from_num(8.0), x.powf(U34F30::from_num(1.5)));
 //! ```
 
+#![allow(clippy::multiple_bound_locations, clippy::wrong_self_convention)]
+
 use std::cmp::{Ord, Ordering};
 
 use fixed::traits::Fixed;
@@ -29,7 +31,7 @@ pub trait FixedPowF: Fixed {
     ///
     /// ```rust
     /// use fixed::types::U34F30;
-    /// use fixed_exp::FixedPowF;
+    /// use solana_pastel_oracle_program::fixed_exp::FixedPowF;
     ///
     /// let x = U34F30::from_num(4.0);
     /// assert_eq!(U34F30::from_num(8.0), x.powf(U34F30::from_num(1.5)));
@@ -50,8 +52,11 @@ where
         if n & 1 == 1 {
             acc *= x;
         }
-        x *= x;
         n >>= 1;
+        // Squaring past the highest set bit would only risk overflow
+        if n > 0 {
+            x *= x;
+        }
     }
     acc
 }
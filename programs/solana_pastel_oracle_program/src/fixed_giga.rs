@@ -1,4 +1,5 @@
 use crate::big_number::CheckedMulDiv;
+use crate::fixed_exp::FixedPowF;
 use fixed::types::U34F30;
 
 pub const ZERO: u64 = 0;
@@ -7,6 +8,8 @@ pub const TWO: u64 = 2_000_000_000;
 
 pub const BITS_ONE: u64 = 0x40000000; // 1 << 30
 
+pub const LN_2: u64 = 693_147_180; // ln(2), rounded down
+
 pub trait FixedPow<RHS = Self> {
     /// Output type for the methods of this trait.
     type Output;
@@ -59,6 +62,13 @@ pub trait FixedComplement<RHS = Self> {
     fn complement(self) -> Self::Output;
 }
 
+pub trait FixedLn<RHS = Self> {
+    /// Output type for the methods of this trait.
+    type Output;
+
+    fn ln_down(self) -> Self::Output;
+}
+
 pub trait ToFixedGiga<RHS = Self> {
     /// Output type for the methods of this trait.
     type Output;
@@ -73,7 +83,7 @@ impl FixedPow for u64 {
         match rhs {
             ZERO => ONE,
             ONE => self,
-            TWO => self * self,
+            TWO => self.mul_down(self),
             // _ => {
             //     let base = U34F30::from_bits(self.mul_down(BITS_ONE));
             //     let exp = U34F30::from_bits(rhs.mul_down(BITS_ONE));
@@ -89,7 +99,7 @@ impl FixedPow for u64 {
         match rhs {
             ZERO => ONE,
             ONE => self,
-            TWO => self * self,
+            TWO => self.mul_up(self),
             // _ => {
             //     let base = U34F30::from_bits(self.mul_up(BITS_ONE));
             //     let exp = U34F30::from_bits(rhs.mul_up(BITS_ONE));
@@ -102,6 +112,11 @@ impl FixedPow for u64 {
     }
 }
 
+// Integer-only through fixed_exp, so every validator computes bit-identical results
+fn exp_bits(exponent_bits: u64) -> u64 {
+    U34F30::E.powf(U34F30::from_bits(exponent_bits)).to_bits()
+}
+
 impl FixedExp for u64 {
     type Output = u64;
 
@@ -109,13 +124,7 @@ impl FixedExp for u64 {
         match self {
             ZERO => ONE,
             ONE => U34F30::E.to_bits().div_down(BITS_ONE),
-            // _ => U34F30::E
-            //     .powf(U34F30::from_bits(self.mul_down(BITS_ONE)))
-            //     .to_bits()
-            //     .div_down(BITS_ONE),
-            _ => U34F30::from_num((self as f64 / 1e9).exp())
-                .to_bits()
-                .div_down(BITS_ONE),
+            _ => exp_bits(self.mul_down(BITS_ONE)).div_down(BITS_ONE),
         }
     }
 
@@ -123,13 +132,7 @@ impl FixedExp for u64 {
         match self {
             ZERO => ONE,
             ONE => U34F30::E.to_bits().div_up(BITS_ONE),
-            // _ => U34F30::E
-            //     .powf(U34F30::from_bits(self.div_up(BITS_ONE)))
-            //     .to_bits()
-            //     .div_up(BITS_ONE),
-            _ => U34F30::from_num((self as f64 / 1e9).exp())
-                .to_bits()
-                .div_up(BITS_ONE),
+            _ => exp_bits(self.mul_up(BITS_ONE)).div_up(BITS_ONE),
         }
     }
 }
@@ -140,18 +143,14 @@ impl FixedNegExp for u64 {
     fn neg_exp_down(self) -> Self::Output {
         match self {
             ZERO => ONE,
-            _ => U34F30::from_num((-(self as f64) / 1e9).exp())
-                .to_bits()
-                .div_down(BITS_ONE),
+            _ => ONE.div_down(self.exp_up()),
         }
     }
 
     fn neg_exp_up(self) -> Self::Output {
         match self {
             ZERO => ONE,
-            _ => U34F30::from_num((-(self as f64) / 1e9).exp())
-                .to_bits()
-                .div_up(BITS_ONE),
+            _ => ONE.div_up(self.exp_down()),
         }
     }
 }
@@ -188,6 +187,34 @@ impl FixedComplement for u64 {
     }
 }
 
+// Integer-only binary logarithm: whole bits by halving into [1, 2), then one fractional bit per squaring.
+// Arguments below ONE saturate to ZERO, since callers only need non-negative logarithms.
+impl FixedLn for u64 {
+    type Output = u64;
+
+    fn ln_down(self) -> Self::Output {
+        if self <= ONE {
+            return ZERO;
+        }
+        let mut log2 = ZERO;
+        let mut y = self;
+        while y >= TWO {
+            y /= 2;
+            log2 += ONE;
+        }
+        let mut bit = ONE / 2;
+        while bit > 0 {
+            y = y.mul_down(y);
+            if y >= TWO {
+                y /= 2;
+                log2 += bit;
+            }
+            bit /= 2;
+        }
+        log2.mul_down(LN_2)
+    }
+}
+
 impl ToFixedGiga for u64 {
     type Output = u64;
 
@@ -215,6 +242,24 @@ mod tests {
         println!("{}", 5.0_f64.exp());
     }
 
+    #[test]
+    fn test_pow_of_two_stays_fixed_point() {
+        assert_eq!(990000000u64.pow_down(TWO), 980100000);
+        assert_eq!(990000000u64.pow_up(TWO), 980100000);
+        assert_eq!(TWO.pow_up(TWO), 4_000000000);
+    }
+
+    #[test]
+    fn test_ln_down() {
+        assert_eq!(ONE.ln_down(), ZERO);
+        assert_eq!(500000000u64.ln_down(), ZERO);
+        for n in [2u64, 10, 100, 1_000, 1_000_000] {
+            let expected = (n as f64).ln() * 1e9;
+            let actual = n.to_fixed_giga().ln_down() as f64;
+            assert!(actual <= expected && expected - actual < 100.0, "ln({})", n);
+        }
+    }
+
     #[test]
     fn test_neg_exp() {
         println!("{}", 5_000000000u64.neg_exp_up());
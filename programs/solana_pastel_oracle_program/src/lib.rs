@@ -4,12 +4,14 @@ pub mod fixed_giga;
 
 use crate::fixed_giga::*;
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::hash::{hash, Hash};
+use anchor_lang::solana_program::hash::{hash, hashv, Hash};
 use anchor_lang::solana_program::sysvar::clock::Clock;
-use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::solana_program::sysvar::slot_hashes;
+use anchor_lang::system_program::{self, transfer, Transfer};
+use anchor_lang::Discriminator;
 
 const REGISTRATION_ENTRANCE_FEE_IN_LAMPORTS: u64 = 10_000_000; // 0.10 SOL in lamports
-const MIN_NUMBER_OF_ORACLES: usize = 8; // Minimum number of oracles to calculate consensus
+const MIN_NUMBER_OF_ORACLES: u32 = 8; // Default minimum number of oracles to calculate consensus
 const MIN_REPORTS_FOR_REWARD: u32 = 10; // Data Contributor must submit at least 10 reports to be eligible for rewards
 const BASE_REWARD_AMOUNT_IN_LAMPORTS: u64 = 100_000; // 0.0001 SOL in lamports is the base reward amount, which is scaled based on the number of highly reliable contributors
 const COST_IN_LAMPORTS_OF_ADDING_PASTEL_TXID_FOR_MONITORING: u64 = 100_000; // 0.0001 SOL in lamports
@@ -19,10 +21,62 @@ const TEMPORARY_BAN_THRESHOLD: u32 = 5; // Number of non-consensus report submis
 const CONTRIBUTIONS_FOR_TEMPORARY_BAN: u32 = 50; // Considered for temporary ban after 50 contributions
 const TEMPORARY_BAN_DURATION: u32 = 24 * 60 * 60; // Duration of temporary ban in seconds (e.g., 1 day)
 const MAX_DURATION_IN_SECONDS_FROM_LAST_REPORT_SUBMISSION_BEFORE_COMPUTING_CONSENSUS: u32 = 10 * 60; // Maximum duration in seconds from last report submission for a given TXID before computing consensus (e.g., 10 minutes)
-const DATA_RETENTION_PERIOD: u32 = 24 * 60 * 60; // How long to keep data in the contract state (1 day)
-const SUBMISSION_COUNT_RETENTION_PERIOD: u32 = 24 * 60 * 60; // Number of seconds to retain submission counts (i.e., 24 hours)
+const DATA_RETENTION_PERIOD: u32 = 24 * 60 * 60; // Default time to keep temp reports and aggregated consensus data (1 day)
+const SUBMISSION_COUNT_RETENTION_PERIOD: u32 = 24 * 60 * 60; // Default number of seconds to retain submission counts (i.e., 24 hours)
 const TXID_STATUS_VARIANT_COUNT: usize = 4; // Manually define the number of variants in TxidStatus
+const PASTEL_TICKET_TYPE_VARIANT_COUNT: usize = 4; // Manually define the number of variants in PastelTicketType
 const MAX_TXID_LENGTH: usize = 64; // Maximum length of a TXID
+const MAX_TRANSACTION_SIGNATURE_LENGTH: usize = 88; // Maximum length of a base58-encoded transaction signature
+pub const CURRENT_SCHEMA_VERSION: u16 = 11; // Layout version of the oracle accounts this build reads and writes; 0 predates versioning
+const MONITORED_TXID_SPACE: usize = 4 + MAX_TXID_LENGTH; // Serialized size of the longest monitored TXID, length prefix included
+const ADMIN_AUDIT_LOG_CAPACITY: usize = 64; // Number of admin audit records kept before the oldest are evicted
+const MAX_AUDIT_PARAM_SUMMARY_LENGTH: usize = 64; // Maximum length of the parameter summary stored in an admin audit record
+const MAX_AUDIT_LOG_PAGE_SIZE: u32 = 16; // Maximum number of admin audit records returned per page
+const REALLOCATION_THRESHOLD: f32 = 0.9; // Reallocate data accounts once they are 90% full
+const REALLOCATION_INCREMENT: usize = 10_240; // Bytes added per reallocation
+const INITIAL_ACCOUNT_SPACE: usize = 10_240; // Bytes allocated for each account created by initialize
+const INITIALIZED_ACCOUNT_COUNT: u64 = 7; // The oracle state plus the six PDAs created by initialize
+                                          // Machine-parseable consensus log line for indexers that read logs rather than events:
+                                          // CONSENSUS|<txid>|<status>|<hash, empty if none>|<contributor count>|<unix timestamp>
+pub const CONSENSUS_LOG_PREFIX: &str = "CONSENSUS";
+pub const CONSENSUS_LOG_SEPARATOR: char = '|';
+const MAX_REALLOCATED_ACCOUNT_SIZE: usize = 100 * 1024; // Accounts are never grown beyond 100KB
+const DEFAULT_HASH_MISMATCH_BAN_THRESHOLD: u32 = 25; // Consecutive consensus hash mismatches that trigger a permanent ban
+const MAX_LOGISTIC_EXPONENT: u64 = 20_000000000; // 20.0; keeps e^x within the range of the fixed-point exp
+const MAX_WEIGHT_COEFFICIENT: u64 = 10_000000000; // 10.0; upper bound on each report weight coefficient
+const MAX_STAKE_WEIGHT: u64 = 1_000_000_000_000; // 1000.0; upper bound on a contributor's stake weight
+const MAX_EARLY_BIRD_MULTIPLIER: u64 = 3_000000000; // 3.0; upper bound on the configurable early-bird weight multiplier
+const AGGREGATED_WEIGHT_SCALE: u64 = 100; // Report weights are scaled by this factor when aggregated
+const MIN_HEARTBEAT_INTERVAL: u32 = 60 * 60; // Contributors may send at most one liveness heartbeat per hour
+const MAX_RELIABILITY_BONUS_FACTOR: u64 = 3_000000000; // 3.0; upper bound on the reward multiplier for reliable contributors
+const DEFAULT_REPORT_COUNT_REWARD_CAP: u64 = 5_000000000; // 5.0; the report-count multiplier stops growing after about 55 reports
+const MAX_REPORT_COUNT_REWARD_CAP: u64 = 10_000000000; // 10.0; upper bound on the configurable report-count reward cap
+const MAX_FINALIZATION_BOUNTY_LAMPORTS: u64 = BASE_REWARD_AMOUNT_IN_LAMPORTS; // A finalization never pays more than a base reward
+const MAX_SUBMISSION_FEE_LAMPORTS: u64 = BASE_REWARD_AMOUNT_IN_LAMPORTS; // A report never costs more than a base reward
+const DEFAULT_COUNTED_REPORTS_WINDOW: u32 = 60 * 60; // Reports counted toward eligibility are capped per hour once a cap is set
+const DEFAULT_MAX_AGGREGATION_AGE: u32 = 7 * 86_400; // A txid still unresolved a week after its first report is force-finalized
+const MAX_FEE_SPLIT_BPS: u16 = 10_000; // Basis points in 100%; the whole registration fee goes to the treasury
+const DEFAULT_CONTRIBUTOR_IDLE_PERIOD: u32 = 30 * 86_400; // Contributors silent for 30 days stop counting as active
+const DEFAULT_CONTRIBUTOR_ACTIVATION_DELAY: u32 = 86_400; // Contributors count toward quorum scaling a day after registering
+const MAX_BRIDGE_CONTRACTS: usize = 8; // Bridges authorized at once, enough to rotate or run several side by side
+const MAX_BLACKLISTED_ADDRESSES: usize = 64; // Reward addresses the admin can bar from registering at once
+const FILE_HASH_PREFIX_LENGTH: u8 = 6; // Reports carry the first 6 characters of the file's SHA3-256 hash
+const DEFAULT_STREAK_DECAY_WINDOW: u32 = 7 * 86_400; // A streak does not survive a week without activity
+const TICKET_TYPE_VOLUME_WINDOW: u32 = 86_400; // Per-ticket-type report volume is counted in day-long windows
+const DEFAULT_PROBATION_PERIOD: u32 = 7 * 86_400; // Unbanned contributors stay on probation for a week
+const DEFAULT_PAYMENT_EXPIRY_PERIOD: u32 = 7 * 86_400; // Monitoring requests left unpaid for a week can be expired
+const DEFAULT_SUBMISSION_QUIET_PERIOD: u32 = 60 * 60; // New contributors wait an hour after registering before their first report
+const MAX_UNDER_REPORTED_REWARD_BOOST: u64 = 3_000000000; // 3.0; upper bound on the participation reward multiplier for under-reported ticket types
+const DEFAULT_MAX_SUBMISSIONS_PER_TXID: u32 = 64; // Default cap on reports recorded for a single txid, bounding its temp report growth
+const DEFAULT_MAX_ACTIVE_REPORTS_PER_CONTRIBUTOR: u32 = 256; // Default cap on a contributor's reports awaiting consensus, bounding its temp report growth
+const RECENT_OUTCOMES_CAPACITY: usize = 20; // Consensus outcomes kept per contributor for recent-accuracy trends
+const CONSENSUS_RETRY_BASE_DELAY_SECONDS: u32 = 30; // Retry hint after the first consensus attempt for a txid that could not finalize
+const CONSENSUS_RETRY_MAX_DELAY_SECONDS: u32 = 60 * 60; // The retry hint doubles per failed attempt up to one hour
+const MAX_CONSENSUS_SUMMARIES: usize = 100; // Finalized consensus summaries kept before the oldest is evicted
+const MAX_ELIGIBILITY_RECOMPUTE_BATCH_SIZE: u32 = 100; // Maximum number of contributors re-evaluated per recompute_all_eligibility call
+const MAX_CONTRIBUTOR_SNAPSHOT_PAGE_SIZE: u32 = 50; // Maximum number of contributor standings emitted per snapshot page
+const MAX_ACTIVE_AGGREGATIONS_PAGE_SIZE: u32 = 25; // Maximum number of un-finalized aggregations emitted per page
+const MAX_BATCH_REGISTRATION_SIZE: usize = 32; // Maximum number of contributors an admin can onboard in one call
 
 const MIN_COMPLIANCE_SCORE_FOR_REWARD: u64 = 65_000000000; // Data Contributor must have a compliance score of at least 80 to be eligible for rewards
 const MIN_RELIABILITY_SCORE_FOR_REWARD: u64 = 80_000000000; // Minimum reliability score to be eligible for rewards
@@ -60,11 +114,64 @@ pub enum OracleError {
     ContributorNotRegistered,
     ContributorBanned,
     EnoughReportsSubmittedForTxid,
+    ConsensusAlreadyReached,
+    ReportNotFound,
+    MaxSizeExceeded,
+    TxidNotMonitored,
+    InvalidConfigValue,
+    ConflictingRoleAssignment,
+    OracleStateReallocationUnderfunded,
+    TempReportAccountMaxSizeExceeded,
+    TempReportAccountReallocationUnderfunded,
+    ContributorDataAccountMaxSizeExceeded,
+    ContributorDataAccountReallocationUnderfunded,
+    SubmissionCountsAccountMaxSizeExceeded,
+    SubmissionCountsAccountReallocationUnderfunded,
+    AggregatedConsensusDataAccountMaxSizeExceeded,
+    AggregatedConsensusDataAccountReallocationUnderfunded,
+    StringTooLong,
+    UnauthorizedSubmitter,
+    HeartbeatTooFrequent,
+    InvalidPoolAccountOwner,
+    ConsensusNotReady,
+    TooManySubmissionsForTxid,
+    NotInitialized,
+    RewardsPaused,
+    TooManyBridgeContracts,
+    BridgeContractNotFound,
+    InconsistentConsensusState,
+    DuplicateReport,
+    ContributorNotBanned,
+    PaymentNotExpired,
+    InvalidPaymentPayer,
+    BatchTooLarge,
+    WeightOverflow,
+    ContributorNotYetActive,
+    AddressBlacklisted,
+    TooManyBlacklistedAddresses,
+    BlacklistedAddressNotFound,
+    ZeroAmount,
+    SchemaVersionTooOld,
+    NoConsensusToReverse,
+    TooManyActiveReports,
+    DuplicatePoolAccounts,
+    ReportsAwaitingConsensus,
+}
+
+// TXIDs are hex, so the same TXID may arrive in mixed case; everything is keyed by the lowercase form
+pub fn normalize_txid(txid: &str) -> String {
+    txid.to_ascii_lowercase()
 }
 
 pub fn create_seed(seed_preamble: &str, txid: &str, reward_address: &Pubkey) -> Hash {
     // Concatenate the string representations. Reward address is Base58-encoded by default.
-    let preimage_string = format!("{}{}{}", seed_preamble, txid, reward_address);
+    // The TXID is normalized so that PDAs match the normalized TXID the instruction stores.
+    let preimage_string = format!(
+        "{}{}{}",
+        seed_preamble,
+        normalize_txid(txid),
+        reward_address
+    );
     // msg!("create_seed: generated preimage string: {}", preimage_string);
     // Convert the concatenated string to bytes
     let preimage_bytes = preimage_string.as_bytes();
@@ -82,6 +189,16 @@ pub enum TxidStatus {
     MinedActivated,
 }
 
+impl TxidStatus {
+    // Every status in ordinal order, i.e. the layout of `AggregatedConsensusData::status_weights`
+    pub const ALL: [TxidStatus; TXID_STATUS_VARIANT_COUNT] = [
+        TxidStatus::Invalid,
+        TxidStatus::PendingMining,
+        TxidStatus::MinedPendingActivation,
+        TxidStatus::MinedActivated,
+    ];
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Copy, AnchorSerialize, AnchorDeserialize)]
 pub enum PastelTicketType {
     Sense,
@@ -90,6 +207,42 @@ pub enum PastelTicketType {
     InferenceApi,
 }
 
+impl PastelTicketType {
+    // Every ticket type in ordinal order, i.e. the layout of the per-ticket-type config arrays
+    pub const ALL: [PastelTicketType; PASTEL_TICKET_TYPE_VARIANT_COUNT] = [
+        PastelTicketType::Sense,
+        PastelTicketType::Cascade,
+        PastelTicketType::Nft,
+        PastelTicketType::InferenceApi,
+    ];
+}
+
+// Which parts of a report must match the consensus for the report to be considered accurate
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy, Default, AnchorSerialize, AnchorDeserialize)]
+pub enum AccuracyPolicy {
+    #[default]
+    StatusAndHash,
+    StatusOnly,
+    HashOnly,
+}
+
+// How a consensus is chosen between statuses (or hashes) that carry exactly the same weight
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy, Default, AnchorSerialize, AnchorDeserialize)]
+pub enum TieBreakStrategy {
+    #[default]
+    HighestOrdinal, // Highest status ordinal, lexicographically last hash
+    Lexicographic,      // Lowest status ordinal, lexicographically first hash
+    EarliestSubmission, // First submitted status or hash; equal timestamps fall back to HighestOrdinal
+}
+
+// How the accuracy reward paid to an eligible contributor is sized
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy, Default, AnchorSerialize, AnchorDeserialize)]
+pub enum RewardMode {
+    #[default]
+    Flat, // The base reward, scaled only by the reliability bonus
+    ScaledByReportCount, // Also multiplied by 1 + ln(total reports submitted), up to the configured cap
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, AnchorSerialize, AnchorDeserialize)]
 pub struct PastelTxStatusReport {
     pub txid: String,
@@ -113,6 +266,7 @@ pub struct SpecificReportData {
     pub contributor_reward_address: Pubkey,
     pub timestamp: u32,
     pub common_data_ref: u64, // Reference to CommonReportData
+    pub weight: u64, // Unscaled weight (including any early-bird boost) the report contributed to the aggregated consensus data
 }
 
 #[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
@@ -125,7 +279,87 @@ pub struct TempTxStatusReport {
 pub struct TempTxStatusReportAccount {
     pub reports: Vec<TempTxStatusReport>,
     pub common_reports: Vec<CommonReportData>,
-    pub specific_reports: Vec<SpecificReportData>,
+}
+
+impl CommonReportData {
+    // Borsh-serialized size, counting the strings' bytes and length prefixes and the options' tags
+    pub fn serialized_len(&self) -> usize {
+        4 + self.txid.len()
+            + 1 // txid_status
+            + 1 + self.pastel_ticket_type.map_or(0, |_| 1)
+            + 1 + self
+                .first_6_characters_of_sha3_256_hash_of_corresponding_file
+                .as_ref()
+                .map_or(0, |hash| 4 + hash.len())
+    }
+}
+
+impl TempTxStatusReport {
+    pub const SERIALIZED_LEN: usize = 8 // common_data_ref
+        + 32 // specific_data.contributor_reward_address
+        + 4 // specific_data.timestamp
+        + 8 // specific_data.common_data_ref
+        + 8; // specific_data.weight
+}
+
+impl TempTxStatusReportAccount {
+    // Reports by a contributor still waiting for their txid's consensus; finalization removes them
+    pub fn active_report_count(&self, reward_address: &Pubkey) -> u32 {
+        self.reports
+            .iter()
+            .filter(|temp_report| {
+                temp_report.specific_data.contributor_reward_address == *reward_address
+            })
+            .count() as u32
+    }
+
+    // A common_data_ref past the end of common_reports (e.g. left by a migration) is reported as an
+    // error instead of panicking on the index
+    pub fn common_data_of(&self, temp_report: &TempTxStatusReport) -> Result<&CommonReportData> {
+        self.common_reports
+            .get(temp_report.common_data_ref as usize)
+            .ok_or_else(|| {
+                msg!(
+                    "Temp report from {} references missing common report data at index {}",
+                    temp_report.specific_data.contributor_reward_address,
+                    temp_report.common_data_ref
+                );
+                OracleError::InconsistentConsensusState.into()
+            })
+    }
+
+    fn reports_txid(&self, temp_report: &TempTxStatusReport, txid: &str) -> bool {
+        self.common_reports
+            .get(temp_report.common_data_ref as usize)
+            .is_some_and(|common_data| common_data.txid == txid)
+    }
+
+    // Removes temp reports that a finalization's cleanup left behind (e.g. because their common_data_ref
+    // pointed at another txid's common data), once the txid they reference no longer has aggregated data
+    // and they are past the retention period. Returns the number of reports removed.
+    pub fn sweep_orphaned_reports(
+        &mut self,
+        aggregated_data_account: &AggregatedConsensusDataAccount,
+        retention_period: u32,
+        current_timestamp: u32,
+    ) -> u32 {
+        let original_len = self.reports.len();
+        let common_reports = &self.common_reports;
+        self.reports.retain(|temp_report| {
+            let has_aggregated_data = common_reports
+                .get(temp_report.common_data_ref as usize)
+                .is_some_and(|common_data| {
+                    aggregated_data_account
+                        .consensus_data
+                        .iter()
+                        .any(|data| data.txid == common_data.txid)
+                });
+            has_aggregated_data
+                || current_timestamp.saturating_sub(temp_report.specific_data.timestamp)
+                    < retention_period
+        });
+        (original_len - self.reports.len()) as u32
+    }
 }
 
 #[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
@@ -133,6 +367,17 @@ pub struct TxidSubmissionCount {
     pub txid: String,
     pub count: u32,
     pub last_updated: u32,
+    pub quorum_reached_at: u32, // Unix timestamp of the submission that first met the quorum; 0 until then
+}
+
+impl TxidSubmissionCount {
+    // Borsh-serialized size, counting the txid's bytes and length prefix
+    pub fn serialized_len(&self) -> usize {
+        4 + self.txid.len()
+            + 4 // count
+            + 4 // last_updated
+            + 4 // quorum_reached_at
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
@@ -140,6 +385,8 @@ pub struct PendingPayment {
     pub txid: String,
     pub expected_amount: u64,
     pub payment_status: PaymentStatus,
+    pub payer: Pubkey, // Paid the account's rent, which is returned when the payment is resolved
+    pub created_at: u32, // Unix timestamp the payment was recorded; expiry counts from it
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
@@ -148,14 +395,68 @@ pub enum PaymentStatus {
     Received,
 }
 
+// How a pending payment account was settled when it was closed
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaymentResolution {
+    Refunded, // Still pending when its txid left monitoring
+    Closed,   // Received before its txid left monitoring
+    Expired,  // Left unpaid past the expiry period, which also ends the txid's monitoring
+}
+
+impl PendingPayment {
+    pub fn is_expired(
+        &self,
+        oracle_contract_state: &OracleContractState,
+        current_time: u32,
+    ) -> bool {
+        matches!(self.payment_status, PaymentStatus::Pending)
+            && oracle_contract_state.payment_expiry_period != 0
+            && current_time.saturating_sub(self.created_at)
+                >= oracle_contract_state.payment_expiry_period
+    }
+
+    pub fn resolution_on_removal(&self) -> PaymentResolution {
+        match self.payment_status {
+            PaymentStatus::Pending => PaymentResolution::Refunded,
+            PaymentStatus::Received => PaymentResolution::Closed,
+        }
+    }
+}
+
 #[account]
 pub struct PastelTxStatusReportAccount {
     pub report: PastelTxStatusReport,
+    pub last_submission_slot: u64, // Slot of the contributor's latest report for this txid; 0 before the first one
+}
+
+impl PastelTxStatusReportAccount {
+    // The report PDA is init_if_needed, so a relayed copy of a submission would otherwise land in the same slot
+    pub fn record_submission_slot(&mut self, current_slot: u64) -> Result<()> {
+        if self.last_submission_slot != 0 && self.last_submission_slot == current_slot {
+            msg!(
+                "A report for this txid was already submitted in slot {}",
+                current_slot
+            );
+            return Err(OracleError::DuplicateReport.into());
+        }
+        self.last_submission_slot = current_slot;
+        Ok(())
+    }
 }
 
 #[derive(Accounts)]
-#[instruction(txid: String, reward_address: Pubkey)]
+#[instruction(txid: String, txid_status: TxidStatus, pastel_ticket_type: PastelTicketType, first_6_characters_hash: String, contributor_reward_address: Pubkey)]
 pub struct SubmitDataReport<'info> {
+    // The quorum reads the oracle state, so its initialization is checked first
+    #[account(
+        mut,
+        seeds = [b"txid_submission_counts"],
+        bump,
+        constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized,
+        constraint = accepts_submission_now(&oracle_contract_state, &txid_submission_counts_account, &temp_report_account, &contributor_data_account, &txid)? @ OracleError::EnoughReportsSubmittedForTxid
+    )]
+    pub txid_submission_counts_account: Account<'info, TxidSubmissionCountsAccount>,
+
     #[account(
         init_if_needed,
         payer = user,
@@ -168,7 +469,11 @@ pub struct SubmitDataReport<'info> {
     #[account(mut)]
     pub oracle_contract_state: Account<'info, OracleContractState>,
 
-    #[account(mut)]
+    // Reports can only be submitted by the contributor they are attributed to
+    #[account(
+        mut,
+        constraint = user.key() == contributor_reward_address @ OracleError::UnauthorizedSubmitter
+    )]
     pub user: Signer<'info>,
 
     #[account(mut, seeds = [b"temp_tx_status_report"], bump)]
@@ -177,41 +482,116 @@ pub struct SubmitDataReport<'info> {
     #[account(mut, seeds = [b"contributor_data"], bump)]
     pub contributor_data_account: Account<'info, ContributorDataAccount>,
 
-    #[account(mut, seeds = [b"txid_submission_counts"], bump)]
-    pub txid_submission_counts_account: Account<'info, TxidSubmissionCountsAccount>,
-
     #[account(mut, seeds = [b"aggregated_consensus_data"], bump)]
     pub aggregated_consensus_data_account: Account<'info, AggregatedConsensusDataAccount>,
 
+    #[account(mut, seeds = [b"consensus_summary"], bump)]
+    pub consensus_summary_account: Account<'info, ConsensusSummaryAccount>,
+
+    /// CHECK: Canonical reward pool PDA holding lamports only, so it must stay owned by the system program; pays newly eligible contributors when auto-pay is on
+    #[account(mut, seeds = [b"reward_pool"], bump, owner = system_program::ID @ OracleError::InvalidPoolAccountOwner)]
+    pub reward_pool_account: UncheckedAccount<'info>,
+
+    /// CHECK: The SlotHashes sysvar, read raw because it is too large to deserialize; seeds audit selection
+    #[account(address = slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
+pub fn get_submission_count(
+    txid_submission_counts_account: &TxidSubmissionCountsAccount,
+    txid: &str,
+) -> u32 {
+    txid_submission_counts_account
+        .find_submission_count(txid)
+        .map_or(0, |c| c.count)
+}
+
+// Whether a txid still takes reports: until its quorum is met, and afterwards only while the consensus
+// cool-down is running, so late reports can join the round. process_data_report still enforces the
+// submission cap either way.
+pub fn accepts_submission(
+    state: &OracleContractState,
+    txid_submission_counts_account: &TxidSubmissionCountsAccount,
+    temp_report_account: &TempTxStatusReportAccount,
+    mature_contributor_count: usize,
+    txid: &str,
+    current_timestamp: u32,
+) -> bool {
+    let Some(submission_count) = txid_submission_counts_account.find_submission_count(txid) else {
+        return true;
+    };
+    let quorum = txid_quorum(state, temp_report_account, mature_contributor_count, txid);
+    submission_count.count < quorum
+        || (submission_count.quorum_reached_at != 0
+            && current_timestamp.saturating_sub(submission_count.quorum_reached_at)
+                < state.consensus_cool_down)
+}
+
+// accepts_submission as checked by the submission account constraints: reads the clock once, for both the
+// quorum scaling and the cool-down, and normalizes the txid as received
+pub fn accepts_submission_now(
+    state: &OracleContractState,
+    txid_submission_counts_account: &TxidSubmissionCountsAccount,
+    temp_report_account: &TempTxStatusReportAccount,
+    contributor_data_account: &ContributorDataAccount,
+    txid: &str,
+) -> Result<bool> {
+    let current_timestamp = Clock::get()?.unix_timestamp as u32;
+    Ok(accepts_submission(
+        state,
+        txid_submission_counts_account,
+        temp_report_account,
+        contributor_data_account.mature_contributor_count(state, current_timestamp),
+        &normalize_txid(txid),
+        current_timestamp,
+    ))
+}
+
+// Rejects a report once its txid has reached the configured submission cap, whatever the quorum
+pub fn check_submission_cap(
+    state: &OracleContractState,
+    txid_submission_counts_account: &TxidSubmissionCountsAccount,
+    txid: &str,
+) -> Result<()> {
+    if state.max_submissions_per_txid != 0
+        && get_submission_count(txid_submission_counts_account, txid)
+            >= state.max_submissions_per_txid
+    {
+        return Err(OracleError::TooManySubmissionsForTxid.into());
+    }
+    Ok(())
+}
+
+// A cap below the global quorum, or below any ticket type's quorum override, would keep the affected txids
+// from ever reaching consensus
+pub fn validate_max_submissions_per_txid(
+    state: &OracleContractState,
+    max_submissions_per_txid: u32,
+) -> Result<()> {
+    if max_submissions_per_txid == 0 {
+        return Ok(());
+    }
+    let highest_quorum = state
+        .ticket_type_quorums
+        .iter()
+        .copied()
+        .fold(state.min_quorum, u32::max);
+    if max_submissions_per_txid < highest_quorum {
+        return Err(OracleError::InvalidConfigValue.into());
+    }
+    Ok(())
+}
+
 fn update_submission_count(
-    txid_submission_counts_account: &mut Account<TxidSubmissionCountsAccount>,
+    txid_submission_counts_account: &mut TxidSubmissionCountsAccount,
     txid: &str,
 ) -> Result<()> {
     // Get the current timestamp
     let current_timestamp = Clock::get()?.unix_timestamp as u32;
 
-    // Check if the txid already exists in the submission counts
-    if let Some(count) = txid_submission_counts_account
-        .submission_counts
-        .iter_mut()
-        .find(|c| c.txid == txid)
-    {
-        // Update the existing count
-        count.count += 1;
-        count.last_updated = current_timestamp;
-    } else {
-        // Insert a new count if the txid does not exist
-        txid_submission_counts_account
-            .submission_counts
-            .push(TxidSubmissionCount {
-                txid: txid.to_string(),
-                count: 1,
-                last_updated: current_timestamp,
-            });
-    }
+    txid_submission_counts_account.record_submission(txid, current_timestamp);
 
     Ok(())
 }
@@ -232,8 +612,39 @@ pub fn get_report_account_pda(
     Pubkey::find_program_address(&[seed_hash.as_ref()], program_id)
 }
 
+// The report account PDA the submission instructions expect for a txid and contributor, so clients can
+// check their own create_seed + find_program_address derivation against it
+#[event]
+pub struct ReportPdaDerived {
+    pub txid: String,
+    pub contributor_reward_address: Pubkey,
+    pub report_account: Pubkey,
+    pub bump: u8,
+}
+
+#[derive(Accounts)]
+pub struct DeriveReportPda {}
+
+pub fn derive_report_pda_helper(
+    ctx: Context<DeriveReportPda>,
+    txid: String,
+    contributor_reward_address: Pubkey,
+) -> Result<()> {
+    let txid = normalize_txid(&txid);
+    validate_txid(&txid)?;
+    let (report_account, bump) =
+        get_report_account_pda(ctx.program_id, &txid, &contributor_reward_address);
+    emit!(ReportPdaDerived {
+        txid,
+        contributor_reward_address,
+        report_account,
+        bump,
+    });
+    Ok(())
+}
+
 fn get_aggregated_data<'a>(
-    aggregated_data_account: &'a Account<AggregatedConsensusDataAccount>,
+    aggregated_data_account: &'a AggregatedConsensusDataAccount,
     txid: &str,
 ) -> Option<&'a AggregatedConsensusData> {
     aggregated_data_account
@@ -242,29 +653,86 @@ fn get_aggregated_data<'a>(
         .find(|data| data.txid == txid)
 }
 
-fn compute_consensus(aggregated_data: &AggregatedConsensusData) -> (TxidStatus, String) {
-    let consensus_status = aggregated_data
+fn aggregation_exceeds_max_age(
+    oracle_contract_state: &OracleContractState,
+    aggregated_data_account: &AggregatedConsensusDataAccount,
+    txid: &str,
+    current_timestamp: u32,
+) -> bool {
+    get_aggregated_data(aggregated_data_account, txid).is_some_and(|data| {
+        data.exceeds_max_age(oracle_contract_state.max_aggregation_age, current_timestamp)
+    })
+}
+
+// Orders two equally weighted candidates given as (key, first submission timestamp); Greater means `a` wins
+fn tie_break_ordering<K: Ord>(
+    tie_break_strategy: TieBreakStrategy,
+    a: (K, u32),
+    b: (K, u32),
+) -> std::cmp::Ordering {
+    let by_key = a.0.cmp(&b.0);
+    match tie_break_strategy {
+        TieBreakStrategy::HighestOrdinal => by_key,
+        TieBreakStrategy::Lexicographic => by_key.reverse(),
+        TieBreakStrategy::EarliestSubmission => b.1.cmp(&a.1).then(by_key),
+    }
+}
+
+// Returns None when no status carries any weight (e.g. every report came from zero-weight contributors),
+// since the max over all-zero weights would otherwise commit a meaningless Invalid consensus
+fn compute_consensus(
+    aggregated_data: &AggregatedConsensusData,
+    tie_break_strategy: TieBreakStrategy,
+) -> Option<(TxidStatus, String)> {
+    if aggregated_data
         .status_weights
         .iter()
-        .enumerate()
-        .max_by_key(|&(_, weight)| weight)
-        .map(|(index, _)| usize_to_txid_status(index).unwrap_or(TxidStatus::Invalid))
+        .all(|&weight| weight == 0)
+    {
+        return None;
+    }
+
+    let consensus_status = TxidStatus::ALL
+        .iter()
+        .copied()
+        .max_by(|&a, &b| {
+            let (a, b) = (a as usize, b as usize);
+            aggregated_data.status_weights[a]
+                .cmp(&aggregated_data.status_weights[b])
+                .then_with(|| {
+                    tie_break_ordering(
+                        tie_break_strategy,
+                        (a, aggregated_data.status_first_seen[a]),
+                        (b, aggregated_data.status_first_seen[b]),
+                    )
+                })
+        })
         .unwrap();
 
     let consensus_hash = aggregated_data
         .hash_weights
         .iter()
-        .max_by_key(|hash_weight| hash_weight.weight)
+        .max_by(|a, b| {
+            a.weight.cmp(&b.weight).then_with(|| {
+                tie_break_ordering(
+                    tie_break_strategy,
+                    (a.hash.as_str(), a.first_seen),
+                    (b.hash.as_str(), b.first_seen),
+                )
+            })
+        })
         .map(|hash_weight| hash_weight.hash.clone())
         .unwrap_or_default();
 
-    (consensus_status, consensus_hash)
+    Some((consensus_status, consensus_hash))
 }
 
 fn apply_bans(contributor: &mut Contributor, current_timestamp: u32, is_accurate: bool) {
     if !is_accurate {
+        // A failure on probation counts double, so it may step over a multiple of the threshold
+        let failure_increment = contributor.consensus_failure_increment(current_timestamp);
         if contributor.total_reports_submitted <= CONTRIBUTIONS_FOR_TEMPORARY_BAN
-            && contributor.consensus_failures % TEMPORARY_BAN_THRESHOLD == 0
+            && contributor.consensus_failures % TEMPORARY_BAN_THRESHOLD < failure_increment
         {
             contributor.ban_expiry = current_timestamp + TEMPORARY_BAN_DURATION;
             msg!("Contributor: {} is temporarily banned as of {} because they have submitted {} reports and have {} consensus failures, more than the maximum allowed consensus failures of {}. Ban expires on: {}", 
@@ -279,10 +747,74 @@ fn apply_bans(contributor: &mut Contributor, current_timestamp: u32, is_accurate
     }
 }
 
-fn update_scores(contributor: &mut Contributor, current_timestamp: u32, is_accurate: bool) {
-    let time_diff = current_timestamp
-        .saturating_sub(contributor.last_active_timestamp)
-        .to_fixed_giga();
+// Accelerated ban path for contributors that keep reporting file hashes which never match consensus;
+// tracked separately from consensus_failures so it triggers well before the general permanent ban
+fn apply_hash_mismatch_ban(
+    contributor: &mut Contributor,
+    oracle_contract_state: &OracleContractState,
+    current_timestamp: u32,
+    hash_matches_consensus: Option<bool>,
+) {
+    match hash_matches_consensus {
+        Some(true) => contributor.distinct_hash_mismatches = 0,
+        Some(false) => {
+            contributor.distinct_hash_mismatches =
+                contributor.distinct_hash_mismatches.saturating_add(1);
+            let threshold = oracle_contract_state.hash_mismatch_ban_threshold;
+            if threshold > 0 && contributor.distinct_hash_mismatches >= threshold {
+                contributor.ban_expiry = u32::MAX;
+                msg!("Contributor: {} is permanently banned as of {} because they have submitted {} consecutive file hashes that did not match consensus, reaching the maximum allowed of {}. Removing from list of contributors!",
+                contributor.reward_address, current_timestamp, contributor.distinct_hash_mismatches, threshold);
+            }
+        }
+        None => {}
+    }
+}
+
+// Multiplier applied to the compliance score for inactivity: DECAY_RATE per day inactive. The base is
+// below ONE so the factor never exceeds ONE; the clamp guards against rounding up in pow_up.
+fn inactivity_decay_factor(hours_inactive: u64) -> u64 {
+    DECAY_RATE
+        .pow_up(hours_inactive.div_up(24_000000000))
+        .min(ONE)
+}
+
+// Multiplier applied to the reliability score for inactivity: DECAY_RATE per whole day since the
+// contributor was last seen
+fn reliability_decay_factor(seconds_inactive: u32) -> u64 {
+    match seconds_inactive / 86_400 {
+        0 => ONE,
+        days_inactive => DECAY_RATE.pow_down(days_inactive.to_fixed_giga()).min(ONE),
+    }
+}
+
+// Reliability before any inactivity decay: the share of accurate reports, scaled to 0..=100
+fn base_reliability_score(contributor: &Contributor) -> u64 {
+    contributor
+        .accurate_reports_count
+        .to_fixed_giga()
+        .div_down(contributor.total_reports_submitted.to_fixed_giga())
+        .clamp(ZERO, ONE)
+        * 100
+}
+
+fn update_scores(
+    contributor: &mut Contributor,
+    oracle_contract_state: &OracleContractState,
+    current_timestamp: u32,
+    is_accurate: bool,
+) {
+    let previous_compliance_score = contributor.compliance_score;
+    let seconds_inactive = current_timestamp.saturating_sub(contributor.last_active_timestamp);
+
+    // A streak built before a long gap in activity does not carry over into the bonus
+    if oracle_contract_state.streak_decay_window != 0
+        && seconds_inactive > oracle_contract_state.streak_decay_window
+    {
+        contributor.current_streak = 0;
+    }
+
+    let time_diff = seconds_inactive.to_fixed_giga();
     let hours_inactive = time_diff.div_up(3600_000000000);
     let current_streak = contributor.current_streak as u64 * ONE_TENTH;
 
@@ -305,8 +837,7 @@ fn update_scores(contributor: &mut Contributor, current_timestamp: u32, is_accur
         .mul_up(ONE + contributor.consensus_failures as u64 * FIVE_TENTH)
         .min(MAX_STREAK_BONUS);
 
-    // Adjusted decay rate 0.99
-    let decay_factor = DECAY_RATE.pow_up(hours_inactive.div_up(24_000000000));
+    let decay_factor = inactivity_decay_factor(hours_inactive);
 
     let streak_bonus = if is_accurate {
         current_streak.min(MAX_STREAK_BONUS) // Enhanced streak bonus
@@ -314,15 +845,24 @@ fn update_scores(contributor: &mut Contributor, current_timestamp: u32, is_accur
         ZERO
     };
 
+    // Reports past the per-window cap still count for scores, streaks and failures, but not toward the
+    // report counts, so eligibility cannot be farmed by reporting on every monitored txid at once
+    let counts_toward_eligibility =
+        contributor.record_counted_report(oracle_contract_state, current_timestamp);
     if is_accurate {
-        contributor.total_reports_submitted += 1;
-        contributor.accurate_reports_count += 1;
+        if counts_toward_eligibility {
+            contributor.total_reports_submitted += 1;
+            contributor.accurate_reports_count += 1;
+        }
         contributor.current_streak += 1;
         contributor.compliance_score += score_increment + streak_bonus;
     } else {
-        contributor.total_reports_submitted += 1;
+        if counts_toward_eligibility {
+            contributor.total_reports_submitted += 1;
+        }
         contributor.current_streak = 0;
-        contributor.consensus_failures += 1;
+        contributor.consensus_failures +=
+            contributor.consensus_failure_increment(current_timestamp);
         contributor.compliance_score = contributor.compliance_score.saturating_sub(score_decrement);
     }
 
@@ -342,15 +882,34 @@ fn update_scores(contributor: &mut Contributor, current_timestamp: u32, is_accur
     contributor.compliance_score = logistic_scale(
         contributor.compliance_score,
         MAX_COMPLIANCE_SCORE,
-        ONE_TENTH,
-        MID_COMPLIANCE_SCORE,
+        oracle_contract_state.logistic_steepness,
+        oracle_contract_state.logistic_midpoint,
     ); // Adjusted logistic scaling
 
-    contributor.reliability_score = reliability_factor * 100;
+    // Logistic scaling pushes scores above the midpoint upward, so an inaccurate report could otherwise raise it
+    if !is_accurate {
+        contributor.compliance_score = contributor.compliance_score.min(previous_compliance_score);
+    }
+
+    contributor.reliability_score = base_reliability_score(contributor);
 
     log_score_updates(contributor);
 }
 
+// The exponent in logistic_scale reaches steepness * (distance from the midpoint to the far end of the
+// score range), which must stay small enough for the fixed-point exp not to overflow
+pub fn validate_logistic_config(steepness: u64, midpoint: u64) -> Result<()> {
+    if steepness == ZERO || midpoint > MAX_COMPLIANCE_SCORE {
+        return Err(OracleError::InvalidConfigValue.into());
+    }
+    let max_distance = midpoint.max(MAX_COMPLIANCE_SCORE - midpoint);
+    if steepness > MAX_LOGISTIC_EXPONENT || steepness.mul_up(max_distance) > MAX_LOGISTIC_EXPONENT {
+        return Err(OracleError::InvalidConfigValue.into());
+    }
+    Ok(())
+}
+
+// Integer-only, since exp_down and neg_exp_down go through fixed_exp rather than f64
 fn logistic_scale(score: u64, max_value: u64, steepness: u64, midpoint: u64) -> u64 {
     // max_value / (1 + e ^ (-steepness * (score - midpoint)))
     let denom = if score > midpoint {
@@ -372,12 +931,28 @@ fn log_score_updates(contributor: &Contributor) {
     );
 }
 
-fn update_statuses(contributor: &mut Contributor, current_timestamp: u32) {
+fn update_statuses(
+    contributor: &mut Contributor,
+    oracle_contract_state: &OracleContractState,
+    current_timestamp: u32,
+) {
+    // Reliability erodes while a contributor is dormant; it is recomputed from the report counts each time,
+    // so repeated status updates do not compound the decay
+    if oracle_contract_state.reliability_decay_enabled && contributor.total_reports_submitted > 0 {
+        contributor.reliability_score = base_reliability_score(contributor).mul_down(
+            reliability_decay_factor(current_timestamp.saturating_sub(contributor.last_seen())),
+        );
+    }
+
     // Updating recently active status
     let recent_activity_threshold = 86_400; // 24 hours in seconds
     contributor.is_recently_active =
         current_timestamp - contributor.last_active_timestamp < recent_activity_threshold;
 
+    // Deactivating idle contributors (not a ban); a later report or heartbeat reactivates them
+    contributor.is_deactivated =
+        contributor.calculate_is_idle(oracle_contract_state, current_timestamp);
+
     // Updating reliability status
     contributor.is_reliable = if contributor.total_reports_submitted > 0 {
         let reliability_ratio = contributor
@@ -389,76 +964,419 @@ fn update_statuses(contributor: &mut Contributor, current_timestamp: u32) {
         false
     };
 
-    // Updating eligibility for rewards
-    contributor.is_eligible_for_rewards = contributor.total_reports_submitted
-        >= MIN_REPORTS_FOR_REWARD
-        && contributor.reliability_score >= MIN_RELIABILITY_SCORE_FOR_REWARD
-        && contributor.compliance_score >= MIN_COMPLIANCE_SCORE_FOR_REWARD;
+    // Updating eligibility for rewards; a banned contributor is never eligible, so the flag is
+    // recomputed from the scores on the first status update after the ban expires
+    contributor.is_eligible_for_rewards = !contributor.calculate_is_banned(current_timestamp)
+        && contributor.calculate_is_eligible_for_rewards(oracle_contract_state);
 }
 
-fn update_contributor(contributor: &mut Contributor, current_timestamp: u32, is_accurate: bool) {
+fn update_contributor(
+    contributor: &mut Contributor,
+    oracle_contract_state: &OracleContractState,
+    current_timestamp: u32,
+    is_accurate: bool,
+    hash_matches_consensus: Option<bool>,
+    participation_reward_lamports: u64,
+) {
     // Check if the contributor is banned before proceeding. If so, just return.
     if contributor.calculate_is_banned(current_timestamp) {
         msg!(
             "Contributor is currently banned and cannot be updated: {}",
             contributor.reward_address
         );
+        contributor.is_eligible_for_rewards = false;
         return; // We don't stop the process here, just skip this contributor.
     }
 
+    // Participation is credited regardless of whether the report was accurate
+    contributor.owed_participation_rewards_lamports = contributor
+        .owed_participation_rewards_lamports
+        .saturating_add(participation_reward_lamports);
+
     // Updating scores
-    update_scores(contributor, current_timestamp, is_accurate);
+    update_scores(
+        contributor,
+        oracle_contract_state,
+        current_timestamp,
+        is_accurate,
+    );
 
     // Applying bans based on report accuracy
     apply_bans(contributor, current_timestamp, is_accurate);
+    apply_hash_mismatch_ban(
+        contributor,
+        oracle_contract_state,
+        current_timestamp,
+        hash_matches_consensus,
+    );
 
     // Updating contributor statuses
-    update_statuses(contributor, current_timestamp);
+    update_statuses(contributor, oracle_contract_state, current_timestamp);
+}
+
+// A near-miss report is neither rewarded nor penalized: participation is credited, scores, streak and
+// failure counts are left as they were
+fn credit_near_miss(
+    contributor: &mut Contributor,
+    oracle_contract_state: &OracleContractState,
+    current_timestamp: u32,
+    participation_reward_lamports: u64,
+) {
+    if contributor.calculate_is_banned(current_timestamp) {
+        msg!(
+            "Contributor is currently banned and cannot be updated: {}",
+            contributor.reward_address
+        );
+        contributor.is_eligible_for_rewards = false;
+        return;
+    }
+
+    contributor.owed_participation_rewards_lamports = contributor
+        .owed_participation_rewards_lamports
+        .saturating_add(participation_reward_lamports);
+    msg!(
+        "Near-miss report from contributor: {}; no penalty applied",
+        contributor.reward_address
+    );
+
+    update_statuses(contributor, oracle_contract_state, current_timestamp);
+}
+
+// Participation reward for a report, boosted while its ticket type's recent volume is below the threshold
+fn participation_reward_for_ticket_type(
+    oracle_contract_state: &OracleContractState,
+    aggregated_data_account: &AggregatedConsensusDataAccount,
+    pastel_ticket_type: Option<PastelTicketType>,
+    current_timestamp: u32,
+) -> u64 {
+    let base_reward = oracle_contract_state.participation_reward_lamports;
+    let threshold = oracle_contract_state.under_reported_volume_threshold;
+    match pastel_ticket_type {
+        Some(ticket_type)
+            if threshold != 0
+                && aggregated_data_account
+                    .recent_ticket_type_volume(ticket_type, current_timestamp)
+                    < threshold =>
+        {
+            base_reward.mul_down(oracle_contract_state.under_reported_reward_boost)
+        }
+        _ => base_reward,
+    }
+}
+
+// Whether the report's file hash matches the consensus hash; None when either hash is absent
+fn hash_matches_consensus(common_data: &CommonReportData, consensus_hash: &str) -> Option<bool> {
+    if consensus_hash.is_empty() {
+        return None;
+    }
+    common_data
+        .first_6_characters_of_sha3_256_hash_of_corresponding_file
+        .as_ref()
+        .map(|hash| hash == consensus_hash)
+}
+
+fn is_report_accurate(
+    common_data: &CommonReportData,
+    consensus_status: TxidStatus,
+    consensus_hash: &str,
+    accuracy_policy: AccuracyPolicy,
+) -> bool {
+    let status_matches = common_data.txid_status == consensus_status;
+    // Omitting the hash matches a consensus without one, as for ticket types that have no file. An
+    // empty reported hash is treated as no hash at all, never as a match for an empty consensus hash.
+    let hash_matches = match &common_data.first_6_characters_of_sha3_256_hash_of_corresponding_file
+    {
+        Some(hash) if !hash.is_empty() => hash == consensus_hash,
+        Some(_) => false,
+        None => consensus_hash.is_empty(),
+    };
+
+    match accuracy_policy {
+        AccuracyPolicy::StatusAndHash => status_matches && hash_matches,
+        AccuracyPolicy::StatusOnly => status_matches,
+        AccuracyPolicy::HashOnly => hash_matches,
+    }
+}
+
+// A report with the consensus status (where the policy checks it) whose hash only agrees with the consensus
+// hash on its first `prefix_length` characters; likely the right file with a transcription slip
+fn is_near_miss_report(
+    common_data: &CommonReportData,
+    consensus_status: TxidStatus,
+    consensus_hash: &str,
+    accuracy_policy: AccuracyPolicy,
+    prefix_length: u8,
+) -> bool {
+    let status_matches = match accuracy_policy {
+        AccuracyPolicy::StatusAndHash => common_data.txid_status == consensus_status,
+        AccuracyPolicy::HashOnly => true,
+        AccuracyPolicy::StatusOnly => return false, // The hash never decides accuracy
+    };
+    let prefix_length = prefix_length as usize;
+    let prefix_matches =
+        match &common_data.first_6_characters_of_sha3_256_hash_of_corresponding_file {
+            Some(hash) if prefix_length != 0 => {
+                match (
+                    hash.get(..prefix_length),
+                    consensus_hash.get(..prefix_length),
+                ) {
+                    (Some(reported), Some(consensus)) => reported == consensus,
+                    _ => false,
+                }
+            }
+            _ => false,
+        };
+    status_matches && prefix_matches
+}
+
+// Seconds to wait before retrying consensus after the given number of consecutive failed attempts
+pub fn consensus_retry_delay(failed_consensus_attempts: u32) -> u32 {
+    if failed_consensus_attempts == 0 {
+        return 0;
+    }
+    1u32.checked_shl(failed_consensus_attempts - 1)
+        .map_or(u32::MAX, |factor| {
+            CONSENSUS_RETRY_BASE_DELAY_SECONDS.saturating_mul(factor)
+        })
+        .min(CONSENSUS_RETRY_MAX_DELAY_SECONDS)
+}
+
+fn record_deferred_consensus(
+    aggregated_data_account: &mut AggregatedConsensusDataAccount,
+    txid: &str,
+    current_timestamp: u32,
+) {
+    if let Some(aggregated_data) = aggregated_data_account
+        .consensus_data
+        .iter_mut()
+        .find(|data| data.txid == txid)
+    {
+        aggregated_data.record_deferred_consensus(current_timestamp);
+        msg!(
+            "Consensus for TXID: {} deferred {} time(s); retry after {}",
+            txid,
+            aggregated_data.failed_consensus_attempts,
+            aggregated_data.next_retry_after
+        );
+    }
+}
+
+pub fn consensus_log_line(
+    txid: &str,
+    consensus_status: TxidStatus,
+    consensus_hash: &str,
+    contributor_count: u32,
+    current_timestamp: u32,
+) -> String {
+    [
+        CONSENSUS_LOG_PREFIX.to_string(),
+        txid.to_string(),
+        format!("{:?}", consensus_status),
+        consensus_hash.to_string(),
+        contributor_count.to_string(),
+        current_timestamp.to_string(),
+    ]
+    .join(&CONSENSUS_LOG_SEPARATOR.to_string())
+}
+
+// Links a finalization into the tamper-evident chain: hash(prev_hash || txid || status || hash || timestamp)
+pub fn next_consensus_chain_hash(
+    previous_chain_hash: &[u8; 32],
+    txid: &str,
+    consensus_status: TxidStatus,
+    consensus_hash: &str,
+    finalized_at: u32,
+) -> [u8; 32] {
+    hashv(&[
+        previous_chain_hash,
+        txid.as_bytes(),
+        &[consensus_status as u8],
+        consensus_hash.as_bytes(),
+        &finalized_at.to_le_bytes(),
+    ])
+    .to_bytes()
 }
 
+#[allow(clippy::too_many_arguments)]
 fn calculate_consensus(
-    aggregated_data_account: &Account<AggregatedConsensusDataAccount>,
+    oracle_contract_state: &mut OracleContractState,
+    aggregated_data_account: &mut AggregatedConsensusDataAccount,
+    consensus_summary_account: &mut ConsensusSummaryAccount,
     temp_report_account: &TempTxStatusReportAccount,
-    contributor_data_account: &mut Account<ContributorDataAccount>,
+    contributor_data_account: &mut ContributorDataAccount,
     txid: &str,
-) -> Result<()> {
-    let current_timestamp = Clock::get()?.unix_timestamp as u32;
-    let (consensus_status, consensus_hash) = get_aggregated_data(aggregated_data_account, txid)
-        .map(|data| compute_consensus(data))
-        .unwrap_or((TxidStatus::Invalid, String::new()));
+    current_timestamp: u32,
+    current_slot: u64,
+) -> Result<bool> {
+    let Some(aggregated_data) = get_aggregated_data(aggregated_data_account, txid) else {
+        msg!(
+            "No aggregated data for TXID: {}; deferring finalization",
+            txid
+        );
+        return Ok(false);
+    };
+
+    if aggregated_data.consensus_ran_in_slot(current_slot) {
+        msg!(
+            "Consensus already ran for TXID: {} in slot {}; skipping recomputation",
+            txid,
+            current_slot
+        );
+        return Ok(false);
+    }
+
+    // A count quorum of near-zero-weight contributors is not enough on its own, unless the txid has
+    // aggregated past the maximum age and is finalized with whatever weight it has
+    let total_weight = aggregated_data.total_weight();
+    let past_max_age = aggregated_data
+        .exceeds_max_age(oracle_contract_state.max_aggregation_age, current_timestamp);
+    if total_weight < oracle_contract_state.min_consensus_weight_sum && !past_max_age {
+        msg!(
+            "Total report weight {} for TXID: {} is below the minimum of {}; deferring finalization",
+            total_weight,
+            txid,
+            oracle_contract_state.min_consensus_weight_sum
+        );
+        record_deferred_consensus(aggregated_data_account, txid, current_timestamp);
+        return Ok(false);
+    }
+
+    let Some((consensus_status, consensus_hash)) =
+        compute_consensus(aggregated_data, oracle_contract_state.tie_break_strategy)
+    else {
+        msg!(
+            "No meaningful consensus for TXID: {} (no weighted reports); deferring finalization",
+            txid
+        );
+        record_deferred_consensus(aggregated_data_account, txid, current_timestamp);
+        return Ok(false);
+    };
 
     let mut updated_contributors = Vec::new();
+    let mut score_snapshots = Vec::new();
     let mut contributor_count = 0;
 
     for temp_report in temp_report_account.reports.iter() {
-        let common_data = &temp_report_account.common_reports[temp_report.common_data_ref as usize];
+        let common_data = temp_report_account.common_data_of(temp_report)?;
         let specific_data = &temp_report.specific_data;
 
         if common_data.txid == txid
             && !updated_contributors.contains(&specific_data.contributor_reward_address)
         {
             if let Some(contributor) = contributor_data_account
-                .contributors
-                .iter_mut()
-                .find(|c| c.reward_address == specific_data.contributor_reward_address)
+                .find_contributor_mut(&specific_data.contributor_reward_address)
             {
-                let is_accurate = common_data.txid_status == consensus_status
-                    && common_data
-                        .first_6_characters_of_sha3_256_hash_of_corresponding_file
-                        .as_ref()
-                        .map_or(false, |hash| hash == &consensus_hash);
-                update_contributor(contributor, current_timestamp, is_accurate);
+                score_snapshots.push(ContributorScoreSnapshot::of(contributor));
+                let accuracy_policy = common_data
+                    .pastel_ticket_type
+                    .map_or(AccuracyPolicy::StatusAndHash, |ticket_type| {
+                        oracle_contract_state.accuracy_policies[ticket_type as usize]
+                    });
+                let is_accurate = is_report_accurate(
+                    common_data,
+                    consensus_status,
+                    &consensus_hash,
+                    accuracy_policy,
+                );
+                let participation_reward = participation_reward_for_ticket_type(
+                    oracle_contract_state,
+                    aggregated_data_account,
+                    common_data.pastel_ticket_type,
+                    current_timestamp,
+                );
+                // Banned contributors are skipped by the update, so their report is not part of their trend
+                let is_scored = !contributor.calculate_is_banned(current_timestamp);
+                let is_near_miss = !is_accurate
+                    && is_near_miss_report(
+                        common_data,
+                        consensus_status,
+                        &consensus_hash,
+                        accuracy_policy,
+                        oracle_contract_state.near_miss_hash_prefix_length,
+                    );
+                if is_near_miss {
+                    credit_near_miss(
+                        contributor,
+                        oracle_contract_state,
+                        current_timestamp,
+                        participation_reward,
+                    );
+                } else {
+                    update_contributor(
+                        contributor,
+                        oracle_contract_state,
+                        current_timestamp,
+                        is_accurate,
+                        hash_matches_consensus(common_data, &consensus_hash),
+                        participation_reward,
+                    );
+                }
+                // Near-misses carry no penalty, so they stay out of the accuracy history as well
+                if is_scored && !is_near_miss {
+                    contributor.record_outcome(current_timestamp, is_accurate);
+                }
                 updated_contributors.push(specific_data.contributor_reward_address);
             }
             contributor_count += 1;
         }
     }
     msg!("Consensus reached for TXID: {}, Status: {:?}, Hash: {}, Number of Contributors Included: {}", txid, consensus_status, consensus_hash, contributor_count);
+    let mut summary = None;
+    if let Some(aggregated_data) = aggregated_data_account
+        .consensus_data
+        .iter_mut()
+        .find(|data| data.txid == txid)
+    {
+        summary = Some(ConsensusSummary {
+            txid: txid.to_string(),
+            consensus_status,
+            consensus_hash: consensus_hash.clone(),
+            confidence: aggregated_data.status_confidence(consensus_status),
+            finalized_at: current_timestamp,
+        });
+        aggregated_data.last_consensus_slot = current_slot;
+        aggregated_data.failed_consensus_attempts = 0;
+        aggregated_data.next_retry_after = 0;
+        aggregated_data.score_snapshots = std::mem::take(&mut score_snapshots);
+        oracle_contract_state.consensus_chain_hash = next_consensus_chain_hash(
+            &oracle_contract_state.consensus_chain_hash,
+            txid,
+            consensus_status,
+            &consensus_hash,
+            current_timestamp,
+        );
+        emit!(ConsensusReached {
+            txid: txid.to_string(),
+            consensus_status,
+            consensus_hash: consensus_hash.clone(),
+            status_weight: aggregated_data.status_weights[consensus_status as usize],
+            contributor_count,
+            runner_up: aggregated_data.runner_up(
+                consensus_status,
+                oracle_contract_state.split_consensus_margin
+            ),
+            consensus_chain_hash: oracle_contract_state.consensus_chain_hash,
+            agreement_level: aggregated_data.agreement_level(consensus_status),
+        });
+    }
+    if let Some(summary) = summary {
+        consensus_summary_account.record_consensus_summary(summary);
+    }
+    msg!(
+        "{}",
+        consensus_log_line(
+            txid,
+            consensus_status,
+            &consensus_hash,
+            contributor_count,
+            current_timestamp
+        )
+    );
 
-    Ok(())
+    Ok(true)
 }
 
-pub fn apply_permanent_bans(contributor_data_account: &mut Account<ContributorDataAccount>) {
+pub fn apply_permanent_bans(contributor_data_account: &mut ContributorDataAccount) {
     // Collect addresses of contributors to be removed for efficient logging
     let contributors_to_remove: Vec<String> = contributor_data_account
         .contributors
@@ -471,57 +1389,168 @@ pub fn apply_permanent_bans(contributor_data_account: &mut Account<ContributorDa
     msg!("Now removing permanently banned contributors! Total number of contributors before removal: {}, Number of contributors to be removed: {}, Addresses of contributors to be removed: {:?}",
         contributor_data_account.contributors.len(), contributors_to_remove.len(), contributors_to_remove);
 
-    // Retain only contributors who are not permanently banned
+    // Retain only contributors who are not permanently banned; retain preserves the sort order
     contributor_data_account
         .contributors
         .retain(|c| c.ban_expiry != u32::MAX);
+    debug_assert!(contributor_data_account.contributors_sorted());
 }
 
 fn post_consensus_tasks(
-    txid_submission_counts_account: &mut Account<TxidSubmissionCountsAccount>,
-    aggregated_data_account: &mut Account<AggregatedConsensusDataAccount>,
+    oracle_contract_state: &OracleContractState,
+    txid_submission_counts_account: &mut TxidSubmissionCountsAccount,
+    aggregated_data_account: &mut AggregatedConsensusDataAccount,
     temp_report_account: &mut TempTxStatusReportAccount,
-    contributor_data_account: &mut Account<ContributorDataAccount>,
+    contributor_data_account: &mut ContributorDataAccount,
     txid: &str,
+    current_timestamp: u32,
 ) -> Result<()> {
-    let current_timestamp = Clock::get()?.unix_timestamp as u32;
-
     apply_permanent_bans(contributor_data_account);
 
     msg!("Now cleaning up unneeded data in TempTxStatusReportAccount...");
     // Cleanup unneeded data in TempTxStatusReportAccount
-    temp_report_account.reports.retain(|temp_report| {
-        // Access the common data from the TempTxStatusReportAccount
-        let common_data = &temp_report_account.common_reports[temp_report.common_data_ref as usize];
-        let specific_data = &temp_report.specific_data;
-        common_data.txid != txid
-            && current_timestamp - specific_data.timestamp < DATA_RETENTION_PERIOD
-    });
+    let keep_reports = temp_report_account
+        .reports
+        .iter()
+        .map(|temp_report| {
+            // Access the common data from the TempTxStatusReportAccount
+            let common_data = temp_report_account.common_data_of(temp_report)?;
+            let specific_data = &temp_report.specific_data;
+            Ok(common_data.txid != txid
+                && current_timestamp.saturating_sub(specific_data.timestamp)
+                    < oracle_contract_state.temp_report_retention_period)
+        })
+        .collect::<Result<Vec<bool>>>()?;
+    let mut keep_reports = keep_reports.into_iter();
+    temp_report_account
+        .reports
+        .retain(|_| keep_reports.next().unwrap_or(false));
 
     msg!("Now cleaning up unneeded data in AggregatedConsensusDataAccount...");
     // Cleanup unneeded data in AggregatedConsensusDataAccount
-    aggregated_data_account
-        .consensus_data
-        .retain(|data| current_timestamp - data.last_updated < DATA_RETENTION_PERIOD);
+    aggregated_data_account.consensus_data.retain(|data| {
+        current_timestamp.saturating_sub(data.last_updated)
+            < oracle_contract_state.aggregated_data_retention_period
+    });
 
     msg!("Now cleaning up unneeded data in TxidSubmissionCountsAccount...");
-    // Cleanup old submission counts in TxidSubmissionCountsAccount
+    // Cleanup old submission counts in TxidSubmissionCountsAccount; retain preserves the sort order
     txid_submission_counts_account
         .submission_counts
-        .retain(|count| current_timestamp - count.last_updated < SUBMISSION_COUNT_RETENTION_PERIOD);
+        .retain(|count| {
+            current_timestamp.saturating_sub(count.last_updated)
+                < oracle_contract_state.submission_count_retention_period
+        });
 
     msg!("Done with post-consensus tasks!");
     Ok(())
 }
 
+// Weight a contributor's report carries in the aggregated consensus data:
+// compliance_coefficient * compliance + reliability_coefficient * reliability + stake_coefficient * stake_weight
+pub fn contributor_report_weight(
+    oracle_contract_state: &OracleContractState,
+    contributor: &Contributor,
+    current_timestamp: u32,
+) -> u64 {
+    let weight = contributor
+        .compliance_score
+        .mul_down(oracle_contract_state.compliance_weight_coefficient)
+        + contributor
+            .reliability_score
+            .mul_down(oracle_contract_state.reliability_weight_coefficient)
+        + contributor
+            .stake_weight
+            .mul_down(oracle_contract_state.stake_weight_coefficient);
+    if contributor.is_on_probation(current_timestamp) {
+        weight.mul_down(oracle_contract_state.probation_weight_factor)
+    } else {
+        weight
+    }
+}
+
+// Fixed-point multiplier applied to a report's weight based on how long after the txid was first seen
+// it arrived: the configured multiplier for the first submission, decaying linearly to 1.0 over the decay period
+pub fn early_bird_multiplier(
+    oracle_contract_state: &OracleContractState,
+    seconds_since_first_seen: u32,
+) -> u64 {
+    let max_multiplier = oracle_contract_state.early_bird_multiplier;
+    let decay_period = oracle_contract_state.early_bird_decay_period;
+    if max_multiplier <= ONE || decay_period == 0 || seconds_since_first_seen >= decay_period {
+        return ONE;
+    }
+    let remaining_fraction = (decay_period - seconds_since_first_seen)
+        .to_fixed_giga()
+        .div_down(decay_period.to_fixed_giga());
+    ONE + (max_multiplier - ONE).mul_down(remaining_fraction)
+}
+
+// Fixed-point multiplier that decays the weight of a txid's earlier reports, so recent ones dominate the
+// status tally: the configured floor for the first submission, ramping linearly to 1.0 over the ramp period
+pub fn recency_weight_multiplier(
+    oracle_contract_state: &OracleContractState,
+    seconds_since_first_seen: u32,
+) -> u64 {
+    let floor = oracle_contract_state.recency_weight_floor;
+    let ramp_period = oracle_contract_state.recency_ramp_period;
+    if floor == 0 || floor >= ONE || ramp_period == 0 || seconds_since_first_seen >= ramp_period {
+        return ONE;
+    }
+    let elapsed_fraction = seconds_since_first_seen
+        .to_fixed_giga()
+        .div_down(ramp_period.to_fixed_giga());
+    floor + (ONE - floor).mul_down(elapsed_fraction)
+}
+
+// Keeps a report's weight within the configured floor and ceiling, so no single contributor can decide
+// consensus alone and every participant keeps a minimal voice
+pub fn clamp_report_weight(oracle_contract_state: &OracleContractState, weight: u64) -> u64 {
+    let weight = weight.max(oracle_contract_state.min_report_weight);
+    match oracle_contract_state.max_report_weight {
+        0 => weight,
+        max_report_weight => weight.min(max_report_weight),
+    }
+}
+
+// Adds the report's weight to the aggregated consensus data and returns the effective (unscaled) weight
+// after the early-bird and recency multipliers, so it can be recorded with the report and reversed later
 fn aggregate_consensus_data(
-    aggregated_data_account: &mut Account<AggregatedConsensusDataAccount>,
+    oracle_contract_state: &OracleContractState,
+    aggregated_data_account: &mut AggregatedConsensusDataAccount,
     report: &PastelTxStatusReport,
     weight: u64,
     txid: &str,
-) -> Result<()> {
-    let scaled_weight = weight * 100; // Scaling by a factor of 100
-    let current_timestamp = Clock::get()?.unix_timestamp as u32;
+    current_timestamp: u32,
+) -> Result<u64> {
+    let first_seen = aggregated_data_account
+        .consensus_data
+        .iter()
+        .find(|d| d.txid == txid)
+        .map_or(current_timestamp, |d| d.first_seen);
+    let seconds_since_first_seen = current_timestamp.saturating_sub(first_seen);
+    let effective_weight = clamp_report_weight(
+        oracle_contract_state,
+        weight
+            .mul_down(early_bird_multiplier(
+                oracle_contract_state,
+                seconds_since_first_seen,
+            ))
+            .mul_down(recency_weight_multiplier(
+                oracle_contract_state,
+                seconds_since_first_seen,
+            )),
+    );
+    let (status_scaled_weight, hash_scaled_weight) =
+        scaled_tally_weights(oracle_contract_state, effective_weight)?;
+    if let Some(ticket_type) = report.pastel_ticket_type {
+        aggregated_data_account.record_ticket_type_report(ticket_type, current_timestamp);
+    }
+    // Empty hashes must never gain weight, or they could become an empty consensus hash
+    let reported_hash = report
+        .first_6_characters_of_sha3_256_hash_of_corresponding_file
+        .as_deref()
+        .filter(|hash| !hash.is_empty());
 
     // Check if the txid already exists in the aggregated consensus data
     if let Some(data_entry) = aggregated_data_account
@@ -530,38 +1559,133 @@ fn aggregate_consensus_data(
         .find(|d| d.txid == txid)
     {
         // Update existing data
-        data_entry.status_weights[report.txid_status as usize] += scaled_weight;
-        if let Some(hash) = &report.first_6_characters_of_sha3_256_hash_of_corresponding_file {
-            update_hash_weight(&mut data_entry.hash_weights, hash, scaled_weight);
+        add_aggregated_weight(
+            &mut data_entry.status_weights[report.txid_status as usize],
+            status_scaled_weight,
+        )?;
+        if data_entry.status_first_seen[report.txid_status as usize] == 0 {
+            data_entry.status_first_seen[report.txid_status as usize] = current_timestamp;
+        }
+        if let Some(hash) = reported_hash {
+            update_hash_weight(
+                &mut data_entry.hash_weights,
+                hash,
+                hash_scaled_weight,
+                current_timestamp,
+            )?;
+            // A report without a hash keeps the latest reported one rather than blanking it
+            data_entry.first_6_characters_of_sha3_256_hash_of_corresponding_file = hash.to_string();
         }
         data_entry.last_updated = current_timestamp;
-        // Handling the Option<String> here
-        data_entry.first_6_characters_of_sha3_256_hash_of_corresponding_file = report
-            .first_6_characters_of_sha3_256_hash_of_corresponding_file
-            .clone()
-            .unwrap_or_default();
     } else {
         // Create new data
         let mut new_data = AggregatedConsensusData {
             txid: txid.to_string(),
             status_weights: [0; TXID_STATUS_VARIANT_COUNT],
             hash_weights: Vec::new(),
-            first_6_characters_of_sha3_256_hash_of_corresponding_file: report
-                .first_6_characters_of_sha3_256_hash_of_corresponding_file
-                .clone()
-                .unwrap_or_default(),
+            first_6_characters_of_sha3_256_hash_of_corresponding_file: reported_hash
+                .unwrap_or_default()
+                .to_string(),
             last_updated: current_timestamp,
+            first_seen: current_timestamp,
+            status_first_seen: [0; TXID_STATUS_VARIANT_COUNT],
+            flagged_for_audit: false,
+            last_consensus_slot: 0,
+            failed_consensus_attempts: 0,
+            next_retry_after: 0,
+            score_snapshots: Vec::new(),
         };
-        new_data.status_weights[report.txid_status as usize] += scaled_weight;
-        if let Some(hash) = &report.first_6_characters_of_sha3_256_hash_of_corresponding_file {
+        new_data.status_weights[report.txid_status as usize] = status_scaled_weight;
+        new_data.status_first_seen[report.txid_status as usize] = current_timestamp;
+        if let Some(hash) = reported_hash {
             new_data.hash_weights.push(HashWeight {
-                hash: hash.clone(),
-                weight: scaled_weight,
+                hash: hash.to_string(),
+                weight: hash_scaled_weight,
+                first_seen: current_timestamp,
             });
         }
         aggregated_data_account.consensus_data.push(new_data);
     }
 
+    Ok(effective_weight)
+}
+
+// A report's weight in one tally: the effective weight scaled by that tally's multiplier, then clamped
+// again, so the floor and ceiling bound each tally on its own; a multiplier of 0 leaves it unchanged
+fn tally_weight(
+    oracle_contract_state: &OracleContractState,
+    effective_weight: u64,
+    multiplier: u64,
+) -> u64 {
+    match multiplier {
+        0 => effective_weight,
+        multiplier => {
+            clamp_report_weight(oracle_contract_state, effective_weight.mul_down(multiplier))
+        }
+    }
+}
+
+// The (status, hash) tally weights of a report, scaled for aggregation
+fn scaled_tally_weights(
+    oracle_contract_state: &OracleContractState,
+    effective_weight: u64,
+) -> Result<(u64, u64)> {
+    Ok((
+        scale_for_aggregation(tally_weight(
+            oracle_contract_state,
+            effective_weight,
+            oracle_contract_state.status_weight_multiplier,
+        ))?,
+        scale_for_aggregation(tally_weight(
+            oracle_contract_state,
+            effective_weight,
+            oracle_contract_state.hash_weight_multiplier,
+        ))?,
+    ))
+}
+
+// Report weights are only bounded by the configured coefficients and multipliers, so scaling and
+// accumulating them is checked rather than left to wrap
+fn scale_for_aggregation(weight: u64) -> Result<u64> {
+    weight
+        .checked_mul(AGGREGATED_WEIGHT_SCALE)
+        .ok_or_else(|| OracleError::WeightOverflow.into())
+}
+
+fn add_aggregated_weight(total: &mut u64, scaled_weight: u64) -> Result<()> {
+    *total = total
+        .checked_add(scaled_weight)
+        .ok_or(OracleError::WeightOverflow)?;
+    Ok(())
+}
+
+// Reverses the contribution a previously aggregated report made to the consensus data for its txid
+fn remove_consensus_data_weight(
+    oracle_contract_state: &OracleContractState,
+    aggregated_data_account: &mut AggregatedConsensusDataAccount,
+    common_data: &CommonReportData,
+    weight: u64,
+) -> Result<()> {
+    let (status_scaled_weight, hash_scaled_weight) =
+        scaled_tally_weights(oracle_contract_state, weight)?;
+
+    if let Some(data_entry) = aggregated_data_account
+        .consensus_data
+        .iter_mut()
+        .find(|d| d.txid == common_data.txid)
+    {
+        let status_weight = &mut data_entry.status_weights[common_data.txid_status as usize];
+        *status_weight = status_weight.saturating_sub(status_scaled_weight);
+        if let Some(hash) = &common_data.first_6_characters_of_sha3_256_hash_of_corresponding_file {
+            if let Some(hash_weight) = data_entry
+                .hash_weights
+                .iter_mut()
+                .find(|hash_weight| hash_weight.hash == *hash)
+            {
+                hash_weight.weight = hash_weight.weight.saturating_sub(hash_scaled_weight);
+            }
+        }
+    }
     Ok(())
 }
 
@@ -582,48 +1706,186 @@ fn find_or_add_common_report_data(
     }
 }
 
-pub fn submit_data_report_helper(
-    ctx: Context<SubmitDataReport>,
-    txid: String,
-    report: PastelTxStatusReport,
-    contributor_reward_address: Pubkey,
-) -> Result<()> {
-    // Directly access accounts from the context
-    let txid_submission_counts_account: &mut Account<'_, TxidSubmissionCountsAccount> =
-        &mut ctx.accounts.txid_submission_counts_account;
-    let aggregated_data_account = &mut ctx.accounts.aggregated_consensus_data_account;
-    let temp_report_account = &mut ctx.accounts.temp_report_account;
-    let contributor_data_account = &mut ctx.accounts.contributor_data_account;
+#[event]
+pub struct AggregationExpired {
+    pub txid: String,
+    pub first_seen: u32,
+    pub expired_at: u32,
+}
 
-    // Retrieve the submission count for the given txid from the PDA account
-    let txid_submission_count: usize = txid_submission_counts_account
-        .submission_counts
+// Drops a txid that aggregated past the maximum age without any weighted report to finalize: its
+// aggregated data, temp reports and submission count are removed so it cannot linger
+fn expire_aggregation(
+    txid_submission_counts_account: &mut TxidSubmissionCountsAccount,
+    temp_report_account: &mut TempTxStatusReportAccount,
+    aggregated_data_account: &mut AggregatedConsensusDataAccount,
+    txid: &str,
+    current_timestamp: u32,
+) {
+    let first_seen = get_aggregated_data(aggregated_data_account, txid).map_or(0, |d| d.first_seen);
+
+    let keep_reports: Vec<bool> = temp_report_account
+        .reports
         .iter()
-        .find(|c| c.txid == txid)
-        .map_or(0, |c| c.count as usize);
+        .map(|temp_report| !temp_report_account.reports_txid(temp_report, txid))
+        .collect();
+    let mut keep_reports = keep_reports.into_iter();
+    temp_report_account
+        .reports
+        .retain(|_| keep_reports.next().unwrap_or(false));
+    aggregated_data_account
+        .consensus_data
+        .retain(|data| data.txid != txid);
+    // retain preserves the sort order
+    txid_submission_counts_account
+        .submission_counts
+        .retain(|count| count.txid != txid);
+
+    msg!(
+        "Aggregation for TXID: {} first seen at {} expired without a weighted report; invalidated",
+        txid,
+        first_seen
+    );
+    emit!(AggregationExpired {
+        txid: txid.to_string(),
+        first_seen,
+        expired_at: current_timestamp,
+    });
+}
+
+// Calculates consensus for a txid that has met its quorum (or aggregated past the maximum age) and, if one
+// is reached, finalizes it. Returns whether the txid was resolved, either finalized or expired.
+#[allow(clippy::too_many_arguments)]
+fn finalize_consensus(
+    oracle_contract_state: &mut OracleContractState,
+    txid_submission_counts_account: &mut TxidSubmissionCountsAccount,
+    temp_report_account: &mut TempTxStatusReportAccount,
+    contributor_data_account: &mut ContributorDataAccount,
+    aggregated_data_account: &mut AggregatedConsensusDataAccount,
+    consensus_summary_account: &mut ConsensusSummaryAccount,
+    txid: &str,
+    audit_seed: &[u8; 32],
+) -> Result<bool> {
+    msg!("Calculating consensus...");
+    let clock = Clock::get()?;
+    let consensus_reached = calculate_consensus(
+        oracle_contract_state,
+        aggregated_data_account,
+        consensus_summary_account,
+        temp_report_account,
+        contributor_data_account,
+        txid,
+        clock.unix_timestamp as u32,
+        clock.slot,
+    )?;
+
+    // Without a meaningful consensus the reports are kept, so nothing is cleaned up yet
+    if consensus_reached {
+        flag_for_audit_if_selected(
+            aggregated_data_account,
+            txid,
+            audit_seed,
+            oracle_contract_state.audit_selection_rate,
+        );
+
+        msg!("Performing post-consensus tasks...");
+        post_consensus_tasks(
+            oracle_contract_state,
+            txid_submission_counts_account,
+            aggregated_data_account,
+            temp_report_account,
+            contributor_data_account,
+            txid,
+            clock.unix_timestamp as u32,
+        )?;
+        return Ok(true);
+    }
 
-    // Check if the number of submissions is already at or exceeds MIN_NUMBER_OF_ORACLES
-    if txid_submission_count >= MIN_NUMBER_OF_ORACLES {
-        msg!("Enough reports have already been submitted for this txid");
-        return Err(OracleError::EnoughReportsSubmittedForTxid.into());
+    // A txid past the maximum age that still has no meaningful consensus is invalidated instead
+    let past_max_age = get_aggregated_data(aggregated_data_account, txid).is_some_and(|data| {
+        data.exceeds_max_age(
+            oracle_contract_state.max_aggregation_age,
+            clock.unix_timestamp as u32,
+        ) && !data.consensus_ran_in_slot(clock.slot)
+    });
+    if past_max_age {
+        expire_aggregation(
+            txid_submission_counts_account,
+            temp_report_account,
+            aggregated_data_account,
+            txid,
+            clock.unix_timestamp as u32,
+        );
     }
+    Ok(past_max_age)
+}
+
+// Shared by submit_data_report and submit_packed_data_report once their accounts are validated
+#[allow(clippy::too_many_arguments)]
+fn process_data_report(
+    oracle_contract_state: &mut OracleContractState,
+    txid_submission_counts_account: &mut TxidSubmissionCountsAccount,
+    temp_report_account: &mut TempTxStatusReportAccount,
+    contributor_data_account: &mut ContributorDataAccount,
+    aggregated_data_account: &mut AggregatedConsensusDataAccount,
+    consensus_summary_account: &mut ConsensusSummaryAccount,
+    report: PastelTxStatusReport,
+    audit_seed: &[u8; 32],
+) -> Result<()> {
+    let txid = report.txid.clone();
+    let contributor_reward_address = report.contributor_reward_address;
+    let current_timestamp = Clock::get()?.unix_timestamp as u32;
+
+    // The quorum check (EnoughReportsSubmittedForTxid, see accepts_submission) is enforced as an account constraint
+    // in SubmitDataReport and SubmitPackedDataReport
 
     // Validate the data report before any contributor-specific checks
     // msg!("Validating data report: {:?}", report);
-    validate_data_contributor_report(&report)?;
+    validate_data_contributor_report(&report, oracle_contract_state)?;
+    check_submission_cap(oracle_contract_state, txid_submission_counts_account, &txid)?;
 
     // Check if the contributor is registered and not banned
     // msg!("Checking if contributor is registered and not banned");
     let contributor = contributor_data_account
-        .contributors
-        .iter()
-        .find(|c| c.reward_address == contributor_reward_address)
+        .find_contributor_mut(&contributor_reward_address)
         .ok_or(OracleError::ContributorNotRegistered)?;
 
-    if contributor.calculate_is_banned(Clock::get()?.unix_timestamp as u32) {
+    if contributor.calculate_is_banned(current_timestamp) {
         return Err(OracleError::ContributorBanned.into());
     }
 
+    if contributor.is_in_quiet_period(
+        oracle_contract_state.submission_quiet_period,
+        current_timestamp,
+    ) {
+        msg!(
+            "Contributor {} registered at {} cannot submit a first report before the {} second quiet period ends",
+            contributor_reward_address,
+            contributor.registered_at,
+            oracle_contract_state.submission_quiet_period
+        );
+        return Err(OracleError::ContributorNotYetActive.into());
+    }
+
+    let max_active_reports = oracle_contract_state.max_active_reports_per_contributor;
+    if max_active_reports != 0
+        && temp_report_account.active_report_count(&contributor_reward_address)
+            >= max_active_reports
+    {
+        msg!(
+            "Contributor {} already has {} reports awaiting consensus",
+            contributor_reward_address,
+            max_active_reports
+        );
+        return Err(OracleError::TooManyActiveReports.into());
+    }
+
+    // A report counts as activity, so an idle contributor is active again
+    contributor.last_submission_timestamp = current_timestamp;
+    contributor.is_deactivated = false;
+
+    let weight = contributor_report_weight(oracle_contract_state, contributor, current_timestamp);
+
     // Clone the String before using it
     let first_6_characters_of_sha3_256_hash_of_corresponding_file = report
         .first_6_characters_of_sha3_256_hash_of_corresponding_file
@@ -635,20 +1897,30 @@ pub fn submit_data_report_helper(
         txid: report.txid.clone(),
         txid_status: report.txid_status,
         pastel_ticket_type: report.pastel_ticket_type,
-        first_6_characters_of_sha3_256_hash_of_corresponding_file:
-            first_6_characters_of_sha3_256_hash_of_corresponding_file,
+        first_6_characters_of_sha3_256_hash_of_corresponding_file,
     };
 
     // Finding or adding common report data
     // msg!("Finding or adding common report data");
     let common_data_index = find_or_add_common_report_data(temp_report_account, &common_data);
 
+    // Add the report's weight to the consensus data, boosted if it arrived early
+    let effective_weight = aggregate_consensus_data(
+        oracle_contract_state,
+        aggregated_data_account,
+        &report,
+        weight,
+        &txid,
+        current_timestamp,
+    )?;
+
     // Creating specific report data
     // msg!("Creating specific report data");
     let specific_report = SpecificReportData {
         contributor_reward_address,
         timestamp: report.timestamp,
         common_data_ref: common_data_index,
+        weight: effective_weight,
     };
 
     // Creating a temporary report entry
@@ -666,1009 +1938,12975 @@ pub fn submit_data_report_helper(
     // msg!("Updating submission count and consensus-related data");
     update_submission_count(txid_submission_counts_account, &txid)?;
 
-    let compliance_score = contributor.compliance_score;
-    let reliability_score = contributor.reliability_score;
-    let weight = compliance_score + reliability_score;
-    aggregate_consensus_data(aggregated_data_account, &report, weight, &txid)?;
-
     // Check for consensus and perform related tasks
-    if should_calculate_consensus(txid_submission_counts_account, &txid)? {
+    let mature_contributor_count =
+        contributor_data_account.mature_contributor_count(oracle_contract_state, current_timestamp);
+    txid_submission_counts_account.record_quorum_reached(
+        &txid,
+        txid_quorum(
+            oracle_contract_state,
+            temp_report_account,
+            mature_contributor_count,
+            &txid,
+        ),
+        current_timestamp,
+    );
+    if should_calculate_consensus(
+        oracle_contract_state,
+        txid_submission_counts_account,
+        temp_report_account,
+        mature_contributor_count,
+        &txid,
+        current_timestamp,
+    ) || aggregation_exceeds_max_age(
+        oracle_contract_state,
+        aggregated_data_account,
+        &txid,
+        current_timestamp,
+    ) {
         msg!(
             "We now have enough reports to calculate consensus for txid: {}",
             txid
         );
 
-        let contributor_data_account: &mut Account<'_, ContributorDataAccount> =
-            &mut ctx.accounts.contributor_data_account;
-        msg!("Calculating consensus...");
-        calculate_consensus(
-            aggregated_data_account,
-            temp_report_account,
-            contributor_data_account,
-            &txid,
-        )?;
-
-        msg!("Performing post-consensus tasks...");
-        post_consensus_tasks(
-            txid_submission_counts_account,
-            aggregated_data_account,
-            temp_report_account,
-            contributor_data_account,
-            &txid,
-        )?;
+        if oracle_contract_state.deferred_consensus {
+            // The submitter that completes the quorum only records its report; anyone can then call
+            // finalize_consensus, so this submitter doesn't pay the compute for everyone's consensus
+            msg!(
+                "Consensus deferred to finalize_consensus for txid: {}",
+                txid
+            );
+        } else {
+            finalize_consensus(
+                oracle_contract_state,
+                txid_submission_counts_account,
+                temp_report_account,
+                contributor_data_account,
+                aggregated_data_account,
+                consensus_summary_account,
+                &txid,
+                audit_seed,
+            )?;
+        }
     }
 
     // Log the new size of temp_tx_status_reports
-    msg!("New size of temp_tx_status_reports in bytes after processing report for txid {} from contributor {}: {}", txid, contributor_reward_address, temp_report_account.reports.len() * std::mem::size_of::<TempTxStatusReport>());
+    msg!("New size of temp_tx_status_reports in bytes after processing report for txid {} from contributor {}: {}", txid, contributor_reward_address, temp_report_account.reports.len() * TempTxStatusReport::SERIALIZED_LEN);
 
     Ok(())
 }
 
+// The SlotHashes sysvar is a u64 entry count followed by (slot, hash) entries, most recent first
+pub fn most_recent_slot_hash(slot_hashes_data: &[u8]) -> Result<[u8; 32]> {
+    slot_hashes_data
+        .get(16..48)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| ProgramError::InvalidAccountData.into())
+}
+
+// Deterministically selects roughly one in `audit_selection_rate` consensus rounds; 0 disables selection
+pub fn is_selected_for_audit(audit_seed: &[u8; 32], txid: &str, audit_selection_rate: u32) -> bool {
+    if audit_selection_rate == 0 {
+        return false;
+    }
+    let digest = hash(&[audit_seed.as_ref(), txid.as_bytes()].concat());
+    let draw = u64::from_le_bytes(digest.to_bytes()[..8].try_into().unwrap());
+    draw % audit_selection_rate as u64 == 0
+}
+
+// Flags the finalized consensus record for admin review; the consensus itself is unaffected
+fn flag_for_audit_if_selected(
+    aggregated_data_account: &mut AggregatedConsensusDataAccount,
+    txid: &str,
+    audit_seed: &[u8; 32],
+    audit_selection_rate: u32,
+) {
+    if !is_selected_for_audit(audit_seed, txid, audit_selection_rate) {
+        return;
+    }
+    if let Some(data) = aggregated_data_account
+        .consensus_data
+        .iter_mut()
+        .find(|data| data.txid == txid)
+    {
+        data.flagged_for_audit = true;
+        msg!("Consensus round for TXID: {} selected for audit", txid);
+    }
+}
+
+#[event]
+pub struct ConsensusReversed {
+    pub txid: String,
+    pub contributors_restored: u32,
+}
+
+// Undoes the score effects of a txid's latest finalization after review finds its consensus wrong: the
+// judged contributors get their pre-consensus scores back and the consensus summary is withdrawn. The
+// snapshots live on the txid's aggregated entry, so a reversal is only possible until the entry is pruned,
+// aggregated_data_retention_period seconds after its last update. Rewards already paid, and contributors
+// already removed by a permanent ban, are not restored. Returns the number of contributors restored.
+pub fn reverse_consensus_effects(
+    oracle_contract_state: &OracleContractState,
+    contributor_data_account: &mut ContributorDataAccount,
+    aggregated_data_account: &mut AggregatedConsensusDataAccount,
+    consensus_summary_account: &mut ConsensusSummaryAccount,
+    txid: &str,
+    current_timestamp: u32,
+) -> Result<u32> {
+    let aggregated_data = aggregated_data_account
+        .consensus_data
+        .iter_mut()
+        .find(|data| data.txid == txid)
+        .ok_or(OracleError::ReportNotFound)?;
+    if aggregated_data.score_snapshots.is_empty() {
+        return Err(OracleError::NoConsensusToReverse.into());
+    }
+    let score_snapshots = std::mem::take(&mut aggregated_data.score_snapshots);
+
+    let mut contributors_restored = 0;
+    for snapshot in &score_snapshots {
+        let Some(contributor) =
+            contributor_data_account.find_contributor_mut(&snapshot.reward_address)
+        else {
+            msg!(
+                "Contributor {} is no longer registered; its scores cannot be restored",
+                snapshot.reward_address
+            );
+            continue;
+        };
+        snapshot.restore(contributor);
+        update_statuses(contributor, oracle_contract_state, current_timestamp);
+        log_score_updates(contributor);
+        contributors_restored += 1;
+    }
+    consensus_summary_account
+        .summaries
+        .retain(|summary| summary.txid != txid);
+
+    msg!(
+        "Consensus for TXID: {} reversed; {} contributors restored",
+        txid,
+        contributors_restored
+    );
+    emit!(ConsensusReversed {
+        txid: txid.to_string(),
+        contributors_restored,
+    });
+    Ok(contributors_restored)
+}
+
+// Admin-only: an audit flag is resolved by the admin rather than by a challenge process. Must run within the
+// aggregated data retention period of the consensus being reversed.
 #[derive(Accounts)]
-#[instruction(txid: String)]
-pub struct HandleConsensus<'info> {
-    #[account(mut)]
+pub struct ReverseConsensus<'info> {
+    #[account(has_one = admin_pubkey, constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized)]
     pub oracle_contract_state: Account<'info, OracleContractState>,
+    pub admin_pubkey: Signer<'info>,
+    #[account(mut, seeds = [b"contributor_data"], bump)]
+    pub contributor_data_account: Account<'info, ContributorDataAccount>,
+    #[account(mut, seeds = [b"aggregated_consensus_data"], bump)]
+    pub aggregated_consensus_data_account: Account<'info, AggregatedConsensusDataAccount>,
+    #[account(mut, seeds = [b"consensus_summary"], bump)]
+    pub consensus_summary_account: Account<'info, ConsensusSummaryAccount>,
+    #[account(mut, seeds = [b"admin_audit_log"], bump)]
+    pub admin_audit_log: Account<'info, AdminAuditLogAccount>,
+}
 
-    #[account(mut)]
-    pub user: Signer<'info>,
+pub fn reverse_consensus_helper(ctx: Context<ReverseConsensus>, txid: String) -> Result<()> {
+    let current_timestamp = Clock::get()?.unix_timestamp as u32;
+    let accounts = ctx.accounts;
+    let contributors_restored = reverse_consensus_effects(
+        &accounts.oracle_contract_state,
+        &mut accounts.contributor_data_account,
+        &mut accounts.aggregated_consensus_data_account,
+        &mut accounts.consensus_summary_account,
+        &txid,
+        current_timestamp,
+    )?;
 
-    pub system_program: Program<'info, System>,
+    accounts.admin_audit_log.record(
+        AdminActionCode::ReverseConsensus,
+        accounts.admin_pubkey.key(),
+        current_timestamp,
+        &format!("txid={},restored={}", txid, contributors_restored),
+    );
+    Ok(())
 }
 
-#[account]
-pub struct PendingPaymentAccount {
-    pub pending_payment: PendingPayment,
+// The accounts SubmitDataReport and SubmitPackedDataReport have in common, so both run one submission path
+pub struct SubmissionAccounts<'a, 'info> {
+    pub txid_submission_counts_account: &'a mut Account<'info, TxidSubmissionCountsAccount>,
+    pub report_account: &'a mut Account<'info, PastelTxStatusReportAccount>,
+    pub oracle_contract_state: &'a mut Account<'info, OracleContractState>,
+    pub user: &'a Signer<'info>,
+    pub temp_report_account: &'a mut Account<'info, TempTxStatusReportAccount>,
+    pub contributor_data_account: &'a mut Account<'info, ContributorDataAccount>,
+    pub aggregated_consensus_data_account: &'a mut Account<'info, AggregatedConsensusDataAccount>,
+    pub consensus_summary_account: &'a mut Account<'info, ConsensusSummaryAccount>,
+    pub reward_pool_account: &'a UncheckedAccount<'info>,
+    pub slot_hashes: &'a UncheckedAccount<'info>,
+    pub system_program: &'a Program<'info, System>,
+}
+
+impl<'info> SubmitDataReport<'info> {
+    fn submission_accounts(&mut self) -> SubmissionAccounts<'_, 'info> {
+        SubmissionAccounts {
+            txid_submission_counts_account: &mut self.txid_submission_counts_account,
+            report_account: &mut self.report_account,
+            oracle_contract_state: &mut self.oracle_contract_state,
+            user: &self.user,
+            temp_report_account: &mut self.temp_report_account,
+            contributor_data_account: &mut self.contributor_data_account,
+            aggregated_consensus_data_account: &mut self.aggregated_consensus_data_account,
+            consensus_summary_account: &mut self.consensus_summary_account,
+            reward_pool_account: &self.reward_pool_account,
+            slot_hashes: &self.slot_hashes,
+            system_program: &self.system_program,
+        }
+    }
+}
+
+// Records the submission, processes the report and collects its fee, then auto-pays any contributor the
+// report's consensus made newly eligible
+fn submit_report<'info>(
+    accounts: SubmissionAccounts<'_, 'info>,
+    reward_pool_bump: u8,
+    remaining_accounts: &[AccountInfo<'info>],
+    report: PastelTxStatusReport,
+) -> Result<()> {
+    accounts
+        .report_account
+        .record_submission_slot(Clock::get()?.slot)?;
+    let eligible_before = auto_pay_snapshot(
+        accounts.oracle_contract_state,
+        accounts.contributor_data_account,
+    );
+    process_data_report(
+        accounts.oracle_contract_state,
+        accounts.txid_submission_counts_account,
+        accounts.temp_report_account,
+        accounts.contributor_data_account,
+        accounts.aggregated_consensus_data_account,
+        accounts.consensus_summary_account,
+        report,
+        &most_recent_slot_hash(&accounts.slot_hashes.try_borrow_data()?)?,
+    )?;
+    collect_submission_fee(
+        accounts.oracle_contract_state,
+        &accounts.user.to_account_info(),
+        &accounts.reward_pool_account.to_account_info(),
+        &accounts.system_program.to_account_info(),
+    )?;
+
+    if let Some(eligible_before) = eligible_before {
+        let payee_accounts: Vec<AccountInfo<'info>> =
+            std::iter::once(accounts.user.to_account_info())
+                .chain(remaining_accounts.iter().cloned())
+                .collect();
+        auto_pay_newly_eligible(
+            accounts.oracle_contract_state,
+            accounts.contributor_data_account,
+            &eligible_before,
+            &accounts.reward_pool_account.to_account_info(),
+            reward_pool_bump,
+            &payee_accounts,
+            &accounts.system_program.to_account_info(),
+        )?;
+    }
+    Ok(())
+}
+
+pub fn submit_data_report_helper<'info>(
+    ctx: Context<'_, '_, '_, 'info, SubmitDataReport<'info>>,
+    report: PastelTxStatusReport,
+) -> Result<()> {
+    submit_report(
+        ctx.accounts.submission_accounts(),
+        ctx.bumps.reward_pool_account,
+        ctx.remaining_accounts,
+        report,
+    )
+}
+
+// submit_packed_data_report's single argument. It leaves out what the program fills in itself: the timestamp
+// is assigned on-chain and the reward address is always the signer's. As in submit_data_report, an empty hash
+// means none was given.
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct PackedTxStatusReport {
+    pub txid: String,
+    pub txid_status: TxidStatus,
+    pub pastel_ticket_type: PastelTicketType,
+    pub first_6_characters_hash: String,
+}
+
+impl PackedTxStatusReport {
+    pub fn into_report(
+        self,
+        contributor_reward_address: Pubkey,
+        timestamp: u32,
+    ) -> PastelTxStatusReport {
+        PastelTxStatusReport {
+            txid: normalize_txid(&self.txid),
+            txid_status: self.txid_status,
+            pastel_ticket_type: Some(self.pastel_ticket_type),
+            first_6_characters_of_sha3_256_hash_of_corresponding_file: (!self
+                .first_6_characters_hash
+                .is_empty())
+            .then_some(self.first_6_characters_hash),
+            timestamp,
+            contributor_reward_address,
+        }
+    }
 }
 
+// Same accounts as SubmitDataReport, with the seeds and constraints taken from the packed report
 #[derive(Accounts)]
-#[instruction(txid: String)]
-pub struct HandlePendingPayment<'info> {
+#[instruction(report: PackedTxStatusReport)]
+pub struct SubmitPackedDataReport<'info> {
+    #[account(
+        mut,
+        seeds = [b"txid_submission_counts"],
+        bump,
+        constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized,
+        constraint = accepts_submission_now(&oracle_contract_state, &txid_submission_counts_account, &temp_report_account, &contributor_data_account, &report.txid)? @ OracleError::EnoughReportsSubmittedForTxid
+    )]
+    pub txid_submission_counts_account: Account<'info, TxidSubmissionCountsAccount>,
+
     #[account(
         init_if_needed,
         payer = user,
-        seeds = [create_seed("pending_payment", &txid, &user.key()).as_ref()],
+        seeds = [create_seed("pastel_tx_status_report", &report.txid, &user.key()).as_ref()],
         bump,
-        space = 8 + std::mem::size_of::<PendingPayment>() + 64 // Adjusted for discriminator
+        space = 8 + (64 + 1 + 2 + 7 + 8 + 32 + 128) // Same layout as in SubmitDataReport
     )]
-    pub pending_payment_account: Account<'info, PendingPaymentAccount>,
+    pub report_account: Account<'info, PastelTxStatusReportAccount>,
 
     #[account(mut)]
     pub oracle_contract_state: Account<'info, OracleContractState>,
 
+    // The report is attributed to its signer
     #[account(mut)]
     pub user: Signer<'info>,
 
-    pub system_program: Program<'info, System>,
-}
+    #[account(mut, seeds = [b"temp_tx_status_report"], bump)]
+    pub temp_report_account: Account<'info, TempTxStatusReportAccount>,
 
-pub fn add_pending_payment_helper(
-    ctx: Context<HandlePendingPayment>,
-    txid: String,
-    pending_payment: PendingPayment,
-) -> Result<()> {
-    let pending_payment_account = &mut ctx.accounts.pending_payment_account;
-
-    // Ensure the account is being initialized for the first time to avoid re-initialization
-    if !pending_payment_account.pending_payment.txid.is_empty()
-        && pending_payment_account.pending_payment.txid != txid
-    {
-        return Err(OracleError::PendingPaymentAlreadyInitialized.into());
-    }
-
-    // Ensure txid is correct and other fields are properly set
-    if pending_payment.txid != txid {
-        return Err(OracleError::InvalidTxid.into());
-    }
-
-    // Store the pending payment in the account
-    pending_payment_account.pending_payment = pending_payment;
+    #[account(mut, seeds = [b"contributor_data"], bump)]
+    pub contributor_data_account: Account<'info, ContributorDataAccount>,
 
-    msg!(
-        "Pending payment account initialized: TXID: {}, Expected Amount: {}, Status: {:?}",
-        pending_payment_account.pending_payment.txid,
-        pending_payment_account.pending_payment.expected_amount,
-        pending_payment_account.pending_payment.payment_status
-    );
+    #[account(mut, seeds = [b"aggregated_consensus_data"], bump)]
+    pub aggregated_consensus_data_account: Account<'info, AggregatedConsensusDataAccount>,
 
-    Ok(())
-}
+    #[account(mut, seeds = [b"consensus_summary"], bump)]
+    pub consensus_summary_account: Account<'info, ConsensusSummaryAccount>,
 
-#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
-pub struct Contributor {
-    pub reward_address: Pubkey,
-    pub registration_entrance_fee_transaction_signature: String,
-    pub compliance_score: u64,
-    pub last_active_timestamp: u32,
-    pub total_reports_submitted: u32,
-    pub accurate_reports_count: u32,
-    pub current_streak: u32,
-    pub reliability_score: u64,
-    pub consensus_failures: u32,
-    pub ban_expiry: u32,
-    pub is_eligible_for_rewards: bool,
-    pub is_recently_active: bool,
-    pub is_reliable: bool,
-}
+    /// CHECK: Canonical reward pool PDA holding lamports only, so it must stay owned by the system program; pays newly eligible contributors when auto-pay is on
+    #[account(mut, seeds = [b"reward_pool"], bump, owner = system_program::ID @ OracleError::InvalidPoolAccountOwner)]
+    pub reward_pool_account: UncheckedAccount<'info>,
 
-#[account]
-pub struct ContributorDataAccount {
-    pub contributors: Vec<Contributor>,
-}
+    /// CHECK: The SlotHashes sysvar, read raw because it is too large to deserialize; seeds audit selection
+    #[account(address = slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
 
-#[account]
-pub struct TxidSubmissionCountsAccount {
-    pub submission_counts: Vec<TxidSubmissionCount>,
+    pub system_program: Program<'info, System>,
 }
 
-#[account]
-pub struct AggregatedConsensusDataAccount {
-    pub consensus_data: Vec<AggregatedConsensusData>,
+impl<'info> SubmitPackedDataReport<'info> {
+    fn submission_accounts(&mut self) -> SubmissionAccounts<'_, 'info> {
+        SubmissionAccounts {
+            txid_submission_counts_account: &mut self.txid_submission_counts_account,
+            report_account: &mut self.report_account,
+            oracle_contract_state: &mut self.oracle_contract_state,
+            user: &self.user,
+            temp_report_account: &mut self.temp_report_account,
+            contributor_data_account: &mut self.contributor_data_account,
+            aggregated_consensus_data_account: &mut self.aggregated_consensus_data_account,
+            consensus_summary_account: &mut self.consensus_summary_account,
+            reward_pool_account: &self.reward_pool_account,
+            slot_hashes: &self.slot_hashes,
+            system_program: &self.system_program,
+        }
+    }
 }
 
-#[account]
-pub struct OracleContractState {
-    pub is_initialized: bool,
-    pub admin_pubkey: Pubkey,
-    pub txid_submission_counts: Vec<TxidSubmissionCount>,
-    pub monitored_txids: Vec<String>,
-    pub reward_pool_account: Pubkey,
-    pub fee_receiving_contract_account: Pubkey,
-    pub txid_submission_counts_account: Pubkey,
-    pub aggregated_consensus_data_account: Pubkey,
-    pub bridge_contract_pubkey: Pubkey,
+pub fn submit_packed_data_report_helper<'info>(
+    ctx: Context<'_, '_, '_, 'info, SubmitPackedDataReport<'info>>,
+    report: PastelTxStatusReport,
+) -> Result<()> {
+    submit_report(
+        ctx.accounts.submission_accounts(),
+        ctx.bumps.reward_pool_account,
+        ctx.remaining_accounts,
+        report,
+    )
 }
 
+// Permissionless: anyone may pay for the consensus of a txid whose quorum is met
 #[derive(Accounts)]
-pub struct Initialize<'info> {
-    #[account(init, payer = user, space = 10_240)] // Adjusted space
-    pub oracle_contract_state: Account<'info, OracleContractState>,
+pub struct FinalizeConsensus<'info> {
     #[account(mut)]
-    pub user: Signer<'info>,
-
-    // Account for TempTxStatusReportAccount PDA
-    #[account(
-        init,
-        seeds = [b"temp_tx_status_report"],
-        bump,
-        payer = user,
-        space = 10_240
-    )]
-    pub temp_report_account: Account<'info, TempTxStatusReportAccount>,
+    pub caller: Signer<'info>,
 
-    // Account for ContributorDataAccount PDA
-    #[account(
-        init,
-        seeds = [b"contributor_data"],
-        bump,
-        payer = user,
-        space = 10_240
-    )]
-    pub contributor_data_account: Account<'info, ContributorDataAccount>,
+    #[account(mut, constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized)]
+    pub oracle_contract_state: Account<'info, OracleContractState>,
 
-    // Account for TxidSubmissionCountsAccount PDA
-    #[account(
-        init,
-        seeds = [b"txid_submission_counts"],
-        bump,
-        payer = user,
-        space = 10_240
-    )]
+    #[account(mut, seeds = [b"txid_submission_counts"], bump)]
     pub txid_submission_counts_account: Account<'info, TxidSubmissionCountsAccount>,
 
-    // Account for AggregatedConsensusDataAccount PDA
-    #[account(
-        init,
-        seeds = [b"aggregated_consensus_data"],
-        bump,
-        payer = user,
-        space = 10_240
-    )]
-    pub aggregated_consensus_data_account: Account<'info, AggregatedConsensusDataAccount>,
-
-    // System program is needed for account creation
-    pub system_program: Program<'info, System>,
-}
-
-impl<'info> Initialize<'info> {
-    pub fn initialize_oracle_state(&mut self) -> Result<()> {
-        msg!("Setting up Oracle Contract State");
+    #[account(mut, seeds = [b"temp_tx_status_report"], bump)]
+    pub temp_report_account: Account<'info, TempTxStatusReportAccount>,
 
-        let state = &mut self.oracle_contract_state;
-        // Ensure the oracle_contract_state is not already initialized
-        if state.is_initialized {
-            return Err(OracleError::AccountAlreadyInitialized.into());
-        }
+    #[account(mut, seeds = [b"contributor_data"], bump)]
+    pub contributor_data_account: Account<'info, ContributorDataAccount>,
 
-        state.is_initialized = true;
-        state.admin_pubkey = self.user.key();
-        msg!("Admin Pubkey set to: {:?}", self.user.key());
+    #[account(mut, seeds = [b"aggregated_consensus_data"], bump)]
+    pub aggregated_consensus_data_account: Account<'info, AggregatedConsensusDataAccount>,
 
-        state.monitored_txids = Vec::new();
-        msg!("Monitored Txids Vector initialized");
+    #[account(mut, seeds = [b"consensus_summary"], bump)]
+    pub consensus_summary_account: Account<'info, ConsensusSummaryAccount>,
 
-        state.bridge_contract_pubkey = Pubkey::default();
-        msg!("Bridge Contract Pubkey set to default");
+    /// CHECK: Canonical reward pool PDA holding lamports only, so it must stay owned by the system program; pays newly eligible contributors when auto-pay is on, and the finalization bounty
+    #[account(mut, seeds = [b"reward_pool"], bump, owner = system_program::ID @ OracleError::InvalidPoolAccountOwner)]
+    pub reward_pool_account: UncheckedAccount<'info>,
 
-        msg!("Oracle Contract State Initialization Complete");
-        Ok(())
-    }
-}
+    /// CHECK: The SlotHashes sysvar, read raw because it is too large to deserialize; seeds audit selection
+    #[account(address = slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
 
-#[derive(Accounts)]
-pub struct ReallocateOracleState<'info> {
-    #[account(mut, has_one = admin_pubkey)]
-    pub oracle_contract_state: Account<'info, OracleContractState>,
-    pub admin_pubkey: Signer<'info>,
     pub system_program: Program<'info, System>,
-    #[account(mut)]
-    pub temp_report_account: Account<'info, TempTxStatusReportAccount>,
-    #[account(mut)]
-    pub contributor_data_account: Account<'info, ContributorDataAccount>,
-    #[account(mut)]
-    pub txid_submission_counts_account: Account<'info, TxidSubmissionCountsAccount>,
-    #[account(mut)]
-    pub aggregated_consensus_data_account: Account<'info, AggregatedConsensusDataAccount>,
 }
 
-pub fn reallocate_temp_report_account(
-    temp_report_account: &mut Account<'_, TempTxStatusReportAccount>,
-) -> Result<()> {
-    // Define the threshold at which to reallocate (e.g., 90% full)
-    const REALLOCATION_THRESHOLD: f32 = 0.9;
-    const ADDITIONAL_SPACE: usize = 10_240;
-    const MAX_SIZE: usize = 100 * 1024;
-
-    let current_size = temp_report_account.to_account_info().data_len();
-    let current_usage =
-        temp_report_account.reports.len() * std::mem::size_of::<TempTxStatusReport>();
-    let usage_ratio = current_usage as f32 / current_size as f32;
+#[event]
+pub struct FinalizationBountyPaid {
+    pub txid: String,
+    pub caller: Pubkey,
+    pub amount: u64,
+}
 
-    if usage_ratio > REALLOCATION_THRESHOLD {
-        let new_size = std::cmp::min(current_size + ADDITIONAL_SPACE, MAX_SIZE);
-        temp_report_account
-            .to_account_info()
-            .realloc(new_size, false)?;
+// Pays the finalization bounty from the reward pool to the caller and returns the amount paid. The bounty
+// is skipped, without failing the finalization, when it is disabled, rewards are paused, or paying it would
+// take the pool below its bounty reserve, so bounties cannot drain the funds meant for contributors.
+fn pay_finalization_bounty<'info>(
+    state: &OracleContractState,
+    reward_pool_account: &AccountInfo<'info>,
+    reward_pool_bump: u8,
+    caller: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<u64> {
+    let bounty = state.finalization_bounty_lamports;
+    if bounty == 0 || state.rewards_paused {
+        return Ok(0);
+    }
+    let available = transferable_lamports(reward_pool_account, &Rent::get()?)
+        .saturating_sub(state.finalization_bounty_pool_reserve);
+    if bounty > available {
         msg!(
-            "TempTxStatusReportAccount reallocated to new size: {}",
-            new_size
+            "Finalization bounty skipped, reward pool is at its reserve: Caller: {}",
+            caller.key
         );
+        return Ok(0);
     }
 
-    Ok(())
+    transfer(
+        CpiContext::new(
+            system_program.clone(),
+            Transfer {
+                from: reward_pool_account.clone(),
+                to: caller.clone(),
+            },
+        )
+        .with_signer(&[&[b"reward_pool", &[reward_pool_bump]]]),
+        bounty,
+    )?;
+    Ok(bounty)
 }
 
-pub fn reallocate_contributor_data_account(
-    contributor_data_account: &mut Account<'_, ContributorDataAccount>,
-) -> Result<()> {
-    // Define the threshold at which to reallocate (e.g., 90% full)
-    const REALLOCATION_THRESHOLD: f32 = 0.9;
-    const ADDITIONAL_SPACE: usize = 10_240;
-    const MAX_SIZE: usize = 100 * 1024;
-
-    let current_size = contributor_data_account.to_account_info().data_len();
-    let current_usage =
-        contributor_data_account.contributors.len() * std::mem::size_of::<Contributor>();
-    let usage_ratio = current_usage as f32 / current_size as f32;
-
-    if usage_ratio > REALLOCATION_THRESHOLD {
-        let new_size = std::cmp::min(current_size + ADDITIONAL_SPACE, MAX_SIZE);
-        contributor_data_account
-            .to_account_info()
-            .realloc(new_size, false)?;
-        msg!(
-            "ContributorDataAccount reallocated to new size: {}",
-            new_size
-        );
+// Charges the submitter the configured submission fee into the reward pool and returns the amount charged
+fn collect_submission_fee<'info>(
+    state: &mut OracleContractState,
+    submitter: &AccountInfo<'info>,
+    reward_pool_account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<u64> {
+    let fee = state.submission_fee_lamports;
+    if fee == 0 {
+        return Ok(0);
     }
+    transfer(
+        CpiContext::new(
+            system_program.clone(),
+            Transfer {
+                from: submitter.clone(),
+                to: reward_pool_account.clone(),
+            },
+        ),
+        fee,
+    )?;
+    state.total_submission_fees_lamports = state.total_submission_fees_lamports.saturating_add(fee);
+    msg!(
+        "Submission fee collected: Submitter: {}, Amount: {}",
+        submitter.key,
+        fee
+    );
+    Ok(fee)
+}
 
-    Ok(())
+// Whether a txid has met its quorum, or aggregated past the maximum age, and still has reports waiting
+// for consensus
+pub fn is_consensus_pending(
+    state: &OracleContractState,
+    txid_submission_counts_account: &TxidSubmissionCountsAccount,
+    temp_report_account: &TempTxStatusReportAccount,
+    aggregated_data_account: &AggregatedConsensusDataAccount,
+    mature_contributor_count: usize,
+    txid: &str,
+    current_timestamp: u32,
+) -> bool {
+    (should_calculate_consensus(
+        state,
+        txid_submission_counts_account,
+        temp_report_account,
+        mature_contributor_count,
+        txid,
+        current_timestamp,
+    ) || aggregation_exceeds_max_age(state, aggregated_data_account, txid, current_timestamp))
+        && temp_report_account
+            .reports
+            .iter()
+            .any(|report| temp_report_account.reports_txid(report, txid))
 }
 
-pub fn reallocate_submission_counts_account(
-    submission_counts_account: &mut Account<'_, TxidSubmissionCountsAccount>,
+pub fn finalize_consensus_helper<'info>(
+    ctx: Context<'_, '_, '_, 'info, FinalizeConsensus<'info>>,
+    txid: String,
 ) -> Result<()> {
-    // Define the threshold at which to reallocate (e.g., 90% full)
-    const REALLOCATION_THRESHOLD: f32 = 0.9;
-    const ADDITIONAL_SPACE: usize = 10_240;
-    const MAX_SIZE: usize = 100 * 1024;
-
-    let current_size = submission_counts_account.to_account_info().data_len();
-    let current_usage = submission_counts_account.submission_counts.len()
-        * std::mem::size_of::<TxidSubmissionCount>();
-    let usage_ratio = current_usage as f32 / current_size as f32;
+    let remaining_accounts = ctx.remaining_accounts;
+    let reward_pool_bump = ctx.bumps.reward_pool_account;
+    let accounts = ctx.accounts;
+    let current_timestamp = Clock::get()?.unix_timestamp as u32;
+    if !is_consensus_pending(
+        &accounts.oracle_contract_state,
+        &accounts.txid_submission_counts_account,
+        &accounts.temp_report_account,
+        &accounts.aggregated_consensus_data_account,
+        accounts
+            .contributor_data_account
+            .mature_contributor_count(&accounts.oracle_contract_state, current_timestamp),
+        &txid,
+        current_timestamp,
+    ) {
+        return Err(OracleError::ConsensusNotReady.into());
+    }
+
+    let eligible_before = auto_pay_snapshot(
+        &accounts.oracle_contract_state,
+        &accounts.contributor_data_account,
+    );
+    let resolved = finalize_consensus(
+        &mut accounts.oracle_contract_state,
+        &mut accounts.txid_submission_counts_account,
+        &mut accounts.temp_report_account,
+        &mut accounts.contributor_data_account,
+        &mut accounts.aggregated_consensus_data_account,
+        &mut accounts.consensus_summary_account,
+        &txid,
+        &most_recent_slot_hash(&accounts.slot_hashes.try_borrow_data()?)?,
+    )?;
 
-    if usage_ratio > REALLOCATION_THRESHOLD {
-        let new_size = std::cmp::min(current_size + ADDITIONAL_SPACE, MAX_SIZE);
-        submission_counts_account
-            .to_account_info()
-            .realloc(new_size, false)?;
+    if let Some(eligible_before) = eligible_before {
+        let payee_accounts: Vec<AccountInfo<'info>> =
+            std::iter::once(accounts.caller.to_account_info())
+                .chain(remaining_accounts.iter().cloned())
+                .collect();
+        auto_pay_newly_eligible(
+            &mut accounts.oracle_contract_state,
+            &mut accounts.contributor_data_account,
+            &eligible_before,
+            &accounts.reward_pool_account.to_account_info(),
+            reward_pool_bump,
+            &payee_accounts,
+            &accounts.system_program.to_account_info(),
+        )?;
+    }
+    // Only an attempt that resolved the txid earns the bounty, so deferred attempts cannot drain the pool
+    if !resolved {
         msg!(
-            "TxidSubmissionCountsAccount reallocated to new size: {}",
-            new_size
+            "Consensus for TXID: {} deferred; no finalization bounty paid",
+            txid
         );
+        return Ok(());
     }
+    msg!(
+        "Consensus finalized for TXID: {} by {}",
+        txid,
+        accounts.caller.key()
+    );
 
-    Ok(())
-}
-
-pub fn reallocate_aggregated_consensus_data_account(
-    aggregated_consensus_data_account: &mut Account<'_, AggregatedConsensusDataAccount>,
-) -> Result<()> {
-    // Define the threshold at which to reallocate (e.g., 90% full)
-    const REALLOCATION_THRESHOLD: f32 = 0.9;
-    const ADDITIONAL_SPACE: usize = 10_240;
-    const MAX_SIZE: usize = 100 * 1024;
-
-    let current_size = aggregated_consensus_data_account
-        .to_account_info()
-        .data_len();
-    let current_usage = aggregated_consensus_data_account.consensus_data.len()
-        * std::mem::size_of::<AggregatedConsensusData>();
-    let usage_ratio = current_usage as f32 / current_size as f32;
-
-    if usage_ratio > REALLOCATION_THRESHOLD {
-        let new_size = std::cmp::min(current_size + ADDITIONAL_SPACE, MAX_SIZE);
-        aggregated_consensus_data_account
-            .to_account_info()
-            .realloc(new_size, false)?;
+    let bounty = pay_finalization_bounty(
+        &accounts.oracle_contract_state,
+        &accounts.reward_pool_account.to_account_info(),
+        reward_pool_bump,
+        &accounts.caller.to_account_info(),
+        &accounts.system_program.to_account_info(),
+    )?;
+    if bounty > 0 {
         msg!(
-            "AggregatedConsensusDataAccount reallocated to new size: {}",
-            new_size
+            "Finalization bounty paid: Caller: {}, Amount: {}",
+            accounts.caller.key(),
+            bounty
         );
+        emit!(FinalizationBountyPaid {
+            txid,
+            caller: accounts.caller.key(),
+            amount: bounty,
+        });
     }
-
     Ok(())
 }
 
-impl<'info> ReallocateOracleState<'info> {
-    pub fn execute(ctx: Context<ReallocateOracleState>) -> Result<()> {
-        let oracle_contract_state = &mut ctx.accounts.oracle_contract_state;
+#[derive(Accounts)]
+#[instruction(txid: String)]
+pub struct UpdateDataReport<'info> {
+    #[account(
+        mut,
+        seeds = [create_seed("pastel_tx_status_report", &txid, &user.key()).as_ref()],
+        bump,
+    )]
+    pub report_account: Account<'info, PastelTxStatusReportAccount>,
 
-        // Calculate new size; add 10,240 bytes for each reallocation
-        // Ensure not to exceed 100KB total size
-        let current_size = oracle_contract_state.to_account_info().data_len();
-        let additional_space = 10_240; // Increment size
-        let max_size = 100 * 1024; // 100KB
-        let new_size = std::cmp::min(current_size + additional_space, max_size);
+    #[account(mut, constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized)]
+    pub oracle_contract_state: Account<'info, OracleContractState>,
 
-        // Perform reallocation
-        oracle_contract_state
-            .to_account_info()
-            .realloc(new_size, false)?;
+    #[account(mut)]
+    pub user: Signer<'info>,
 
-        msg!("OracleContractState reallocated to new size: {}", new_size);
+    #[account(mut, seeds = [b"temp_tx_status_report"], bump)]
+    pub temp_report_account: Account<'info, TempTxStatusReportAccount>,
 
-        reallocate_temp_report_account(&mut ctx.accounts.temp_report_account)?;
-        reallocate_contributor_data_account(&mut ctx.accounts.contributor_data_account)?;
-        reallocate_submission_counts_account(&mut ctx.accounts.txid_submission_counts_account)?;
-        reallocate_aggregated_consensus_data_account(
-            &mut ctx.accounts.aggregated_consensus_data_account,
-        )?;
-        Ok(())
-    }
+    #[account(mut, seeds = [b"contributor_data"], bump)]
+    pub contributor_data_account: Account<'info, ContributorDataAccount>,
+
+    // Reports stay correctable until consensus is finalized, so also while late reports are collected in the cool-down
+    #[account(
+        mut,
+        seeds = [b"aggregated_consensus_data"],
+        bump,
+        constraint = !is_consensus_finalized(&aggregated_consensus_data_account, &normalize_txid(&txid)) @ OracleError::ConsensusAlreadyReached
+    )]
+    pub aggregated_consensus_data_account: Account<'info, AggregatedConsensusDataAccount>,
+
+    pub system_program: Program<'info, System>,
 }
 
-#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
-pub struct HashWeight {
-    pub hash: String,
-    pub weight: u64,
+// Whether consensus has already been applied for the txid; its aggregation then no longer takes changes
+pub fn is_consensus_finalized(
+    aggregated_data_account: &AggregatedConsensusDataAccount,
+    txid: &str,
+) -> bool {
+    get_aggregated_data(aggregated_data_account, txid)
+        .is_some_and(|data| data.last_consensus_slot != 0)
 }
 
-// Function to update hash weight
-fn update_hash_weight(hash_weights: &mut Vec<HashWeight>, hash: &str, weight: u64) {
-    let mut found = false;
+// Replaces the contributor's existing temp report for the report's txid, moving its weight in the
+// aggregated consensus data from the old status/hash to the new one
+fn replace_temp_report(
+    oracle_contract_state: &OracleContractState,
+    temp_report_account: &mut TempTxStatusReportAccount,
+    aggregated_data_account: &mut AggregatedConsensusDataAccount,
+    report: &PastelTxStatusReport,
+    weight: u64,
+    current_timestamp: u32,
+) -> Result<()> {
+    let report_index = temp_report_account
+        .reports
+        .iter()
+        .position(|temp_report| {
+            temp_report.specific_data.contributor_reward_address
+                == report.contributor_reward_address
+                && temp_report_account.reports_txid(temp_report, &report.txid)
+        })
+        .ok_or(OracleError::ReportNotFound)?;
+
+    // Subtract the weight the previous report contributed
+    let old_temp_report = &temp_report_account.reports[report_index];
+    let old_common_data = temp_report_account.common_data_of(old_temp_report)?.clone();
+    remove_consensus_data_weight(
+        oracle_contract_state,
+        aggregated_data_account,
+        &old_common_data,
+        old_temp_report.specific_data.weight,
+    )?;
 
-    for hash_weight in hash_weights.iter_mut() {
-        if hash_weight.hash.as_str() == hash {
-            hash_weight.weight += weight;
-            found = true;
-            break;
-        }
-    }
+    // Add the weight of the new report
+    let effective_weight = aggregate_consensus_data(
+        oracle_contract_state,
+        aggregated_data_account,
+        report,
+        weight,
+        &report.txid,
+        current_timestamp,
+    )?;
 
-    if !found {
-        hash_weights.push(HashWeight {
-            hash: hash.to_string(), // Clone only when necessary
-            weight,
-        });
-    }
-}
+    let common_data = CommonReportData {
+        txid: report.txid.clone(),
+        txid_status: report.txid_status,
+        pastel_ticket_type: report.pastel_ticket_type,
+        first_6_characters_of_sha3_256_hash_of_corresponding_file: report
+            .first_6_characters_of_sha3_256_hash_of_corresponding_file
+            .clone(),
+    };
+    let common_data_index = find_or_add_common_report_data(temp_report_account, &common_data);
 
-// Struct to hold aggregated data for consensus calculation
-#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
-pub struct AggregatedConsensusData {
-    pub txid: String,
-    pub status_weights: [u64; TXID_STATUS_VARIANT_COUNT],
-    pub hash_weights: Vec<HashWeight>,
-    pub first_6_characters_of_sha3_256_hash_of_corresponding_file: String,
-    pub last_updated: u32, // Unix timestamp indicating the last update time
-}
+    temp_report_account.reports[report_index] = TempTxStatusReport {
+        common_data_ref: common_data_index,
+        specific_data: SpecificReportData {
+            contributor_reward_address: report.contributor_reward_address,
+            timestamp: report.timestamp,
+            common_data_ref: common_data_index,
+            weight: effective_weight,
+        },
+    };
 
-#[derive(Accounts)]
-pub struct RequestReward<'info> {
-    /// CHECK: OK
-    #[account(mut, seeds = [b"reward_pool"], bump)]
-    pub reward_pool_account: UncheckedAccount<'info>,
-    #[account(mut)]
-    pub oracle_contract_state: Account<'info, OracleContractState>,
-    #[account(mut)]
-    pub contributor_data_account: Account<'info, ContributorDataAccount>,
-    /// CHECK: This is the account we're transferring lamports to
-    #[account(mut)]
-    pub contributor: UncheckedAccount<'info>,
-    pub system_program: Program<'info, System>,
+    Ok(())
 }
 
-pub fn request_reward_helper(
-    ctx: Context<RequestReward>,
-    contributor_address: Pubkey,
+pub fn update_data_report_helper(
+    ctx: Context<UpdateDataReport>,
+    report: PastelTxStatusReport,
 ) -> Result<()> {
-    let contributor_data_account = &ctx.accounts.contributor_data_account;
-    let reward_pool_account = &ctx.accounts.reward_pool_account;
-    let contributor_account = &ctx.accounts.contributor;
-
-    // Find the contributor in the PDA and check eligibility
-    let contributor = contributor_data_account
-        .contributors
-        .iter()
-        .find(|c| c.reward_address == contributor_address)
-        .ok_or(OracleError::UnregisteredOracle)?;
+    validate_data_contributor_report(&report, &ctx.accounts.oracle_contract_state)?;
 
-    let current_unix_timestamp = Clock::get()?.unix_timestamp as u32;
+    let current_timestamp = Clock::get()?.unix_timestamp as u32;
 
-    if !contributor.is_eligible_for_rewards {
-        msg!(
-            "Contributor is not eligible for rewards: {}",
-            contributor_address
-        );
-        return Err(OracleError::NotEligibleForReward.into());
-    }
+    let contributor = ctx
+        .accounts
+        .contributor_data_account
+        .find_contributor(&report.contributor_reward_address)
+        .ok_or(OracleError::ContributorNotRegistered)?;
 
-    if contributor.calculate_is_banned(current_unix_timestamp) {
-        msg!("Contributor is banned: {}", contributor_address);
+    if contributor.calculate_is_banned(current_timestamp) {
         return Err(OracleError::ContributorBanned.into());
     }
 
-    let reward_amount = BASE_REWARD_AMOUNT_IN_LAMPORTS;
-
-    // Ensure the reward pool has sufficient funds
-    if reward_pool_account.to_account_info().lamports() < reward_amount {
-        msg!("Insufficient funds in reward pool");
-        return Err(OracleError::InsufficientFunds.into());
-    }
+    let weight = contributor_report_weight(
+        &ctx.accounts.oracle_contract_state,
+        contributor,
+        current_timestamp,
+    );
 
-    // Transfer the reward from the reward pool to the contributor
-    transfer(
-        CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.reward_pool_account.to_account_info(),
-                to: contributor_account.to_account_info(),
-            },
-        )
-        .with_signer(&[&[b"reward_pool", &[ctx.bumps.reward_pool_account]]]),
-        reward_amount,
+    replace_temp_report(
+        &ctx.accounts.oracle_contract_state,
+        &mut ctx.accounts.temp_report_account,
+        &mut ctx.accounts.aggregated_consensus_data_account,
+        &report,
+        weight,
+        current_timestamp,
     )?;
 
     msg!(
-        "Paid out Valid Reward Request: Contributor: {}, Amount: {}",
-        contributor_address,
-        reward_amount
+        "Updated data report for txid {} from contributor {}: Status: {:?}, Hash: {:?}",
+        report.txid,
+        report.contributor_reward_address,
+        report.txid_status,
+        report.first_6_characters_of_sha3_256_hash_of_corresponding_file
     );
 
+    ctx.accounts.report_account.report = report;
+
     Ok(())
 }
 
 #[derive(Accounts)]
-pub struct RegisterNewDataContributor<'info> {
-    /// CHECK: Manual checks are performed in the instruction to ensure the contributor_account is valid and safe to use.
+#[instruction(txid: String)]
+pub struct HandleConsensus<'info> {
+    #[account(mut, constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized)]
+    pub oracle_contract_state: Account<'info, OracleContractState>,
+
     #[account(mut)]
-    pub contributor_account: Signer<'info>,
+    pub user: Signer<'info>,
 
-    /// CHECK: OK
-    #[account(mut, seeds = [b"reward_pool"], bump)]
-    pub reward_pool_account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
 
-    /// CHECK: OK
-    #[account(mut, seeds = [b"fee_receiving_contract"], bump)]
-    pub fee_receiving_contract_account: UncheckedAccount<'info>,
+#[account]
+pub struct PendingPaymentAccount {
+    pub pending_payment: PendingPayment,
+}
+
+#[derive(Accounts)]
+#[instruction(txid: String)]
+pub struct HandlePendingPayment<'info> {
+    #[account(
+        init_if_needed,
+        payer = user,
+        seeds = [create_seed("pending_payment", &txid, &user.key()).as_ref()],
+        bump,
+        space = 8 + std::mem::size_of::<PendingPayment>() + 64 // Adjusted for discriminator
+    )]
+    pub pending_payment_account: Account<'info, PendingPaymentAccount>,
+
+    #[account(mut, constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized)]
+    pub oracle_contract_state: Account<'info, OracleContractState>,
 
     #[account(mut)]
-    pub contributor_data_account: Account<'info, ContributorDataAccount>,
+    pub user: Signer<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
-pub fn register_new_data_contributor_helper(
-    ctx: Context<RegisterNewDataContributor>,
+pub fn add_pending_payment_helper(
+    ctx: Context<HandlePendingPayment>,
+    txid: String,
+    pending_payment: PendingPayment,
 ) -> Result<()> {
-    let contributor_data_account = &mut ctx.accounts.contributor_data_account;
-    msg!(
-        "Initiating new contributor registration: {}",
-        ctx.accounts.contributor_account.key()
-    );
+    validate_txid(&txid)?;
 
-    // Check if the contributor is already registered
-    if contributor_data_account
-        .contributors
-        .iter()
-        .any(|c| c.reward_address == *ctx.accounts.contributor_account.key)
+    let pending_payment_account = &mut ctx.accounts.pending_payment_account;
+
+    // Ensure the account is being initialized for the first time to avoid re-initialization
+    if !pending_payment_account.pending_payment.txid.is_empty()
+        && pending_payment_account.pending_payment.txid != txid
     {
-        msg!(
-            "Registration failed: Contributor already registered: {}",
-            ctx.accounts.contributor_account.key
-        );
-        return Err(OracleError::ContributorAlreadyRegistered.into());
+        return Err(OracleError::PendingPaymentAlreadyInitialized.into());
     }
 
-    msg!(
-        "Registration fee verified. Attempting to register new contributor {}",
-        ctx.accounts.contributor_account.key
-    );
+    // Ensure txid is correct and other fields are properly set
+    if pending_payment.txid != txid {
+        return Err(OracleError::InvalidTxid.into());
+    }
 
-    // Deduct the registration fee from the fee_receiving_contract_account and add it to the reward pool account
-    transfer(
-        CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            Transfer {
-                from: ctx
-                    .accounts
-                    .fee_receiving_contract_account
-                    .to_account_info(),
-                to: ctx.accounts.reward_pool_account.to_account_info(),
-            },
-        )
-        .with_signer(&[&[
-            b"fee_receiving_contract",
-            &[ctx.bumps.fee_receiving_contract_account],
-        ]]),
-        REGISTRATION_ENTRANCE_FEE_IN_LAMPORTS as u64,
-    )?;
+    // Store the pending payment in the account
+    pending_payment_account.pending_payment = pending_payment;
 
-    let last_active_timestamp = Clock::get()?.unix_timestamp as u32;
+    msg!(
+        "Pending payment account initialized: TXID: {}, Expected Amount: {}, Status: {:?}",
+        pending_payment_account.pending_payment.txid,
+        pending_payment_account.pending_payment.expected_amount,
+        pending_payment_account.pending_payment.payment_status
+    );
 
-    // Create and add the new contributor
-    let new_contributor = Contributor {
-        reward_address: *ctx.accounts.contributor_account.key,
-        registration_entrance_fee_transaction_signature: String::new(), // Replace with actual data if available
-        compliance_score: ONE,                                          // Initial compliance score
-        last_active_timestamp, // Set the last active timestamp to the current time
-        total_reports_submitted: 0, // Initially, no reports have been submitted
-        accurate_reports_count: 0, // Initially, no accurate reports
-        current_streak: 0,     // No streak at the beginning
-        reliability_score: ONE, // Initial reliability score
-        consensus_failures: 0, // No consensus failures at the start
-        ban_expiry: 0,         // No ban initially set
-        is_eligible_for_rewards: false, // Initially not eligible for rewards
-        is_recently_active: false, // Initially not considered active
-        is_reliable: false,    // Initially not considered reliable
-    };
+    Ok(())
+}
 
-    // Append the new contributor to the ContributorDataAccount
-    contributor_data_account.contributors.push(new_contributor);
+#[event]
+pub struct PendingPaymentResolved {
+    pub txid: String,
+    pub payer: Pubkey,
+    pub resolution: PaymentResolution,
+    pub rent_returned: u64,
+}
 
-    // Logging for debug purposes
+// Closes the payment account, returning its rent to the payer recorded on it
+fn close_pending_payment<'info>(
+    pending_payment_account: &Account<'info, PendingPaymentAccount>,
+    payer: AccountInfo<'info>,
+    resolution: PaymentResolution,
+) -> Result<()> {
+    let pending_payment = &pending_payment_account.pending_payment;
+    if payer.key() != pending_payment.payer {
+        return Err(OracleError::InvalidPaymentPayer.into());
+    }
+    let rent_returned = pending_payment_account.to_account_info().lamports();
+    pending_payment_account.close(payer)?;
     msg!(
-        "New Contributor successfully Registered: Address: {}, Timestamp: {}",
-        ctx.accounts.contributor_account.key,
-        last_active_timestamp
+        "Pending payment for TXID {} resolved as {:?}; {} lamports returned to {}",
+        pending_payment.txid,
+        resolution,
+        rent_returned,
+        pending_payment.payer
     );
+    emit!(PendingPaymentResolved {
+        txid: pending_payment.txid.clone(),
+        payer: pending_payment.payer,
+        resolution,
+        rent_returned,
+    });
     Ok(())
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize)]
-pub struct AddTxidForMonitoringData {
-    pub txid: String,
+// An unpaid monitoring request past its expiry ends the txid's monitoring along with the payment
+pub fn expire_pending_payment(
+    state: &mut OracleContractState,
+    pending_payment: &PendingPayment,
+    current_timestamp: u32,
+) -> Result<()> {
+    if !pending_payment.is_expired(state, current_timestamp) {
+        return Err(OracleError::PaymentNotExpired.into());
+    }
+    if state.monitored_txids.contains(&pending_payment.txid) {
+        remove_monitored_txid(state, &pending_payment.txid)?;
+    }
+    Ok(())
 }
 
+// Permissionless: the rent only ever goes back to the recorded payer
 #[derive(Accounts)]
-pub struct AddTxidForMonitoring<'info> {
-    #[account(mut)]
-    pub oracle_contract_state: Account<'info, OracleContractState>,
-
-    /// CHECK: The caller is manually verified in the instruction logic to ensure it's the correct and authorized account.
+pub struct ExpirePendingPayment<'info> {
     pub caller: Signer<'info>,
 
-    // The `pending_payment_account` will be initialized in the function
+    #[account(mut, constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized)]
+    pub oracle_contract_state: Account<'info, OracleContractState>,
+
     #[account(mut)]
     pub pending_payment_account: Account<'info, PendingPaymentAccount>,
 
+    /// CHECK: Checked in the handler against the payer recorded on the pending payment
     #[account(mut)]
-    pub user: Signer<'info>,
-    pub system_program: Program<'info, System>,
+    pub payment_payer: UncheckedAccount<'info>,
 }
 
-pub fn add_txid_for_monitoring_helper(
-    ctx: Context<AddTxidForMonitoring>,
-    data: AddTxidForMonitoringData,
-) -> Result<()> {
-    let state = &mut ctx.accounts.oracle_contract_state;
+pub fn expire_pending_payment_helper(ctx: Context<ExpirePendingPayment>) -> Result<()> {
+    expire_pending_payment(
+        &mut ctx.accounts.oracle_contract_state,
+        &ctx.accounts.pending_payment_account.pending_payment,
+        Clock::get()?.unix_timestamp as u32,
+    )?;
+    close_pending_payment(
+        &ctx.accounts.pending_payment_account,
+        ctx.accounts.payment_payer.to_account_info(),
+        PaymentResolution::Expired,
+    )
+}
 
-    if ctx.accounts.caller.key != &state.bridge_contract_pubkey {
-        return Err(OracleError::NotBridgeContractAddress.into());
+#[derive(Debug, Clone, Default, AnchorSerialize, AnchorDeserialize)]
+pub struct Contributor {
+    pub reward_address: Pubkey,
+    pub registration_entrance_fee_transaction_signature: String,
+    pub compliance_score: u64,
+    pub last_active_timestamp: u32,
+    pub total_reports_submitted: u32,
+    pub accurate_reports_count: u32,
+    pub current_streak: u32,
+    pub reliability_score: u64,
+    pub consensus_failures: u32,
+    pub ban_expiry: u32,
+    pub is_eligible_for_rewards: bool,
+    pub is_recently_active: bool,
+    pub is_reliable: bool,
+    pub total_rewards_earned_lamports: u64,
+    pub distinct_hash_mismatches: u32, // Consecutive reported file hashes that did not match consensus
+    pub stake_weight: u64, // Fixed-point stake weight set by the admin; only counts when the stake coefficient is non-zero
+    pub owed_participation_rewards_lamports: u64, // Participation rewards credited but not yet paid out
+    pub last_submission_timestamp: u32, // Unix timestamp of the contributor's latest report; 0 if none
+    pub is_deactivated: bool, // Idle for longer than the configured period; excluded from active counts until it reports or heartbeats
+    pub registered_at: u32, // Unix timestamp of registration; 0 for contributors registered before it was recorded
+    pub probation_until: u32, // Unix timestamp until which an unbanned contributor is on probation; 0 if never unbanned
+    pub report_window_start: u32, // Unix timestamp the current counted-reports window opened
+    pub report_window_count: u32, // Judged reports in the current window, including any past the cap
+    pub recent_outcomes: [ReportOutcome; RECENT_OUTCOMES_CAPACITY], // Ring buffer of the latest judged reports; empty slots have timestamp 0
+    pub recent_outcomes_head: u8, // Index in recent_outcomes the next outcome is written to
+}
+
+// Whether a report judged in a consensus round matched the consensus
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct ReportOutcome {
+    pub timestamp: u32,
+    pub was_accurate: bool,
+}
+
+// Contributors are kept sorted by reward address so lookups can binary search
+#[account]
+pub struct ContributorDataAccount {
+    pub contributors: Vec<Contributor>,
+}
+
+impl ContributorDataAccount {
+    // Invariant check: reward addresses are strictly increasing (sorted and unique)
+    pub fn contributors_sorted(&self) -> bool {
+        self.contributors
+            .windows(2)
+            .all(|pair| pair[0].reward_address < pair[1].reward_address)
     }
 
-    // Explicitly cast txid to String and ensure it meets requirements
-    let txid = data.txid.clone();
-    if txid.len() > MAX_TXID_LENGTH {
-        msg!("TXID exceeds maximum length.");
-        return Err(OracleError::InvalidTxid.into());
+    fn position_of(&self, reward_address: &Pubkey) -> std::result::Result<usize, usize> {
+        debug_assert!(self.contributors_sorted());
+        self.contributors
+            .binary_search_by_key(reward_address, |c| c.reward_address)
     }
 
-    // Add the TXID to the monitored list
-    state.monitored_txids.push(txid.clone());
+    pub fn find_contributor(&self, reward_address: &Pubkey) -> Option<&Contributor> {
+        self.position_of(reward_address)
+            .ok()
+            .map(|index| &self.contributors[index])
+    }
 
-    // Initialize pending_payment_account here using the txid
-    let pending_payment_account = &mut ctx.accounts.pending_payment_account;
-    pending_payment_account.pending_payment = PendingPayment {
-        txid: txid.clone(),
-        expected_amount: COST_IN_LAMPORTS_OF_ADDING_PASTEL_TXID_FOR_MONITORING,
-        payment_status: PaymentStatus::Pending, // Enum, no need for casting
-    };
+    // Contributors that are not idle
+    pub fn active_contributor_count(&self) -> usize {
+        self.contributors
+            .iter()
+            .filter(|c| !c.is_deactivated)
+            .count()
+    }
 
-    msg!(
-        "Added Pastel TXID for Monitoring: {}",
-        pending_payment_account.pending_payment.txid
-    );
-    Ok(())
-}
+    // Contributors that count toward quorum auto-scaling: active and registered for at least the
+    // activation delay, so a burst of fresh registrations cannot inflate the quorum
+    pub fn mature_contributor_count(
+        &self,
+        oracle_contract_state: &OracleContractState,
+        current_timestamp: u32,
+    ) -> usize {
+        self.contributors
+            .iter()
+            .filter(|c| {
+                !c.is_deactivated
+                    && current_timestamp.saturating_sub(c.registered_at)
+                        >= oracle_contract_state.contributor_activation_delay
+            })
+            .count()
+    }
 
-#[derive(Accounts)]
-pub struct ProcessPastelTxStatusReport<'info> {
-    #[account(mut)]
-    pub oracle_contract_state: Account<'info, OracleContractState>,
+    pub fn find_contributor_mut(&mut self, reward_address: &Pubkey) -> Option<&mut Contributor> {
+        self.position_of(reward_address)
+            .ok()
+            .map(|index| &mut self.contributors[index])
+    }
 
-    /// CHECK: Manual checks are performed in the instruction to ensure the contributor is valid and authorized. This includes verifying signatures and other relevant validations.
-    #[account(mut)]
-    pub contributor: Signer<'info>,
-    // You can add other accounts as needed
-}
-
-pub fn should_calculate_consensus(
-    txid_submission_counts_account: &Account<TxidSubmissionCountsAccount>,
-    txid: &str,
-) -> Result<bool> {
-    // Retrieve the count of submissions and last updated timestamp for the given txid
-    let (submission_count, last_updated) = txid_submission_counts_account
-        .submission_counts
-        .iter()
-        .find(|c| c.txid == txid)
-        .map(|c| (c.count, c.last_updated))
-        .unwrap_or((0, 0));
-
-    // Check if the minimum threshold of reports is met
-    let min_threshold_met = submission_count >= MIN_NUMBER_OF_ORACLES as u32;
-
-    // Get the current unix timestamp from the Solana clock
-    let current_unix_timestamp = Clock::get()?.unix_timestamp as u32;
-
-    // Check if N minutes have elapsed since the last update
-    let max_waiting_period_elapsed_for_txid = current_unix_timestamp - last_updated
-        >= MAX_DURATION_IN_SECONDS_FROM_LAST_REPORT_SUBMISSION_BEFORE_COMPUTING_CONSENSUS;
+    pub fn insert_contributor(&mut self, contributor: Contributor) -> Result<()> {
+        // Accounts written before contributors were kept sorted are put in order on the next insert
+        if !self.contributors_sorted() {
+            self.contributors.sort_by_key(|c| c.reward_address);
+        }
+        match self.position_of(&contributor.reward_address) {
+            Ok(_) => Err(OracleError::ContributorAlreadyRegistered.into()),
+            Err(index) => {
+                self.contributors.insert(index, contributor);
+                Ok(())
+            }
+        }
+    }
 
-    // Calculate consensus if minimum threshold is met or if N minutes have passed with at least MIN_NUMBER_OF_ORACLES reports
-    Ok(min_threshold_met
-        || (max_waiting_period_elapsed_for_txid
-            && submission_count >= MIN_NUMBER_OF_ORACLES as u32))
+    // Moves a contributor to a new reward address, keeping its scores, counts and owed rewards
+    pub fn change_reward_address(
+        &mut self,
+        current_reward_address: &Pubkey,
+        new_reward_address: Pubkey,
+    ) -> Result<()> {
+        if self.find_contributor(&new_reward_address).is_some() {
+            return Err(OracleError::ContributorAlreadyRegistered.into());
+        }
+        let index = self
+            .position_of(current_reward_address)
+            .map_err(|_| OracleError::ContributorNotRegistered)?;
+        let mut contributor = self.contributors.remove(index);
+        contributor.reward_address = new_reward_address;
+        self.insert_contributor(contributor)
+    }
 }
 
-pub fn cleanup_old_submission_counts(state: &mut OracleContractState) -> Result<()> {
-    let current_time = Clock::get()?.unix_timestamp as u32;
-    state
-        .txid_submission_counts
-        .retain(|count| current_time - count.last_updated < SUBMISSION_COUNT_RETENTION_PERIOD);
-    Ok(())
+// Submission counts are kept sorted by txid, with one entry per txid, so lookups can binary search
+#[account]
+pub struct TxidSubmissionCountsAccount {
+    pub submission_counts: Vec<TxidSubmissionCount>,
 }
 
-pub fn usize_to_txid_status(index: usize) -> Option<TxidStatus> {
-    match index {
-        0 => Some(TxidStatus::Invalid),
-        1 => Some(TxidStatus::PendingMining),
-        2 => Some(TxidStatus::MinedPendingActivation),
-        3 => Some(TxidStatus::MinedActivated),
-        _ => None,
+impl TxidSubmissionCountsAccount {
+    // Invariant check: txids are strictly increasing (sorted and unique)
+    pub fn submission_counts_sorted(&self) -> bool {
+        self.submission_counts
+            .windows(2)
+            .all(|pair| pair[0].txid < pair[1].txid)
     }
-}
 
-// Function to handle the submission of Pastel transaction status reports
-pub fn validate_data_contributor_report(report: &PastelTxStatusReport) -> Result<()> {
-    // Direct return in case of invalid data, reducing nested if conditions
-    if report.txid.trim().is_empty() {
-        msg!("Error: InvalidTxid (TXID is empty)");
-        return Err(OracleError::InvalidTxid.into());
-    }
-    // Simplified TXID status validation
-    if !matches!(
-        report.txid_status,
-        TxidStatus::MinedActivated
-            | TxidStatus::MinedPendingActivation
-            | TxidStatus::PendingMining
-            | TxidStatus::Invalid
-    ) {
-        return Err(OracleError::InvalidTxidStatus.into());
+    fn position_of(&self, txid: &str) -> std::result::Result<usize, usize> {
+        debug_assert!(self.submission_counts_sorted());
+        self.submission_counts
+            .binary_search_by(|c| c.txid.as_str().cmp(txid))
     }
-    // Direct return in case of missing data, reducing nested if conditions
-    if report.pastel_ticket_type.is_none() {
-        msg!("Error: Missing Pastel Ticket Type");
-        return Err(OracleError::MissingPastelTicketType.into());
+
+    pub fn find_submission_count(&self, txid: &str) -> Option<&TxidSubmissionCount> {
+        self.position_of(txid)
+            .ok()
+            .map(|index| &self.submission_counts[index])
     }
-    // Direct return in case of invalid hash, reducing nested if conditions
-    if let Some(hash) = &report.first_6_characters_of_sha3_256_hash_of_corresponding_file {
-        if hash.len() != 6 || !hash.chars().all(|c| c.is_ascii_hexdigit()) {
-            msg!("Error: Invalid File Hash Length or Non-hex characters");
-            return Err(OracleError::InvalidFileHashLength.into());
+
+    pub fn record_submission(&mut self, txid: &str, current_timestamp: u32) {
+        match self.position_of(txid) {
+            Ok(index) => {
+                let count = &mut self.submission_counts[index];
+                count.count = count.count.saturating_add(1);
+                count.last_updated = current_timestamp;
+            }
+            Err(index) => self.submission_counts.insert(
+                index,
+                TxidSubmissionCount {
+                    txid: txid.to_string(),
+                    count: 1,
+                    last_updated: current_timestamp,
+                    quorum_reached_at: 0,
+                },
+            ),
         }
-    } else {
-        return Err(OracleError::MissingFileHash.into());
     }
-    Ok(())
-}
 
-impl Contributor {
-    // Check if the contributor is currently banned
-    pub fn calculate_is_banned(&self, current_time: u32) -> bool {
-        current_time < self.ban_expiry
+    // Starts the consensus cool-down clock the first time the txid's submissions meet the quorum
+    pub fn record_quorum_reached(&mut self, txid: &str, quorum: u32, current_timestamp: u32) {
+        if let Ok(index) = self.position_of(txid) {
+            let count = &mut self.submission_counts[index];
+            if count.quorum_reached_at == 0 && count.count >= quorum {
+                count.quorum_reached_at = current_timestamp;
+            }
+        }
     }
 
-    // Method to determine if the contributor is eligible for rewards
-    pub fn calculate_is_eligible_for_rewards(&self) -> bool {
-        self.total_reports_submitted >= MIN_REPORTS_FOR_REWARD
-            && self.reliability_score >= MIN_RELIABILITY_SCORE_FOR_REWARD
-            && self.compliance_score >= MIN_COMPLIANCE_SCORE_FOR_REWARD
+    // Restores the sorted, one-entry-per-txid invariant for accounts written by the old unsorted
+    // push-based code; duplicate entries are merged by summing counts and keeping the latest update.
+    // Returns the number of duplicate entries that were merged away.
+    pub fn repair_submission_counts(&mut self) -> u32 {
+        let original_len = self.submission_counts.len();
+        self.submission_counts.sort_by(|a, b| a.txid.cmp(&b.txid));
+        self.submission_counts.dedup_by(|duplicate, kept| {
+            if duplicate.txid != kept.txid {
+                return false;
+            }
+            kept.count = kept.count.saturating_add(duplicate.count);
+            kept.last_updated = kept.last_updated.max(duplicate.last_updated);
+            kept.quorum_reached_at = match (kept.quorum_reached_at, duplicate.quorum_reached_at) {
+                (0, reached_at) | (reached_at, 0) => reached_at,
+                (kept_at, duplicate_at) => kept_at.min(duplicate_at),
+            };
+            true
+        });
+        (original_len - self.submission_counts.len()) as u32
     }
 }
 
-#[derive(Accounts)]
-pub struct SetBridgeContract<'info> {
-    #[account(mut, has_one = admin_pubkey)]
-    pub oracle_contract_state: Account<'info, OracleContractState>,
-    pub admin_pubkey: Signer<'info>,
+// Reports aggregated for one ticket type since the start of its current volume window
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct TicketTypeVolume {
+    pub window_start: u32,
+    pub report_count: u32,
 }
 
-impl<'info> SetBridgeContract<'info> {
-    pub fn set_bridge_contract(
-        ctx: Context<SetBridgeContract>,
-        bridge_contract_pubkey: Pubkey,
-    ) -> Result<()> {
-        let state = &mut ctx.accounts.oracle_contract_state;
-        state.bridge_contract_pubkey = bridge_contract_pubkey;
-        msg!(
-            "Bridge contract pubkey updated: {:?}",
-            bridge_contract_pubkey
-        );
-        Ok(())
-    }
+#[account]
+pub struct AggregatedConsensusDataAccount {
+    pub consensus_data: Vec<AggregatedConsensusData>,
+    pub ticket_type_volumes: [TicketTypeVolume; PASTEL_TICKET_TYPE_VARIANT_COUNT], // Indexed by PastelTicketType
 }
 
-#[derive(Accounts)]
-#[instruction(txid: String)] // Include txid as part of the instruction
-pub struct ProcessPayment<'info> {
-    /// CHECK: This is checked in the handler function to verify it's the bridge contract.
-    pub source_account: Signer<'info>,
-
-    #[account(mut)]
-    pub oracle_contract_state: Account<'info, OracleContractState>,
-
-    #[account(
-        mut,
-        seeds = [create_seed("pending_payment", &txid, &source_account.key()).as_ref()],
-        bump // You won't explicitly include the bump here; it's handled by Anchor
-    )]
-    pub pending_payment_account: Account<'info, PendingPaymentAccount>,
+impl AggregatedConsensusDataAccount {
+    // Counts a report toward its ticket type's volume, starting a new window once the current one has lapsed
+    pub fn record_ticket_type_report(
+        &mut self,
+        pastel_ticket_type: PastelTicketType,
+        current_timestamp: u32,
+    ) {
+        let volume = &mut self.ticket_type_volumes[pastel_ticket_type as usize];
+        if current_timestamp.saturating_sub(volume.window_start) >= TICKET_TYPE_VOLUME_WINDOW {
+            *volume = TicketTypeVolume {
+                window_start: current_timestamp,
+                report_count: 0,
+            };
+        }
+        volume.report_count = volume.report_count.saturating_add(1);
+    }
 
-    pub system_program: Program<'info, System>,
+    pub fn recent_ticket_type_volume(
+        &self,
+        pastel_ticket_type: PastelTicketType,
+        current_timestamp: u32,
+    ) -> u32 {
+        let volume = &self.ticket_type_volumes[pastel_ticket_type as usize];
+        if current_timestamp.saturating_sub(volume.window_start) >= TICKET_TYPE_VOLUME_WINDOW {
+            0
+        } else {
+            volume.report_count
+        }
+    }
 }
 
-pub fn process_payment_helper(
-    ctx: Context<ProcessPayment>,
-    txid: String,
-    amount: u64,
-) -> Result<()> {
-    // Access the pending payment account using the txid as a seed
-    let pending_payment_account = &mut ctx.accounts.pending_payment_account;
+// Latest finalized consensus per txid, kept apart from the aggregated data so the bridge reads only this small account
+#[account]
+#[derive(Default)]
+pub struct ConsensusSummaryAccount {
+    pub summaries: Vec<ConsensusSummary>, // Survive the retention cleanup of consensus_data; bounded, oldest evicted first
+}
 
-    // Ensure the payment corresponds to the provided txid
-    if pending_payment_account.pending_payment.txid != txid {
-        return Err(OracleError::PaymentNotFound.into());
+impl ConsensusSummaryAccount {
+    pub fn find_consensus_summary(&self, txid: &str) -> Option<&ConsensusSummary> {
+        self.summaries.iter().find(|summary| summary.txid == txid)
     }
 
-    // Verify the payment amount matches the expected amount
-    if pending_payment_account.pending_payment.expected_amount != amount {
-        return Err(OracleError::InvalidPaymentAmount.into());
+    // Replaces the txid's summary, or adds one and evicts the oldest finalization once the bound is reached
+    pub fn record_consensus_summary(&mut self, summary: ConsensusSummary) {
+        if let Some(existing) = self
+            .summaries
+            .iter_mut()
+            .find(|existing| existing.txid == summary.txid)
+        {
+            *existing = summary;
+            return;
+        }
+        if self.summaries.len() >= MAX_CONSENSUS_SUMMARIES {
+            if let Some(oldest_index) = self
+                .summaries
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, existing)| existing.finalized_at)
+                .map(|(index, _)| index)
+            {
+                self.summaries.remove(oldest_index);
+            }
+        }
+        self.summaries.push(summary);
     }
+}
 
-    // Mark the payment as received
-    pending_payment_account.pending_payment.payment_status = PaymentStatus::Received;
-
-    Ok(())
+// Compact record of a txid's latest finalized consensus, small enough for the bridge to read per txid
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct ConsensusSummary {
+    pub txid: String,
+    pub consensus_status: TxidStatus,
+    pub consensus_hash: String,
+    pub confidence: u64, // Fixed-point share of the aggregated weight behind the consensus status
+    pub finalized_at: u32, // Unix timestamp of the consensus round
 }
 
-#[derive(Accounts)]
-pub struct WithdrawFunds<'info> {
-    #[account(
-        mut,
-        constraint = oracle_contract_state.admin_pubkey == *admin_account.key @ OracleError::UnauthorizedWithdrawalAccount,
-    )]
-    pub oracle_contract_state: Account<'info, OracleContractState>,
+#[account]
+#[derive(Default)]
+pub struct OracleContractState {
+    pub is_initialized: bool,
+    pub admin_pubkey: Pubkey,
+    pub txid_submission_counts: Vec<TxidSubmissionCount>,
+    pub monitored_txids: Vec<String>,
+    pub reward_pool_account: Pubkey,
+    pub fee_receiving_contract_account: Pubkey,
+    pub txid_submission_counts_account: Pubkey,
+    pub aggregated_consensus_data_account: Pubkey,
+    pub bridge_contract_pubkey: Pubkey, // Single bridge from before the allowlist; folded into bridge_contract_pubkeys on the next change
+    pub accuracy_policies: [AccuracyPolicy; PASTEL_TICKET_TYPE_VARIANT_COUNT], // Indexed by PastelTicketType
+    pub max_monitored_txids: u32,
+    pub min_quorum: u32,
+    pub quorum_auto_scaling_enabled: bool,
+    pub quorum_fraction: u64, // Fixed-point fraction of registered contributors required for quorum when auto-scaling
+    pub min_reports_for_reward: u32,
+    pub min_reliability_score_for_reward: u64,
+    pub min_compliance_score_for_reward: u64,
+    pub early_bird_multiplier: u64, // Fixed-point weight multiplier for the first submission of a txid; 1.0 disables it
+    pub early_bird_decay_period: u32, // Seconds after a txid is first seen over which the multiplier decays to 1.0
+    pub hash_mismatch_ban_threshold: u32, // Consecutive hash mismatches before a permanent ban; 0 disables it
+    pub compliance_weight_coefficient: u64, // Fixed-point coefficients of the report weight linear combination
+    pub reliability_weight_coefficient: u64,
+    pub stake_weight_coefficient: u64,
+    pub logistic_steepness: u64, // Fixed-point steepness of the logistic curve applied to compliance scores
+    pub logistic_midpoint: u64,  // Fixed-point compliance score at the center of the logistic curve
+    pub temp_report_retention_period: u32, // Seconds to keep temp reports for txids still awaiting consensus
+    pub aggregated_data_retention_period: u32, // Seconds to keep aggregated consensus data after its last update
+    pub submission_count_retention_period: u32, // Seconds to keep submission counts after their last update
+    pub reliability_bonus_factor: u64, // Fixed-point reward multiplier for reliable contributors; 1.0 disables it
+    pub tie_break_strategy: TieBreakStrategy,
+    pub auto_replenish_threshold: u64, // Reward pool balance below which it is topped up from the fee account; 0 disables it
+    pub auto_replenish_amount: u64,    // Lamports moved from the fee account per top-up
+    pub fee_account_reserve: u64, // Lamports kept in the fee account for the admin, on top of its rent-exempt minimum
+    pub file_hash_required: [bool; PASTEL_TICKET_TYPE_VARIANT_COUNT], // Indexed by PastelTicketType
+    pub participation_reward_lamports: u64, // Credited per consensus round to every participant, accurate or not
+    pub min_consensus_weight_sum: u64, // Fixed-point sum of report weights required before finalizing; 0 disables it
+    pub audit_selection_rate: u32, // One in this many consensus rounds is flagged for audit; 0 disables it
+    pub deferred_consensus: bool, // Leave consensus to finalize_consensus instead of the submission completing the quorum
+    pub max_submissions_per_txid: u32, // Reports recorded per txid beyond which submissions are rejected; 0 disables it
+    pub fee_split_bps: u16, // Share of each registration fee routed to the treasury, in basis points
+    pub contributor_idle_period: u32, // Seconds without a report or heartbeat before a contributor is deactivated; 0 disables it
+    pub split_consensus_margin: u64, // Fixed-point fraction of the winning weight within which the runner-up status is reported; 0 disables it
+    pub auto_pay_rewards: bool, // Pay contributors from the reward pool as soon as a consensus round makes them eligible
+    pub min_report_weight: u64, // Fixed-point floor on the weight a single report carries in consensus; 0 disables it
+    pub max_report_weight: u64, // Fixed-point ceiling on the weight a single report carries in consensus; 0 disables it
+    pub rewards_paused: bool,   // Freezes reward payouts while submissions and consensus carry on
+    pub streak_decay_window: u32, // Seconds of inactivity after which an accuracy streak restarts; 0 disables it
+    pub near_miss_hash_prefix_length: u8, // Leading hash characters that must match consensus for a near-miss; 0 disables it
+    pub bridge_contract_pubkeys: Vec<Pubkey>, // Bridges allowed to add txids for monitoring and process payments
+    pub consensus_cool_down: u32, // Seconds after the quorum is met during which late reports are still collected; 0 disables it
+    pub contributor_activation_delay: u32, // Seconds after registration before a contributor counts toward quorum scaling
+    pub under_reported_volume_threshold: u32, // Recent reports per ticket type below which participation is boosted; 0 disables it
+    pub under_reported_reward_boost: u64, // Fixed-point participation reward multiplier for under-reported ticket types
+    pub probation_period: u32,            // Seconds an unbanned contributor stays on probation
+    pub probation_weight_factor: u64, // Fixed-point multiplier on a contributor's report weight while on probation
+    pub consensus_chain_hash: [u8; 32], // Running hash over every finalized consensus, for tamper-evidence between snapshots
+    pub payment_expiry_period: u32, // Seconds a pending payment may stay unpaid before it can be expired; 0 disables expiry
+    pub status_weight_multiplier: u64, // Fixed-point multiplier on a report's weight in the status tally; 0 disables it
+    pub hash_weight_multiplier: u64, // Fixed-point multiplier on a report's weight in the file hash tally; 0 disables it
+    pub submission_quiet_period: u32, // Seconds after registration before a contributor's first report is accepted; 0 disables it
+    pub reward_mode: RewardMode,
+    pub report_count_reward_cap: u64, // Fixed-point cap on the report-count reward multiplier in ScaledByReportCount mode
+    pub blacklist: Vec<Pubkey>,       // Reward addresses that may never register or be moved to
+    pub finalization_bounty_lamports: u64, // Paid from the reward pool to whoever finalizes consensus; 0 disables it
+    pub finalization_bounty_pool_reserve: u64, // Lamports above rent exemption the reward pool keeps back from bounties
+    pub reliability_decay_enabled: bool, // Decays reliability by DECAY_RATE per day since the contributor was last seen
+    pub max_counted_reports_per_window: u32, // Reports per contributor per window that count toward eligibility; 0 disables the cap
+    pub counted_reports_window: u32,         // Seconds in a counted-reports window
+    pub max_aggregation_age: u32, // Seconds after a txid's first report before it is force-finalized or invalidated; 0 disables it
+    pub schema_version: u16,      // Layout version of this state; raised by migrate_schema
+    pub ticket_type_quorums: [u32; PASTEL_TICKET_TYPE_VARIANT_COUNT], // Indexed by PastelTicketType; replaces min_quorum for txids of that type, 0 if unset
+    pub max_active_reports_per_contributor: u32, // Reports a contributor may have awaiting consensus at once; 0 disables the cap
+    pub initial_compliance_score: u64, // Compliance score new contributors start from, below the reward threshold; 0 starts them at ONE
+    pub submission_fee_lamports: u64, // Charged to the submitter into the reward pool for each report; 0 disables it
+    pub total_submission_fees_lamports: u64, // Submission fees collected since initialization
+    pub total_registration_fees_lamports: u64, // Registration fees collected since initialization
+    pub total_pool_funding_lamports: u64, // Lamports deposited through fund_reward_pool since initialization
+    pub recency_weight_floor: u64, // Fixed-point weight multiplier for a txid's first submission, ramping to 1.0 for later ones; 1.0 disables it
+    pub recency_ramp_period: u32, // Seconds after a txid is first seen over which the recency multiplier ramps up to 1.0
+    pub total_rewards_paid_lamports: u64, // Rewards paid out of the reward pool since initialization, including to contributors since removed
+}
 
-    /// CHECK: The admin_account is manually verified in the instruction to ensure it's the correct and authorized account for withdrawal operations. This includes checking if the account matches the admin_pubkey stored in oracle_contract_state.
-    #[account(mut)]
-    pub admin_account: Signer<'info>,
+impl OracleContractState {
+    // The allowlist plus the legacy single bridge, until the latter is migrated
+    pub fn authorized_bridges(&self) -> impl Iterator<Item = &Pubkey> {
+        self.bridge_contract_pubkeys.iter().chain(
+            Some(&self.bridge_contract_pubkey).filter(|legacy| **legacy != Pubkey::default()),
+        )
+    }
 
-    /// CHECK: OK
-    #[account(mut, seeds = [b"reward_pool"], bump)]
-    pub reward_pool_account: UncheckedAccount<'info>,
-    /// CHECK: OK
-    #[account(mut, seeds = [b"fee_receiving_contract"], bump)]
-    pub fee_receiving_contract_account: UncheckedAccount<'info>,
+    pub fn is_authorized_bridge(&self, pubkey: &Pubkey) -> bool {
+        self.authorized_bridges().any(|bridge| bridge == pubkey)
+    }
 
-    pub system_program: Program<'info, System>,
-}
+    // Moves the bridge set through the old single-pubkey field into the allowlist
+    pub fn migrate_legacy_bridge_contract(&mut self) {
+        let legacy = std::mem::take(&mut self.bridge_contract_pubkey);
+        if legacy != Pubkey::default() && !self.bridge_contract_pubkeys.contains(&legacy) {
+            self.bridge_contract_pubkeys.push(legacy);
+        }
+    }
 
-impl<'info> WithdrawFunds<'info> {
-    pub fn execute(
-        ctx: Context<WithdrawFunds>,
-        reward_pool_amount: u64,
-        fee_receiving_amount: u64,
-    ) -> Result<()> {
-        if ctx.accounts.oracle_contract_state.admin_pubkey == ctx.accounts.admin_account.key() {
-            return Err(OracleError::UnauthorizedWithdrawalAccount.into()); // Check if the admin_account matches admin_pubkey stored in oracle_contract_state
+    // Brings a state written by an older build up to CURRENT_SCHEMA_VERSION, one version at a time. Fields a
+    // version appends read whatever bytes followed the old layout, so each step resets them to the defaults
+    // initialize sets. Steps that changed another account's layout are applied to it by MigrateSchema.
+    // Returns whether anything was migrated.
+    pub fn migrate_schema(&mut self) -> bool {
+        let from_version = self.schema_version;
+        if self.schema_version < 1 {
+            // Version 1 keeps bridges only in the allowlist
+            self.migrate_legacy_bridge_contract();
+            self.schema_version = 1;
         }
-        let reward_pool_account = &mut ctx.accounts.reward_pool_account;
-        let fee_receiving_contract_account = &mut ctx.accounts.fee_receiving_contract_account;
+        if self.schema_version < 2 {
+            // Version 2 adds per-ticket-type quorum overrides
+            self.ticket_type_quorums = [0; PASTEL_TICKET_TYPE_VARIANT_COUNT];
+            self.schema_version = 2;
+        }
+        if self.schema_version < 3 {
+            // Version 3 adds score snapshots to each aggregated consensus entry
+            self.schema_version = 3;
+        }
+        if self.schema_version < 4 {
+            // Version 4 caps the reports a contributor can have awaiting consensus
+            self.max_active_reports_per_contributor = DEFAULT_MAX_ACTIVE_REPORTS_PER_CONTRIBUTOR;
+            self.schema_version = 4;
+        }
+        if self.schema_version < 5 {
+            // Version 5 makes the starting compliance score configurable
+            self.initial_compliance_score = ONE;
+            self.schema_version = 5;
+        }
+        if self.schema_version < 6 {
+            // Version 6 adds the recent outcomes ring buffer to each contributor
+            self.schema_version = 6;
+        }
+        if self.schema_version < 7 {
+            // Version 7 adds the report submission fee
+            self.submission_fee_lamports = 0;
+            self.total_submission_fees_lamports = 0;
+            self.schema_version = 7;
+        }
+        if self.schema_version < 8 {
+            // Version 8 adds the reward economics totals, which only count from the migration on
+            self.total_registration_fees_lamports = 0;
+            self.total_pool_funding_lamports = 0;
+            self.schema_version = 8;
+        }
+        if self.schema_version < 9 {
+            // Version 9 adds recency decay of report weights, disabled
+            self.recency_weight_floor = ONE;
+            self.recency_ramp_period =
+                MAX_DURATION_IN_SECONDS_FROM_LAST_REPORT_SUBMISSION_BEFORE_COMPUTING_CONSENSUS;
+            self.schema_version = 9;
+        }
+        if self.schema_version < 10 {
+            // Version 10 keeps a lifetime total of rewards paid, which only counts from the migration on
+            self.total_rewards_paid_lamports = 0;
+            self.schema_version = 10;
+        }
+        if self.schema_version < 11 {
+            // Version 11 moves the consensus summaries into their own account
+            self.schema_version = 11;
+        }
+        self.schema_version != from_version
+    }
 
-        // Transfer SOL from the reward pool account to the admin account
-        if reward_pool_account.lamports() < reward_pool_amount {
-            return Err(OracleError::InsufficientFunds.into());
+    pub fn require_schema_version(&self, required_schema_version: u16) -> Result<()> {
+        if self.schema_version < required_schema_version {
+            msg!(
+                "Error: Schema version {} is required but the oracle state is at version {}",
+                required_schema_version,
+                self.schema_version
+            );
+            return Err(OracleError::SchemaVersionTooOld.into());
         }
-        transfer(
-            CpiContext::new(
-                ctx.accounts.system_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.reward_pool_account.to_account_info(),
-                    to: ctx.accounts.admin_account.to_account_info(),
-                },
-            )
-            .with_signer(&[&[b"reward_pool", &[ctx.bumps.reward_pool_account]]]),
-            reward_pool_amount,
-        )?;
+        Ok(())
+    }
 
-        // Transfer SOL from the fee receiving contract account to the admin account
-        if fee_receiving_contract_account.lamports() < fee_receiving_amount {
-            return Err(OracleError::InsufficientFunds.into());
+    pub fn add_bridge_contract(&mut self, bridge_contract_pubkey: Pubkey) -> Result<()> {
+        validate_role_assignment(&self.admin_pubkey, &bridge_contract_pubkey)?;
+        self.migrate_legacy_bridge_contract();
+        if self
+            .bridge_contract_pubkeys
+            .contains(&bridge_contract_pubkey)
+        {
+            return Ok(());
         }
-        transfer(
-            CpiContext::new(
-                ctx.accounts.system_program.to_account_info(),
-                Transfer {
-                    from: fee_receiving_contract_account.to_account_info(),
-                    to: ctx.accounts.admin_account.to_account_info(),
-                },
-            )
-            .with_signer(&[&[
-                b"fee_receiving_contract",
-                &[ctx.bumps.fee_receiving_contract_account],
-            ]]),
-            fee_receiving_amount,
-        )?;
+        if self.bridge_contract_pubkeys.len() >= MAX_BRIDGE_CONTRACTS {
+            return Err(OracleError::TooManyBridgeContracts.into());
+        }
+        self.bridge_contract_pubkeys.push(bridge_contract_pubkey);
+        Ok(())
+    }
 
-        msg!("Withdrawal successful: {} lamports transferred from reward pool and {} lamports from fee receiving contract to admin account", reward_pool_amount, fee_receiving_amount);
+    pub fn remove_bridge_contract(&mut self, bridge_contract_pubkey: &Pubkey) -> Result<()> {
+        self.migrate_legacy_bridge_contract();
+        let index = self
+            .bridge_contract_pubkeys
+            .iter()
+            .position(|bridge| bridge == bridge_contract_pubkey)
+            .ok_or(OracleError::BridgeContractNotFound)?;
+        self.bridge_contract_pubkeys.remove(index);
         Ok(())
     }
-}
 
-declare_id!("AfP1c4sFcY1FeiGjQEtyxCim8BRnw22okNbKAsH2sBsB");
+    pub fn record_registration_fee(&mut self, fee: u64) {
+        self.total_registration_fees_lamports =
+            self.total_registration_fees_lamports.saturating_add(fee);
+    }
 
-#[program]
-pub mod solana_pastel_oracle_program {
-    use super::*;
+    pub fn record_pool_funding(&mut self, amount: u64) {
+        self.total_pool_funding_lamports = self.total_pool_funding_lamports.saturating_add(amount);
+    }
 
-    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
-        msg!("Initializing Oracle Contract State");
-        ctx.accounts.initialize_oracle_state()?;
-        // msg!("Oracle Contract State Initialized with Admin Pubkey: {:?}", admin_pubkey);
+    pub fn record_reward_payout(&mut self, reward_amount: u64) {
+        self.total_rewards_paid_lamports = self
+            .total_rewards_paid_lamports
+            .saturating_add(reward_amount);
+    }
 
-        // Logging for Reward Pool and Fee Receiving Contract Accounts
-        // msg!("Reward Pool Account: {:?}", ctx.accounts.reward_pool_account.key());
-        // msg!("Fee Receiving Contract Account: {:?}", ctx.accounts.fee_receiving_contract_account.key());
-        msg!(
-            "Temp Report Account: {:?}",
-            ctx.accounts.temp_report_account.key()
-        );
-        msg!(
-            "Contributor Data Account: {:?}",
-            ctx.accounts.contributor_data_account.key()
-        );
-        msg!(
-            "Txid Submission Counts Account: {:?}",
-            ctx.accounts.txid_submission_counts_account.key()
-        );
-        msg!(
-            "Aggregated Consensus Data Account: {:?}",
-            ctx.accounts.aggregated_consensus_data_account.key()
-        );
+    pub fn is_blacklisted(&self, reward_address: &Pubkey) -> bool {
+        self.blacklist.contains(reward_address)
+    }
 
+    pub fn require_not_blacklisted(&self, reward_address: &Pubkey) -> Result<()> {
+        if self.is_blacklisted(reward_address) {
+            msg!("Error: Reward address {} is blacklisted", reward_address);
+            return Err(OracleError::AddressBlacklisted.into());
+        }
         Ok(())
     }
 
-    pub fn reallocate_oracle_state(ctx: Context<ReallocateOracleState>) -> Result<()> {
-        ReallocateOracleState::execute(ctx)
+    pub fn add_to_blacklist(&mut self, reward_address: Pubkey) -> Result<()> {
+        if self.is_blacklisted(&reward_address) {
+            return Ok(());
+        }
+        if self.blacklist.len() >= MAX_BLACKLISTED_ADDRESSES {
+            return Err(OracleError::TooManyBlacklistedAddresses.into());
+        }
+        self.blacklist.push(reward_address);
+        Ok(())
     }
 
-    pub fn register_new_data_contributor(ctx: Context<RegisterNewDataContributor>) -> Result<()> {
-        register_new_data_contributor_helper(ctx)
+    pub fn remove_from_blacklist(&mut self, reward_address: &Pubkey) -> Result<()> {
+        let index = self
+            .blacklist
+            .iter()
+            .position(|blacklisted| blacklisted == reward_address)
+            .ok_or(OracleError::BlacklistedAddressNotFound)?;
+        self.blacklist.remove(index);
+        Ok(())
+    }
+}
+
+// Upper bound on the lamports initialize spends on rent, ignoring any prefunding of the oracle state
+pub fn initialization_rent(rent: &Rent) -> u64 {
+    rent.minimum_balance(INITIAL_ACCOUNT_SPACE)
+        .saturating_mul(INITIALIZED_ACCOUNT_COUNT)
+}
+
+// Nested accounts are validated while Initialize is deserialized, before its init constraints allocate
+// anything, so an underfunded payer fails with InsufficientFunds instead of a system program error
+#[derive(Accounts)]
+pub struct InitializePayer<'info> {
+    #[account(
+        mut,
+        constraint = user.lamports() >= initialization_rent(&Rent::get()?) @ OracleError::InsufficientFunds
+    )]
+    pub user: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    pub payer: InitializePayer<'info>,
+
+    #[account(init, payer = payer.user, space = INITIAL_ACCOUNT_SPACE)]
+    pub oracle_contract_state: Account<'info, OracleContractState>,
+
+    // Account for TempTxStatusReportAccount PDA
+    #[account(
+        init,
+        seeds = [b"temp_tx_status_report"],
+        bump,
+        payer = payer.user,
+        space = INITIAL_ACCOUNT_SPACE
+    )]
+    pub temp_report_account: Account<'info, TempTxStatusReportAccount>,
+
+    // Account for ContributorDataAccount PDA
+    #[account(
+        init,
+        seeds = [b"contributor_data"],
+        bump,
+        payer = payer.user,
+        space = INITIAL_ACCOUNT_SPACE
+    )]
+    pub contributor_data_account: Account<'info, ContributorDataAccount>,
+
+    // Account for TxidSubmissionCountsAccount PDA
+    #[account(
+        init,
+        seeds = [b"txid_submission_counts"],
+        bump,
+        payer = payer.user,
+        space = INITIAL_ACCOUNT_SPACE
+    )]
+    pub txid_submission_counts_account: Account<'info, TxidSubmissionCountsAccount>,
+
+    // Account for AggregatedConsensusDataAccount PDA
+    #[account(
+        init,
+        seeds = [b"aggregated_consensus_data"],
+        bump,
+        payer = payer.user,
+        space = INITIAL_ACCOUNT_SPACE
+    )]
+    pub aggregated_consensus_data_account: Account<'info, AggregatedConsensusDataAccount>,
+
+    // Account for ConsensusSummaryAccount PDA
+    #[account(
+        init,
+        seeds = [b"consensus_summary"],
+        bump,
+        payer = payer.user,
+        space = INITIAL_ACCOUNT_SPACE
+    )]
+    pub consensus_summary_account: Account<'info, ConsensusSummaryAccount>,
+
+    // Account for AdminAuditLogAccount PDA
+    #[account(
+        init,
+        seeds = [b"admin_audit_log"],
+        bump,
+        payer = payer.user,
+        space = INITIAL_ACCOUNT_SPACE
+    )]
+    pub admin_audit_log: Account<'info, AdminAuditLogAccount>,
+
+    // System program is needed for account creation
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Initialize<'info> {
+    pub fn initialize_oracle_state(&mut self) -> Result<()> {
+        msg!("Setting up Oracle Contract State");
+
+        let state = &mut self.oracle_contract_state;
+        // Ensure the oracle_contract_state is not already initialized
+        if state.is_initialized {
+            return Err(OracleError::AccountAlreadyInitialized.into());
+        }
+
+        state.is_initialized = true;
+        state.admin_pubkey = self.payer.user.key();
+        msg!("Admin Pubkey set to: {:?}", self.payer.user.key());
+
+        state.monitored_txids = Vec::new();
+        msg!("Monitored Txids Vector initialized");
+
+        state.max_monitored_txids =
+            monitored_txids_capacity(state, state.to_account_info().data_len())?;
+        msg!("Max Monitored Txids set to: {}", state.max_monitored_txids);
+
+        state.min_quorum = MIN_NUMBER_OF_ORACLES;
+        state.quorum_auto_scaling_enabled = false;
+        state.quorum_fraction = ZERO;
+        msg!("Quorum set to: {}", MIN_NUMBER_OF_ORACLES);
+
+        state.min_reports_for_reward = MIN_REPORTS_FOR_REWARD;
+        state.min_reliability_score_for_reward = MIN_RELIABILITY_SCORE_FOR_REWARD;
+        state.min_compliance_score_for_reward = MIN_COMPLIANCE_SCORE_FOR_REWARD;
+        msg!("Reward eligibility thresholds set to defaults");
+
+        state.early_bird_multiplier = ONE;
+        state.early_bird_decay_period =
+            MAX_DURATION_IN_SECONDS_FROM_LAST_REPORT_SUBMISSION_BEFORE_COMPUTING_CONSENSUS;
+        msg!("Early-bird weight multiplier disabled");
+
+        state.hash_mismatch_ban_threshold = DEFAULT_HASH_MISMATCH_BAN_THRESHOLD;
+        msg!(
+            "Hash mismatch ban threshold set to: {}",
+            DEFAULT_HASH_MISMATCH_BAN_THRESHOLD
+        );
+
+        state.compliance_weight_coefficient = ONE;
+        state.reliability_weight_coefficient = ONE;
+        state.stake_weight_coefficient = ZERO;
+        msg!("Report weight set to compliance score plus reliability score");
+
+        state.logistic_steepness = ONE_TENTH;
+        state.logistic_midpoint = MID_COMPLIANCE_SCORE;
+        msg!(
+            "Logistic scaling set to: Steepness: {}, Midpoint: {}",
+            ONE_TENTH,
+            MID_COMPLIANCE_SCORE
+        );
+
+        state.temp_report_retention_period = DATA_RETENTION_PERIOD;
+        state.aggregated_data_retention_period = DATA_RETENTION_PERIOD;
+        state.submission_count_retention_period = SUBMISSION_COUNT_RETENTION_PERIOD;
+        msg!("Retention periods set to defaults");
+
+        state.reliability_bonus_factor = ONE;
+        msg!("Reliability reward bonus disabled");
+
+        state.tie_break_strategy = TieBreakStrategy::HighestOrdinal;
+        msg!("Consensus tie-break strategy set to HighestOrdinal");
+
+        state.auto_replenish_threshold = 0;
+        state.auto_replenish_amount = 0;
+        state.fee_account_reserve = 0;
+        msg!("Reward pool auto-replenishment disabled");
+
+        state.bridge_contract_pubkey = Pubkey::default();
+        state.bridge_contract_pubkeys = Vec::new();
+        msg!("No bridge contracts authorized");
+
+        state.accuracy_policies = [AccuracyPolicy::StatusAndHash; PASTEL_TICKET_TYPE_VARIANT_COUNT];
+        msg!("Accuracy policies set to StatusAndHash for all ticket types");
+
+        state.file_hash_required = [true; PASTEL_TICKET_TYPE_VARIANT_COUNT];
+        msg!("File hash required for all ticket types");
+
+        state.participation_reward_lamports = 0;
+        msg!("Participation reward disabled");
+
+        state.min_consensus_weight_sum = 0;
+        msg!("Minimum consensus weight sum disabled");
+
+        state.audit_selection_rate = 0;
+        msg!("Consensus audit selection disabled");
+
+        state.deferred_consensus = false;
+        msg!("Consensus runs on the submission that completes the quorum");
+
+        state.max_submissions_per_txid = DEFAULT_MAX_SUBMISSIONS_PER_TXID;
+        msg!(
+            "Max submissions per txid set to: {}",
+            DEFAULT_MAX_SUBMISSIONS_PER_TXID
+        );
+
+        state.fee_split_bps = 0;
+        msg!("Registration fees go entirely to the reward pool");
+
+        state.contributor_idle_period = DEFAULT_CONTRIBUTOR_IDLE_PERIOD;
+        msg!(
+            "Contributor idle period set to: {} seconds",
+            DEFAULT_CONTRIBUTOR_IDLE_PERIOD
+        );
+
+        state.split_consensus_margin = 0;
+        msg!("Split consensus runner-up reporting disabled");
+
+        state.auto_pay_rewards = false;
+        msg!("Auto-pay of rewards on eligibility disabled");
+
+        state.min_report_weight = 0;
+        state.max_report_weight = 0;
+        msg!("Report weight floor and ceiling disabled");
+
+        state.rewards_paused = false;
+        msg!("Reward claims enabled");
+
+        state.streak_decay_window = DEFAULT_STREAK_DECAY_WINDOW;
+        msg!(
+            "Streak decay window set to: {} seconds",
+            DEFAULT_STREAK_DECAY_WINDOW
+        );
+
+        state.consensus_cool_down = 0;
+        msg!("Consensus cool-down disabled");
+
+        state.contributor_activation_delay = DEFAULT_CONTRIBUTOR_ACTIVATION_DELAY;
+        msg!(
+            "Contributor activation delay set to: {} seconds",
+            DEFAULT_CONTRIBUTOR_ACTIVATION_DELAY
+        );
+
+        state.under_reported_volume_threshold = 0;
+        state.under_reported_reward_boost = ONE;
+        msg!("Under-reported ticket type reward boost disabled");
+
+        state.probation_period = DEFAULT_PROBATION_PERIOD;
+        state.probation_weight_factor = FIVE_TENTH;
+        msg!(
+            "Probation config set to: Period: {} seconds, Weight Factor: {}",
+            DEFAULT_PROBATION_PERIOD,
+            FIVE_TENTH
+        );
+
+        state.payment_expiry_period = DEFAULT_PAYMENT_EXPIRY_PERIOD;
+        msg!(
+            "Payment expiry period set to: {} seconds",
+            DEFAULT_PAYMENT_EXPIRY_PERIOD
+        );
+
+        state.status_weight_multiplier = ONE;
+        state.hash_weight_multiplier = ONE;
+        msg!("Status and hash tally weight multipliers set to: {}", ONE);
+
+        state.submission_quiet_period = DEFAULT_SUBMISSION_QUIET_PERIOD;
+        msg!(
+            "Submission quiet period set to: {} seconds",
+            DEFAULT_SUBMISSION_QUIET_PERIOD
+        );
+
+        state.reward_mode = RewardMode::Flat;
+        state.report_count_reward_cap = DEFAULT_REPORT_COUNT_REWARD_CAP;
+        msg!(
+            "Reward mode set to Flat; report-count reward cap set to: {}",
+            DEFAULT_REPORT_COUNT_REWARD_CAP
+        );
+
+        state.blacklist = Vec::new();
+        msg!("No reward addresses blacklisted");
+
+        state.finalization_bounty_lamports = 0;
+        state.finalization_bounty_pool_reserve = 0;
+        msg!("Finalization bounty disabled");
+
+        state.reliability_decay_enabled = false;
+        msg!("Reliability inactivity decay disabled");
+
+        state.max_counted_reports_per_window = 0;
+        state.counted_reports_window = DEFAULT_COUNTED_REPORTS_WINDOW;
+        msg!("Cap on reports counted toward eligibility disabled");
+
+        state.max_aggregation_age = DEFAULT_MAX_AGGREGATION_AGE;
+        msg!(
+            "Maximum aggregation age set to {} seconds",
+            DEFAULT_MAX_AGGREGATION_AGE
+        );
+
+        state.schema_version = CURRENT_SCHEMA_VERSION;
+        msg!("Schema version set to: {}", CURRENT_SCHEMA_VERSION);
+
+        state.ticket_type_quorums = [0; PASTEL_TICKET_TYPE_VARIANT_COUNT];
+        msg!("Every ticket type uses the global quorum");
+
+        state.max_active_reports_per_contributor = DEFAULT_MAX_ACTIVE_REPORTS_PER_CONTRIBUTOR;
+        msg!(
+            "Max active reports per contributor set to: {}",
+            DEFAULT_MAX_ACTIVE_REPORTS_PER_CONTRIBUTOR
+        );
+
+        state.initial_compliance_score = ONE;
+        msg!("Initial compliance score set to: {}", ONE);
+
+        state.submission_fee_lamports = 0;
+        state.total_submission_fees_lamports = 0;
+        msg!("Report submission fee disabled");
+
+        state.total_registration_fees_lamports = 0;
+        state.total_pool_funding_lamports = 0;
+        msg!("Reward economics totals reset");
+
+        state.recency_weight_floor = ONE;
+        state.recency_ramp_period =
+            MAX_DURATION_IN_SECONDS_FROM_LAST_REPORT_SUBMISSION_BEFORE_COMPUTING_CONSENSUS;
+        msg!("Recency weight decay disabled");
+
+        state.total_rewards_paid_lamports = 0;
+        msg!("Total rewards paid reset");
+
+        msg!("Oracle Contract State Initialization Complete");
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct ReallocateOracleState<'info> {
+    #[account(mut, has_one = admin_pubkey, constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized)]
+    pub oracle_contract_state: Account<'info, OracleContractState>,
+    pub admin_pubkey: Signer<'info>,
+    #[account(mut, seeds = [b"admin_audit_log"], bump)]
+    pub admin_audit_log: Account<'info, AdminAuditLogAccount>,
+    pub system_program: Program<'info, System>,
+    #[account(mut)]
+    pub temp_report_account: Account<'info, TempTxStatusReportAccount>,
+    #[account(mut)]
+    pub contributor_data_account: Account<'info, ContributorDataAccount>,
+    #[account(mut)]
+    pub txid_submission_counts_account: Account<'info, TxidSubmissionCountsAccount>,
+    #[account(mut)]
+    pub aggregated_consensus_data_account: Account<'info, AggregatedConsensusDataAccount>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReallocatableAccount {
+    OracleContractState,
+    TempReportAccount,
+    ContributorDataAccount,
+    SubmissionCountsAccount,
+    AggregatedConsensusDataAccount,
+}
+
+impl ReallocatableAccount {
+    fn max_size_exceeded_error(self) -> OracleError {
+        match self {
+            // The oracle state grows unconditionally and simply stops at the cap, so it shares the generic variant
+            ReallocatableAccount::OracleContractState => OracleError::MaxSizeExceeded,
+            ReallocatableAccount::TempReportAccount => {
+                OracleError::TempReportAccountMaxSizeExceeded
+            }
+            ReallocatableAccount::ContributorDataAccount => {
+                OracleError::ContributorDataAccountMaxSizeExceeded
+            }
+            ReallocatableAccount::SubmissionCountsAccount => {
+                OracleError::SubmissionCountsAccountMaxSizeExceeded
+            }
+            ReallocatableAccount::AggregatedConsensusDataAccount => {
+                OracleError::AggregatedConsensusDataAccountMaxSizeExceeded
+            }
+        }
+    }
+
+    fn underfunded_error(self) -> OracleError {
+        match self {
+            ReallocatableAccount::OracleContractState => {
+                OracleError::OracleStateReallocationUnderfunded
+            }
+            ReallocatableAccount::TempReportAccount => {
+                OracleError::TempReportAccountReallocationUnderfunded
+            }
+            ReallocatableAccount::ContributorDataAccount => {
+                OracleError::ContributorDataAccountReallocationUnderfunded
+            }
+            ReallocatableAccount::SubmissionCountsAccount => {
+                OracleError::SubmissionCountsAccountReallocationUnderfunded
+            }
+            ReallocatableAccount::AggregatedConsensusDataAccount => {
+                OracleError::AggregatedConsensusDataAccountReallocationUnderfunded
+            }
+        }
+    }
+}
+
+// Ensures the account already holds enough lamports to stay rent-exempt at the new size,
+// so an underfunded account fails with an error naming it instead of a runtime rent failure.
+pub fn check_reallocation_funding(
+    account: ReallocatableAccount,
+    new_size: usize,
+    lamports: u64,
+    rent: &Rent,
+) -> Result<()> {
+    if lamports < rent.minimum_balance(new_size) {
+        msg!(
+            "{:?} holds {} lamports but needs {} to reallocate to {} bytes",
+            account,
+            lamports,
+            rent.minimum_balance(new_size),
+            new_size
+        );
+        return Err(account.underfunded_error().into());
+    }
+    Ok(())
+}
+
+// Returns the size a nearly-full account should grow to, or None if it still has room
+pub fn compute_reallocation_size(
+    account: ReallocatableAccount,
+    current_size: usize,
+    current_usage: usize,
+    lamports: u64,
+    rent: &Rent,
+) -> Result<Option<usize>> {
+    let usage_ratio = current_usage as f32 / current_size as f32;
+    if usage_ratio <= REALLOCATION_THRESHOLD {
+        return Ok(None);
+    }
+
+    if current_size >= MAX_REALLOCATED_ACCOUNT_SIZE {
+        msg!(
+            "{:?} is {:.0}% full and already at the maximum size of {} bytes",
+            account,
+            usage_ratio * 100.0,
+            MAX_REALLOCATED_ACCOUNT_SIZE
+        );
+        return Err(account.max_size_exceeded_error().into());
+    }
+
+    let new_size = std::cmp::min(
+        current_size + REALLOCATION_INCREMENT,
+        MAX_REALLOCATED_ACCOUNT_SIZE,
+    );
+    check_reallocation_funding(account, new_size, lamports, rent)?;
+    Ok(Some(new_size))
+}
+
+// Sizes the data accounts grow to, given as (account, current size, current usage, lamports). Every
+// account is checked before any is grown, so one failing its size or funding check leaves all untouched.
+pub fn plan_reallocations(
+    accounts: &[(ReallocatableAccount, usize, usize, u64)],
+    rent: &Rent,
+) -> Result<Vec<Option<usize>>> {
+    accounts
+        .iter()
+        .map(|&(account, current_size, current_usage, lamports)| {
+            compute_reallocation_size(account, current_size, current_usage, lamports, rent)
+        })
+        .collect()
+}
+
+pub fn temp_report_account_usage(temp_report_account: &TempTxStatusReportAccount) -> usize {
+    temp_report_account.reports.len() * TempTxStatusReport::SERIALIZED_LEN
+        + temp_report_account
+            .common_reports
+            .iter()
+            .map(CommonReportData::serialized_len)
+            .sum::<usize>()
+}
+
+pub fn contributor_data_account_usage(contributor_data_account: &ContributorDataAccount) -> usize {
+    contributor_data_account
+        .contributors
+        .iter()
+        .map(Contributor::serialized_len)
+        .sum()
+}
+
+pub fn submission_counts_account_usage(
+    submission_counts_account: &TxidSubmissionCountsAccount,
+) -> usize {
+    submission_counts_account
+        .submission_counts
+        .iter()
+        .map(TxidSubmissionCount::serialized_len)
+        .sum()
+}
+
+pub fn aggregated_consensus_data_account_usage(
+    aggregated_consensus_data_account: &AggregatedConsensusDataAccount,
+) -> usize {
+    aggregated_consensus_data_account
+        .consensus_data
+        .iter()
+        .map(AggregatedConsensusData::serialized_len)
+        .sum()
+}
+
+impl<'info> ReallocateOracleState<'info> {
+    pub fn execute(ctx: Context<ReallocateOracleState>) -> Result<()> {
+        let rent = Rent::get()?;
+        let oracle_contract_state = ctx.accounts.oracle_contract_state.to_account_info();
+
+        // Calculate new size; add 10,240 bytes for each reallocation
+        // Ensure not to exceed 100KB total size
+        let current_size = oracle_contract_state.data_len();
+        let new_size = std::cmp::min(
+            current_size + REALLOCATION_INCREMENT,
+            MAX_REALLOCATED_ACCOUNT_SIZE,
+        );
+        check_reallocation_funding(
+            ReallocatableAccount::OracleContractState,
+            new_size,
+            oracle_contract_state.lamports(),
+            &rent,
+        )?;
+
+        // Plan every data account's reallocation before growing any account
+        let data_accounts = [
+            (
+                ctx.accounts.temp_report_account.to_account_info(),
+                ReallocatableAccount::TempReportAccount,
+                temp_report_account_usage(&ctx.accounts.temp_report_account),
+            ),
+            (
+                ctx.accounts.contributor_data_account.to_account_info(),
+                ReallocatableAccount::ContributorDataAccount,
+                contributor_data_account_usage(&ctx.accounts.contributor_data_account),
+            ),
+            (
+                ctx.accounts
+                    .txid_submission_counts_account
+                    .to_account_info(),
+                ReallocatableAccount::SubmissionCountsAccount,
+                submission_counts_account_usage(&ctx.accounts.txid_submission_counts_account),
+            ),
+            (
+                ctx.accounts
+                    .aggregated_consensus_data_account
+                    .to_account_info(),
+                ReallocatableAccount::AggregatedConsensusDataAccount,
+                aggregated_consensus_data_account_usage(
+                    &ctx.accounts.aggregated_consensus_data_account,
+                ),
+            ),
+        ];
+        let planned_sizes = plan_reallocations(
+            &data_accounts
+                .iter()
+                .map(|(account_info, account, usage)| {
+                    (
+                        *account,
+                        account_info.data_len(),
+                        *usage,
+                        account_info.lamports(),
+                    )
+                })
+                .collect::<Vec<_>>(),
+            &rent,
+        )?;
+
+        // Perform reallocation
+        oracle_contract_state.realloc(new_size, false)?;
+        msg!("OracleContractState reallocated to new size: {}", new_size);
+
+        for ((account_info, account, _), planned_size) in data_accounts.iter().zip(planned_sizes) {
+            if let Some(planned_size) = planned_size {
+                account_info.realloc(planned_size, false)?;
+                msg!("{:?} reallocated to new size: {}", account, planned_size);
+            }
+        }
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::ReallocateOracleState,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!("new_size={}", new_size),
+        );
+        Ok(())
+    }
+}
+
+// Contributor layout before schema version 6 added the recent outcomes ring buffer
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct ContributorV5 {
+    pub reward_address: Pubkey,
+    pub registration_entrance_fee_transaction_signature: String,
+    pub compliance_score: u64,
+    pub last_active_timestamp: u32,
+    pub total_reports_submitted: u32,
+    pub accurate_reports_count: u32,
+    pub current_streak: u32,
+    pub reliability_score: u64,
+    pub consensus_failures: u32,
+    pub ban_expiry: u32,
+    pub is_eligible_for_rewards: bool,
+    pub is_recently_active: bool,
+    pub is_reliable: bool,
+    pub total_rewards_earned_lamports: u64,
+    pub distinct_hash_mismatches: u32,
+    pub stake_weight: u64,
+    pub owed_participation_rewards_lamports: u64,
+    pub last_submission_timestamp: u32,
+    pub is_deactivated: bool,
+    pub registered_at: u32,
+    pub probation_until: u32,
+    pub report_window_start: u32,
+    pub report_window_count: u32,
+}
+
+impl From<ContributorV5> for Contributor {
+    fn from(contributor: ContributorV5) -> Self {
+        Contributor {
+            reward_address: contributor.reward_address,
+            registration_entrance_fee_transaction_signature: contributor
+                .registration_entrance_fee_transaction_signature,
+            compliance_score: contributor.compliance_score,
+            last_active_timestamp: contributor.last_active_timestamp,
+            total_reports_submitted: contributor.total_reports_submitted,
+            accurate_reports_count: contributor.accurate_reports_count,
+            current_streak: contributor.current_streak,
+            reliability_score: contributor.reliability_score,
+            consensus_failures: contributor.consensus_failures,
+            ban_expiry: contributor.ban_expiry,
+            is_eligible_for_rewards: contributor.is_eligible_for_rewards,
+            is_recently_active: contributor.is_recently_active,
+            is_reliable: contributor.is_reliable,
+            total_rewards_earned_lamports: contributor.total_rewards_earned_lamports,
+            distinct_hash_mismatches: contributor.distinct_hash_mismatches,
+            stake_weight: contributor.stake_weight,
+            owed_participation_rewards_lamports: contributor.owed_participation_rewards_lamports,
+            last_submission_timestamp: contributor.last_submission_timestamp,
+            is_deactivated: contributor.is_deactivated,
+            registered_at: contributor.registered_at,
+            probation_until: contributor.probation_until,
+            report_window_start: contributor.report_window_start,
+            report_window_count: contributor.report_window_count,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ContributorDataAccountV5 {
+    pub contributors: Vec<ContributorV5>,
+}
+
+// Aggregated consensus entry layout before schema version 3 added score snapshots
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct AggregatedConsensusDataV2 {
+    pub txid: String,
+    pub status_weights: [u64; TXID_STATUS_VARIANT_COUNT],
+    pub hash_weights: Vec<HashWeight>,
+    pub first_6_characters_of_sha3_256_hash_of_corresponding_file: String,
+    pub last_updated: u32,
+    pub first_seen: u32,
+    pub status_first_seen: [u32; TXID_STATUS_VARIANT_COUNT],
+    pub flagged_for_audit: bool,
+    pub last_consensus_slot: u64,
+    pub failed_consensus_attempts: u32,
+    pub next_retry_after: u32,
+}
+
+impl From<AggregatedConsensusDataV2> for AggregatedConsensusData {
+    fn from(data: AggregatedConsensusDataV2) -> Self {
+        AggregatedConsensusData {
+            txid: data.txid,
+            status_weights: data.status_weights,
+            hash_weights: data.hash_weights,
+            first_6_characters_of_sha3_256_hash_of_corresponding_file: data
+                .first_6_characters_of_sha3_256_hash_of_corresponding_file,
+            last_updated: data.last_updated,
+            first_seen: data.first_seen,
+            status_first_seen: data.status_first_seen,
+            flagged_for_audit: data.flagged_for_audit,
+            last_consensus_slot: data.last_consensus_slot,
+            failed_consensus_attempts: data.failed_consensus_attempts,
+            next_retry_after: data.next_retry_after,
+            score_snapshots: Vec::new(),
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AggregatedConsensusDataAccountV2 {
+    pub consensus_data: Vec<AggregatedConsensusDataV2>,
+    pub summaries: Vec<ConsensusSummary>,
+    pub ticket_type_volumes: [TicketTypeVolume; PASTEL_TICKET_TYPE_VARIANT_COUNT],
+}
+
+// Aggregated consensus data account layout before schema version 11 moved the summaries to their own account
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AggregatedConsensusDataAccountV10 {
+    pub consensus_data: Vec<AggregatedConsensusData>,
+    pub summaries: Vec<ConsensusSummary>,
+    pub ticket_type_volumes: [TicketTypeVolume; PASTEL_TICKET_TYPE_VARIANT_COUNT],
+}
+
+// Decodes the body of an account still in an older layout, after checking its discriminator
+fn deserialize_legacy_account<T: AnchorDeserialize>(
+    data: &[u8],
+    discriminator: &[u8],
+) -> Result<T> {
+    if data.len() < discriminator.len() || &data[..discriminator.len()] != discriminator {
+        return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+    }
+    T::deserialize(&mut &data[discriminator.len()..])
+        .map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+}
+
+// The contributor data account rewritten in the current layout, or None if its layout has not changed since
+// from_version
+pub fn migrate_contributor_data(
+    from_version: u16,
+    data: &[u8],
+) -> Result<Option<ContributorDataAccount>> {
+    if from_version >= 6 {
+        return Ok(None);
+    }
+    let legacy: ContributorDataAccountV5 =
+        deserialize_legacy_account(data, &ContributorDataAccount::DISCRIMINATOR)?;
+    Ok(Some(ContributorDataAccount {
+        contributors: legacy
+            .contributors
+            .into_iter()
+            .map(Contributor::from)
+            .collect(),
+    }))
+}
+
+// The aggregated consensus data account rewritten in the current layout, along with the consensus summaries
+// it held before they moved to their own account, or None if its layout has not changed since from_version
+pub fn migrate_aggregated_consensus_data(
+    from_version: u16,
+    data: &[u8],
+) -> Result<Option<(AggregatedConsensusDataAccount, Vec<ConsensusSummary>)>> {
+    if from_version >= 11 {
+        return Ok(None);
+    }
+    let discriminator = &AggregatedConsensusDataAccount::DISCRIMINATOR;
+    if from_version < 3 {
+        let legacy: AggregatedConsensusDataAccountV2 =
+            deserialize_legacy_account(data, discriminator)?;
+        return Ok(Some((
+            AggregatedConsensusDataAccount {
+                consensus_data: legacy
+                    .consensus_data
+                    .into_iter()
+                    .map(AggregatedConsensusData::from)
+                    .collect(),
+                ticket_type_volumes: legacy.ticket_type_volumes,
+            },
+            legacy.summaries,
+        )));
+    }
+    let legacy: AggregatedConsensusDataAccountV10 =
+        deserialize_legacy_account(data, discriminator)?;
+    Ok(Some((
+        AggregatedConsensusDataAccount {
+            consensus_data: legacy.consensus_data,
+            ticket_type_volumes: legacy.ticket_type_volumes,
+        },
+        legacy.summaries,
+    )))
+}
+
+// Writes a migrated account over its old layout, growing it first if the new layout no longer fits
+fn write_migrated_account<T: AccountSerialize>(
+    account_info: &AccountInfo,
+    account: ReallocatableAccount,
+    migrated: &T,
+    rent: &Rent,
+) -> Result<()> {
+    let mut serialized = Vec::new();
+    migrated.try_serialize(&mut serialized)?;
+    if serialized.len() > account_info.data_len() {
+        if serialized.len() > MAX_REALLOCATED_ACCOUNT_SIZE {
+            return Err(account.max_size_exceeded_error().into());
+        }
+        check_reallocation_funding(account, serialized.len(), account_info.lamports(), rent)?;
+        account_info.realloc(serialized.len(), false)?;
+    }
+    account_info.try_borrow_mut_data()?[..serialized.len()].copy_from_slice(&serialized);
+    msg!("{:?} migrated to the current layout", account);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateSchema<'info> {
+    #[account(mut, has_one = admin_pubkey, constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized)]
+    pub oracle_contract_state: Account<'info, OracleContractState>,
+    #[account(mut)]
+    pub admin_pubkey: Signer<'info>,
+    #[account(mut, seeds = [b"admin_audit_log"], bump)]
+    pub admin_audit_log: Account<'info, AdminAuditLogAccount>,
+    /// CHECK: Read raw, since it may still hold a layout that no longer deserializes; migrate_contributor_data checks its discriminator
+    #[account(mut, seeds = [b"contributor_data"], bump)]
+    pub contributor_data_account: UncheckedAccount<'info>,
+    /// CHECK: Read raw, since it may still hold a layout that no longer deserializes; migrate_aggregated_consensus_data checks its discriminator
+    #[account(mut, seeds = [b"aggregated_consensus_data"], bump)]
+    pub aggregated_consensus_data_account: UncheckedAccount<'info>,
+    // Created by the version 11 migration for states initialized before it; paid for by the admin
+    #[account(
+        init_if_needed,
+        seeds = [b"consensus_summary"],
+        bump,
+        payer = admin_pubkey,
+        space = INITIAL_ACCOUNT_SPACE
+    )]
+    pub consensus_summary_account: Account<'info, ConsensusSummaryAccount>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateSchema<'info> {
+    pub fn execute(ctx: Context<MigrateSchema>) -> Result<()> {
+        let rent = Rent::get()?;
+        let from_version = ctx.accounts.oracle_contract_state.schema_version;
+
+        // Rewrite every account whose layout changed, before the state records the new version
+        let contributor_data_account = ctx.accounts.contributor_data_account.to_account_info();
+        let migrated_contributor_data =
+            migrate_contributor_data(from_version, &contributor_data_account.try_borrow_data()?)?;
+        if let Some(migrated) = migrated_contributor_data {
+            write_migrated_account(
+                &contributor_data_account,
+                ReallocatableAccount::ContributorDataAccount,
+                &migrated,
+                &rent,
+            )?;
+        }
+        let aggregated_consensus_data_account = ctx
+            .accounts
+            .aggregated_consensus_data_account
+            .to_account_info();
+        let migrated_aggregated_data = migrate_aggregated_consensus_data(
+            from_version,
+            &aggregated_consensus_data_account.try_borrow_data()?,
+        )?;
+        if let Some((migrated, summaries)) = migrated_aggregated_data {
+            write_migrated_account(
+                &aggregated_consensus_data_account,
+                ReallocatableAccount::AggregatedConsensusDataAccount,
+                &migrated,
+                &rent,
+            )?;
+            for summary in summaries {
+                ctx.accounts
+                    .consensus_summary_account
+                    .record_consensus_summary(summary);
+            }
+        }
+
+        let state = &mut ctx.accounts.oracle_contract_state;
+        if !state.migrate_schema() {
+            msg!("Schema already at version {}", from_version);
+            return Ok(());
+        }
+        let to_version = state.schema_version;
+        msg!(
+            "Schema migrated from version {} to {}",
+            from_version,
+            to_version
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::MigrateSchema,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!("from={},to={}", from_version, to_version),
+        );
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct HashWeight {
+    pub hash: String,
+    pub weight: u64,
+    pub first_seen: u32, // Unix timestamp of the first report with this hash
+}
+
+impl HashWeight {
+    // Borsh-serialized size, counting the hash string's bytes and length prefix
+    pub fn serialized_len(&self) -> usize {
+        4 + self.hash.len()
+            + 8 // weight
+            + 4 // first_seen
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct StatusWeight {
+    pub status: TxidStatus,
+    pub weight: u64,
+}
+
+// Function to update hash weight
+fn update_hash_weight(
+    hash_weights: &mut Vec<HashWeight>,
+    hash: &str,
+    weight: u64,
+    current_timestamp: u32,
+) -> Result<()> {
+    let mut found = false;
+
+    for hash_weight in hash_weights.iter_mut() {
+        if hash_weight.hash.as_str() == hash {
+            add_aggregated_weight(&mut hash_weight.weight, weight)?;
+            found = true;
+            break;
+        }
+    }
+
+    if !found {
+        hash_weights.push(HashWeight {
+            hash: hash.to_string(), // Clone only when necessary
+            weight,
+            first_seen: current_timestamp,
+        });
+    }
+    Ok(())
+}
+
+// A contributor's scores just before a consensus round judged its report, kept so an audit that finds the
+// consensus wrong can reverse the round's effect on them
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct ContributorScoreSnapshot {
+    pub reward_address: Pubkey,
+    pub compliance_score: u64,
+    pub reliability_score: u64,
+    pub total_reports_submitted: u32,
+    pub accurate_reports_count: u32,
+    pub current_streak: u32,
+    pub consensus_failures: u32,
+    pub ban_expiry: u32,
+    pub distinct_hash_mismatches: u32,
+    pub report_window_count: u32,
+}
+
+impl ContributorScoreSnapshot {
+    pub const SERIALIZED_LEN: usize = 32 // reward_address
+        + 8 // compliance_score
+        + 8 // reliability_score
+        + 4 // total_reports_submitted
+        + 4 // accurate_reports_count
+        + 4 // current_streak
+        + 4 // consensus_failures
+        + 4 // ban_expiry
+        + 4 // distinct_hash_mismatches
+        + 4; // report_window_count
+
+    pub fn of(contributor: &Contributor) -> Self {
+        ContributorScoreSnapshot {
+            reward_address: contributor.reward_address,
+            compliance_score: contributor.compliance_score,
+            reliability_score: contributor.reliability_score,
+            total_reports_submitted: contributor.total_reports_submitted,
+            accurate_reports_count: contributor.accurate_reports_count,
+            current_streak: contributor.current_streak,
+            consensus_failures: contributor.consensus_failures,
+            ban_expiry: contributor.ban_expiry,
+            distinct_hash_mismatches: contributor.distinct_hash_mismatches,
+            report_window_count: contributor.report_window_count,
+        }
+    }
+
+    pub fn restore(&self, contributor: &mut Contributor) {
+        contributor.compliance_score = self.compliance_score;
+        contributor.reliability_score = self.reliability_score;
+        contributor.total_reports_submitted = self.total_reports_submitted;
+        contributor.accurate_reports_count = self.accurate_reports_count;
+        contributor.current_streak = self.current_streak;
+        contributor.consensus_failures = self.consensus_failures;
+        contributor.ban_expiry = self.ban_expiry;
+        contributor.distinct_hash_mismatches = self.distinct_hash_mismatches;
+        contributor.report_window_count = self.report_window_count;
+    }
+}
+
+// Struct to hold aggregated data for consensus calculation
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct AggregatedConsensusData {
+    pub txid: String,
+    pub status_weights: [u64; TXID_STATUS_VARIANT_COUNT],
+    pub hash_weights: Vec<HashWeight>,
+    pub first_6_characters_of_sha3_256_hash_of_corresponding_file: String,
+    pub last_updated: u32, // Unix timestamp indicating the last update time
+    pub first_seen: u32,   // Unix timestamp of the first report aggregated for this txid
+    pub status_first_seen: [u32; TXID_STATUS_VARIANT_COUNT], // Unix timestamp of the first report per status; 0 if none
+    pub flagged_for_audit: bool, // Set when the finalized consensus round was selected for admin review
+    pub last_consensus_slot: u64, // Slot in which consensus last updated scores for this txid; 0 if never
+    pub failed_consensus_attempts: u32, // Consecutive consensus attempts that deferred without finalizing
+    pub next_retry_after: u32, // Unix timestamp before which another consensus attempt is unlikely to finalize; 0 if none deferred
+    pub score_snapshots: Vec<ContributorScoreSnapshot>, // Scores of the contributors judged in the latest finalization, taken just before it; pruned with the entry
+}
+
+impl AggregatedConsensusData {
+    // Borsh-serialized size, which unlike size_of counts the strings' bytes and the vectors' elements
+    pub fn serialized_len(&self) -> usize {
+        4 + self.txid.len()
+            + 8 * TXID_STATUS_VARIANT_COUNT // status_weights
+            + 4 + self
+                .hash_weights
+                .iter()
+                .map(HashWeight::serialized_len)
+                .sum::<usize>()
+            + 4 + self
+                .first_6_characters_of_sha3_256_hash_of_corresponding_file
+                .len()
+            + 4 // last_updated
+            + 4 // first_seen
+            + 4 * TXID_STATUS_VARIANT_COUNT // status_first_seen
+            + 1 // flagged_for_audit
+            + 8 // last_consensus_slot
+            + 4 // failed_consensus_attempts
+            + 4 // next_retry_after
+            + 4 + self.score_snapshots.len() * ContributorScoreSnapshot::SERIALIZED_LEN
+    }
+
+    // Whether the txid has been aggregating for longer than the hard maximum age; 0 disables the limit
+    pub fn exceeds_max_age(&self, max_aggregation_age: u32, current_timestamp: u32) -> bool {
+        max_aggregation_age != 0
+            && current_timestamp.saturating_sub(self.first_seen) >= max_aggregation_age
+    }
+
+    // Guards against two consensus paths applying the same score updates within one slot
+    pub fn consensus_ran_in_slot(&self, slot: u64) -> bool {
+        self.last_consensus_slot != 0 && self.last_consensus_slot == slot
+    }
+
+    // Pushes the retry hint out after an attempt that could not finalize, backing off per failed attempt
+    pub fn record_deferred_consensus(&mut self, current_timestamp: u32) {
+        self.failed_consensus_attempts = self.failed_consensus_attempts.saturating_add(1);
+        self.next_retry_after =
+            current_timestamp.saturating_add(consensus_retry_delay(self.failed_consensus_attempts));
+    }
+
+    // Each status weight fits in a u64 but their sum may not, so it is accumulated in a u128
+    fn scaled_status_weight_sum(&self) -> u128 {
+        self.status_weights
+            .iter()
+            .map(|&weight| weight as u128)
+            .sum()
+    }
+
+    // Sum of the (unscaled) weights of all reports aggregated for this txid
+    pub fn total_weight(&self) -> u64 {
+        (self.scaled_status_weight_sum() / AGGREGATED_WEIGHT_SCALE as u128) as u64
+    }
+
+    // Fixed-point share of the aggregated weight that reported the given status
+    pub fn status_confidence(&self, status: TxidStatus) -> u64 {
+        let total_weight = self.scaled_status_weight_sum();
+        if total_weight == 0 {
+            return 0;
+        }
+        (self.status_weights[status as usize] as u128 * ONE as u128 / total_weight) as u64
+    }
+
+    // How decisively the consensus status won, judged by its share of the aggregated status weight
+    pub fn agreement_level(&self, consensus_status: TxidStatus) -> AgreementLevel {
+        let total_weight = self.scaled_status_weight_sum();
+        let winning_weight = self.status_weights[consensus_status as usize] as u128;
+        if winning_weight == total_weight {
+            AgreementLevel::Unanimous
+        } else if winning_weight * 3 >= total_weight * 2 {
+            AgreementLevel::Supermajority
+        } else if winning_weight * 2 > total_weight {
+            AgreementLevel::Majority
+        } else {
+            AgreementLevel::Plurality
+        }
+    }
+
+    // The strongest losing status, if it came within `split_consensus_margin` (a fraction of the winning
+    // weight) of the consensus status
+    pub fn runner_up(
+        &self,
+        consensus_status: TxidStatus,
+        split_consensus_margin: u64,
+    ) -> Option<StatusWeight> {
+        if split_consensus_margin == 0 {
+            return None;
+        }
+        let winning_weight = self.status_weights[consensus_status as usize];
+        self.status_breakdown()
+            .into_iter()
+            .filter(|candidate| candidate.status != consensus_status && candidate.weight > 0)
+            .max_by_key(|candidate| candidate.weight)
+            .filter(|candidate| {
+                winning_weight - candidate.weight <= winning_weight.mul_down(split_consensus_margin)
+            })
+    }
+
+    // Status weights labeled with their status, so clients don't depend on the ordinal layout
+    pub fn status_breakdown(&self) -> Vec<StatusWeight> {
+        TxidStatus::ALL
+            .iter()
+            .map(|&status| StatusWeight {
+                status,
+                weight: self.status_weights[status as usize],
+            })
+            .collect()
+    }
+}
+
+// Share of the aggregated status weight behind a finalized consensus status
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum AgreementLevel {
+    Unanimous,     // Every weighted report agreed
+    Supermajority, // At least two thirds of the weight
+    Majority,      // More than half of the weight
+    Plurality,     // The largest share, but no more than half
+}
+
+#[event]
+pub struct ConsensusReached {
+    pub txid: String,
+    pub consensus_status: TxidStatus,
+    pub consensus_hash: String,
+    pub status_weight: u64,
+    pub contributor_count: u32,
+    pub runner_up: Option<StatusWeight>, // Set when the runner-up status came within the split consensus margin
+    pub consensus_chain_hash: [u8; 32],  // Chain hash after this finalization
+    pub agreement_level: AgreementLevel,
+}
+
+#[event]
+pub struct AggregatedDataBreakdown {
+    pub txid: String,
+    pub status_weights: Vec<StatusWeight>,
+    pub hash_weights: Vec<HashWeight>,
+    pub first_seen: u32,
+    pub last_updated: u32,
+    pub flagged_for_audit: bool,
+}
+
+// Lets clients check the deployed layout before decoding accounts
+#[event]
+pub struct ProgramInfo {
+    pub schema_version: u16,
+    pub current_schema_version: u16, // Version this build migrates to; newer than schema_version until migrate_schema runs
+    pub program_version: String,
+}
+
+pub fn program_info(oracle_contract_state: &OracleContractState) -> ProgramInfo {
+    ProgramInfo {
+        schema_version: oracle_contract_state.schema_version,
+        current_schema_version: CURRENT_SCHEMA_VERSION,
+        program_version: env!("CARGO_PKG_VERSION").to_string(),
+    }
+}
+
+#[derive(Accounts)]
+pub struct GetProgramInfo<'info> {
+    #[account(constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized)]
+    pub oracle_contract_state: Account<'info, OracleContractState>,
+}
+
+pub fn get_program_info_helper(ctx: Context<GetProgramInfo>) -> Result<()> {
+    let info = program_info(&ctx.accounts.oracle_contract_state);
+    msg!(
+        "Schema version: {} (this build: {}), program version: {}",
+        info.schema_version,
+        info.current_schema_version,
+        info.program_version
+    );
+    emit!(info);
+    Ok(())
+}
+
+// Clients built against a newer layout can prepend this to a transaction so it fails cleanly, rather than
+// misreading accounts, while the deployed state is still at an older schema version
+pub fn require_schema_version_helper(
+    ctx: Context<GetProgramInfo>,
+    required_schema_version: u16,
+) -> Result<()> {
+    ctx.accounts
+        .oracle_contract_state
+        .require_schema_version(required_schema_version)
+}
+
+#[derive(Accounts)]
+pub struct GetAggregatedData<'info> {
+    #[account(seeds = [b"aggregated_consensus_data"], bump)]
+    pub aggregated_consensus_data_account: Account<'info, AggregatedConsensusDataAccount>,
+}
+
+pub fn get_aggregated_data_helper(ctx: Context<GetAggregatedData>, txid: String) -> Result<()> {
+    let txid = normalize_txid(&txid);
+    let data = get_aggregated_data(&ctx.accounts.aggregated_consensus_data_account, &txid)
+        .ok_or(OracleError::ReportNotFound)?;
+
+    emit!(AggregatedDataBreakdown {
+        txid: data.txid.clone(),
+        status_weights: data.status_breakdown(),
+        hash_weights: data.hash_weights.clone(),
+        first_seen: data.first_seen,
+        last_updated: data.last_updated,
+        flagged_for_audit: data.flagged_for_audit,
+    });
+    Ok(())
+}
+
+#[event]
+pub struct LatestConsensus {
+    pub summary: ConsensusSummary,
+}
+
+#[derive(Accounts)]
+pub struct GetConsensusSummary<'info> {
+    #[account(seeds = [b"consensus_summary"], bump)]
+    pub consensus_summary_account: Account<'info, ConsensusSummaryAccount>,
+}
+
+pub fn get_consensus_summary_helper(ctx: Context<GetConsensusSummary>, txid: String) -> Result<()> {
+    let txid = normalize_txid(&txid);
+    let summary = ctx
+        .accounts
+        .consensus_summary_account
+        .find_consensus_summary(&txid)
+        .ok_or(OracleError::ReportNotFound)?;
+    emit!(LatestConsensus {
+        summary: summary.clone(),
+    });
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct ActiveAggregation {
+    pub txid: String,
+    pub submission_count: u32,
+    pub total_status_weight: u64, // Unscaled, like ConsensusReadiness::total_weight
+    pub first_seen: u32,
+    pub last_updated: u32,
+}
+
+// One page of the txids still aggregating reports without a finalized consensus
+#[event]
+pub struct ActiveAggregationsPage {
+    pub start_index: u32,
+    pub total_active: u32,
+    pub aggregations: Vec<ActiveAggregation>,
+}
+
+#[derive(Accounts)]
+pub struct GetActiveAggregations<'info> {
+    #[account(seeds = [b"aggregated_consensus_data"], bump)]
+    pub aggregated_consensus_data_account: Account<'info, AggregatedConsensusDataAccount>,
+
+    #[account(seeds = [b"txid_submission_counts"], bump)]
+    pub txid_submission_counts_account: Account<'info, TxidSubmissionCountsAccount>,
+}
+
+fn is_active_aggregation(data: &AggregatedConsensusData) -> bool {
+    data.last_consensus_slot == 0
+}
+
+// Pages over the un-finalized aggregations only, so `start_index` counts active entries
+pub fn active_aggregations_page(
+    aggregated_data_account: &AggregatedConsensusDataAccount,
+    txid_submission_counts_account: &TxidSubmissionCountsAccount,
+    start_index: u32,
+    limit: u32,
+) -> Vec<ActiveAggregation> {
+    aggregated_data_account
+        .consensus_data
+        .iter()
+        .filter(|data| is_active_aggregation(data))
+        .skip(start_index as usize)
+        .take(limit.min(MAX_ACTIVE_AGGREGATIONS_PAGE_SIZE) as usize)
+        .map(|data| ActiveAggregation {
+            txid: data.txid.clone(),
+            submission_count: get_submission_count(txid_submission_counts_account, &data.txid),
+            total_status_weight: data.total_weight(),
+            first_seen: data.first_seen,
+            last_updated: data.last_updated,
+        })
+        .collect()
+}
+
+pub fn get_active_aggregations_helper(
+    ctx: Context<GetActiveAggregations>,
+    start_index: u32,
+    limit: u32,
+) -> Result<()> {
+    let aggregated_data_account = &ctx.accounts.aggregated_consensus_data_account;
+    emit!(ActiveAggregationsPage {
+        start_index,
+        total_active: aggregated_data_account
+            .consensus_data
+            .iter()
+            .filter(|data| is_active_aggregation(data))
+            .count() as u32,
+        aggregations: active_aggregations_page(
+            aggregated_data_account,
+            &ctx.accounts.txid_submission_counts_account,
+            start_index,
+            limit,
+        ),
+    });
+    Ok(())
+}
+
+#[event]
+pub struct ConsensusReadiness {
+    pub txid: String,
+    pub is_ready: bool,
+    pub submission_count: u32,
+    pub effective_quorum: u32,
+    pub total_weight: u64,
+    pub min_consensus_weight_sum: u64,
+    pub failed_consensus_attempts: u32,
+    pub next_retry_after: u32, // Clients should wait until this Unix timestamp before retrying; 0 if no attempt deferred
+}
+
+#[derive(Accounts)]
+pub struct PreviewConsensusReadiness<'info> {
+    #[account(constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized)]
+    pub oracle_contract_state: Account<'info, OracleContractState>,
+
+    #[account(seeds = [b"txid_submission_counts"], bump)]
+    pub txid_submission_counts_account: Account<'info, TxidSubmissionCountsAccount>,
+
+    #[account(seeds = [b"temp_tx_status_report"], bump)]
+    pub temp_report_account: Account<'info, TempTxStatusReportAccount>,
+
+    #[account(seeds = [b"contributor_data"], bump)]
+    pub contributor_data_account: Account<'info, ContributorDataAccount>,
+
+    #[account(seeds = [b"aggregated_consensus_data"], bump)]
+    pub aggregated_consensus_data_account: Account<'info, AggregatedConsensusDataAccount>,
+}
+
+// Whether finalize_consensus would succeed now, and when to retry if a previous attempt deferred
+pub fn consensus_readiness(
+    state: &OracleContractState,
+    txid_submission_counts_account: &TxidSubmissionCountsAccount,
+    temp_report_account: &TempTxStatusReportAccount,
+    aggregated_data_account: &AggregatedConsensusDataAccount,
+    mature_contributor_count: usize,
+    txid: &str,
+    current_timestamp: u32,
+) -> ConsensusReadiness {
+    let aggregated_data = get_aggregated_data(aggregated_data_account, txid);
+    let total_weight = aggregated_data.map_or(0, |data| data.total_weight());
+    let (failed_consensus_attempts, next_retry_after) = aggregated_data.map_or((0, 0), |data| {
+        (data.failed_consensus_attempts, data.next_retry_after)
+    });
+    let past_max_age = aggregated_data
+        .is_some_and(|data| data.exceeds_max_age(state.max_aggregation_age, current_timestamp));
+    let is_ready = is_consensus_pending(
+        state,
+        txid_submission_counts_account,
+        temp_report_account,
+        aggregated_data_account,
+        mature_contributor_count,
+        txid,
+        current_timestamp,
+    ) && (past_max_age
+        || (total_weight >= state.min_consensus_weight_sum
+            && current_timestamp >= next_retry_after));
+
+    ConsensusReadiness {
+        txid: txid.to_string(),
+        is_ready,
+        submission_count: get_submission_count(txid_submission_counts_account, txid),
+        effective_quorum: txid_quorum(state, temp_report_account, mature_contributor_count, txid),
+        total_weight,
+        min_consensus_weight_sum: state.min_consensus_weight_sum,
+        failed_consensus_attempts,
+        next_retry_after,
+    }
+}
+
+pub fn preview_consensus_readiness_helper(
+    ctx: Context<PreviewConsensusReadiness>,
+    txid: String,
+) -> Result<()> {
+    let current_timestamp = Clock::get()?.unix_timestamp as u32;
+    emit!(consensus_readiness(
+        &ctx.accounts.oracle_contract_state,
+        &ctx.accounts.txid_submission_counts_account,
+        &ctx.accounts.temp_report_account,
+        &ctx.accounts.aggregated_consensus_data_account,
+        ctx.accounts
+            .contributor_data_account
+            .mature_contributor_count(&ctx.accounts.oracle_contract_state, current_timestamp),
+        &normalize_txid(&txid),
+        current_timestamp,
+    ));
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RequestReward<'info> {
+    /// CHECK: Canonical reward pool PDA holding lamports only, so it must stay owned by the system program
+    #[account(mut, seeds = [b"reward_pool"], bump, owner = system_program::ID @ OracleError::InvalidPoolAccountOwner)]
+    pub reward_pool_account: UncheckedAccount<'info>,
+    #[account(mut, constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized)]
+    pub oracle_contract_state: Account<'info, OracleContractState>,
+    #[account(mut)]
+    pub contributor_data_account: Account<'info, ContributorDataAccount>,
+    /// CHECK: This is the account we're transferring lamports to
+    #[account(mut)]
+    pub contributor: UncheckedAccount<'info>,
+    /// CHECK: Canonical fee PDA holding lamports only, so it must stay owned by the system program
+    #[account(mut, seeds = [b"fee_receiving_contract"], bump, owner = system_program::ID @ OracleError::InvalidPoolAccountOwner)]
+    pub fee_receiving_contract_account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// Lamports to move from the fee account into a low reward pool; never dips into the fee account's reserve
+pub fn compute_replenishment_amount(
+    state: &OracleContractState,
+    reward_pool_balance: u64,
+    fee_account_balance: u64,
+    fee_account_rent_reserve: u64,
+) -> u64 {
+    if state.auto_replenish_threshold == 0 || reward_pool_balance >= state.auto_replenish_threshold
+    {
+        return 0;
+    }
+    let available = fee_account_balance
+        .saturating_sub(fee_account_rent_reserve.saturating_add(state.fee_account_reserve));
+    state.auto_replenish_amount.min(available)
+}
+
+pub fn request_reward_helper(
+    ctx: Context<RequestReward>,
+    contributor_address: Pubkey,
+) -> Result<()> {
+    let contributor_data_account = &ctx.accounts.contributor_data_account;
+    let reward_pool_account = &ctx.accounts.reward_pool_account;
+    let contributor_account = &ctx.accounts.contributor;
+
+    if ctx.accounts.oracle_contract_state.rewards_paused {
+        msg!("Reward claims are paused");
+        return Err(OracleError::RewardsPaused.into());
+    }
+
+    // Find the contributor in the PDA and check eligibility
+    let contributor = contributor_data_account
+        .find_contributor(&contributor_address)
+        .ok_or(OracleError::UnregisteredOracle)?;
+
+    let current_unix_timestamp = Clock::get()?.unix_timestamp as u32;
+
+    if contributor.calculate_is_banned(current_unix_timestamp) {
+        msg!("Contributor is banned: {}", contributor_address);
+        return Err(OracleError::ContributorBanned.into());
+    }
+
+    let reward_amount = claimable_reward_amount(&ctx.accounts.oracle_contract_state, contributor);
+    if reward_amount == 0 {
+        msg!(
+            "Contributor is not eligible for rewards: {}",
+            contributor_address
+        );
+        return Err(OracleError::NotEligibleForReward.into());
+    }
+
+    // Top up a low reward pool from collected registration fees before paying out
+    let replenishment_amount = compute_replenishment_amount(
+        &ctx.accounts.oracle_contract_state,
+        reward_pool_account.lamports(),
+        ctx.accounts.fee_receiving_contract_account.lamports(),
+        Rent::get()?.minimum_balance(0),
+    );
+    if replenishment_amount > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx
+                        .accounts
+                        .fee_receiving_contract_account
+                        .to_account_info(),
+                    to: reward_pool_account.to_account_info(),
+                },
+            )
+            .with_signer(&[&[
+                b"fee_receiving_contract",
+                &[ctx.bumps.fee_receiving_contract_account],
+            ]]),
+            replenishment_amount,
+        )?;
+        msg!(
+            "Reward pool replenished from fee account: Amount: {}",
+            replenishment_amount
+        );
+    }
+
+    // Ensure the reward pool has sufficient funds and stays rent-exempt
+    require_rent_exempt_after_transfer(
+        &reward_pool_account.to_account_info(),
+        reward_amount,
+        &Rent::get()?,
+    )?;
+
+    // Transfer the reward from the reward pool to the contributor
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.reward_pool_account.to_account_info(),
+                to: contributor_account.to_account_info(),
+            },
+        )
+        .with_signer(&[&[b"reward_pool", &[ctx.bumps.reward_pool_account]]]),
+        reward_amount,
+    )?;
+
+    let contributor = ctx
+        .accounts
+        .contributor_data_account
+        .find_contributor_mut(&contributor_address)
+        .ok_or(OracleError::UnregisteredOracle)?;
+    contributor.record_reward_payout(reward_amount);
+    contributor.owed_participation_rewards_lamports = 0;
+    ctx.accounts
+        .oracle_contract_state
+        .record_reward_payout(reward_amount);
+
+    msg!(
+        "Paid out Valid Reward Request: Contributor: {}, Amount: {}, Total Rewards Earned: {}",
+        contributor_address,
+        reward_amount,
+        contributor.total_rewards_earned_lamports
+    );
+
+    Ok(())
+}
+
+// Lamports a PDA can send while staying rent-exempt
+pub fn transferable_lamports(source: &AccountInfo, rent: &Rent) -> u64 {
+    source
+        .lamports()
+        .saturating_sub(rent.minimum_balance(source.data_len()))
+}
+
+// Transfers out of a PDA must leave it rent-exempt; draining it below the minimum would effectively
+// close it, and none of the program's PDAs are meant to be closed by a transfer
+pub fn require_rent_exempt_after_transfer(
+    source: &AccountInfo,
+    amount: u64,
+    rent: &Rent,
+) -> Result<()> {
+    if amount > transferable_lamports(source, rent) {
+        msg!(
+            "Insufficient funds: transferring {} lamports would leave {} below its rent-exempt minimum",
+            amount,
+            source.key
+        );
+        return Err(OracleError::InsufficientFunds.into());
+    }
+    Ok(())
+}
+
+// Grows sub-linearly with sustained contribution: 1 + ln(total reports), capped so rewards stay bounded
+pub fn report_count_reward_multiplier(
+    state: &OracleContractState,
+    total_reports_submitted: u32,
+) -> u64 {
+    (ONE + total_reports_submitted.to_fixed_giga().ln_down()).min(state.report_count_reward_cap)
+}
+
+// Reliable contributors have the base reward scaled by the configured bonus factor, and in
+// ScaledByReportCount mode by their report-count multiplier as well
+pub fn contributor_reward_amount(state: &OracleContractState, contributor: &Contributor) -> u64 {
+    let reward_amount = if contributor.is_reliable {
+        BASE_REWARD_AMOUNT_IN_LAMPORTS.mul_down(state.reliability_bonus_factor)
+    } else {
+        BASE_REWARD_AMOUNT_IN_LAMPORTS
+    };
+    match state.reward_mode {
+        RewardMode::Flat => reward_amount,
+        RewardMode::ScaledByReportCount => reward_amount.mul_down(report_count_reward_multiplier(
+            state,
+            contributor.total_reports_submitted,
+        )),
+    }
+}
+
+// The largest accuracy reward a single claim can pay under the current config: a reliable contributor's,
+// at the report-count cap in ScaledByReportCount mode
+pub fn max_claim_reward_amount(state: &OracleContractState) -> u64 {
+    let reward_amount =
+        BASE_REWARD_AMOUNT_IN_LAMPORTS.mul_down(state.reliability_bonus_factor.max(ONE));
+    match state.reward_mode {
+        RewardMode::Flat => reward_amount,
+        RewardMode::ScaledByReportCount => reward_amount.mul_down(state.report_count_reward_cap),
+    }
+}
+
+// Accuracy reward (only while eligible) plus any participation rewards owed
+pub fn claimable_reward_amount(state: &OracleContractState, contributor: &Contributor) -> u64 {
+    let accuracy_reward = if contributor.is_eligible_for_rewards {
+        contributor_reward_amount(state, contributor)
+    } else {
+        0
+    };
+    accuracy_reward.saturating_add(contributor.owed_participation_rewards_lamports)
+}
+
+// Reward addresses already eligible before a consensus round, or None when auto-pay is off or paused
+pub fn auto_pay_snapshot(
+    state: &OracleContractState,
+    contributor_data_account: &ContributorDataAccount,
+) -> Option<Vec<Pubkey>> {
+    (state.auto_pay_rewards && !state.rewards_paused).then(|| {
+        contributor_data_account
+            .contributors
+            .iter()
+            .filter(|c| c.is_eligible_for_rewards)
+            .map(|c| c.reward_address)
+            .collect()
+    })
+}
+
+// Contributors that crossed the eligibility threshold since the snapshot, with the reward each can claim
+pub fn newly_eligible_rewards(
+    state: &OracleContractState,
+    contributor_data_account: &ContributorDataAccount,
+    eligible_before: &[Pubkey],
+) -> Vec<(Pubkey, u64)> {
+    contributor_data_account
+        .contributors
+        .iter()
+        .filter(|c| c.is_eligible_for_rewards && !eligible_before.contains(&c.reward_address))
+        .map(|c| (c.reward_address, claimable_reward_amount(state, c)))
+        .filter(|&(_, reward_amount)| reward_amount > 0)
+        .collect()
+}
+
+// Pays each newly eligible contributor straight from the reward pool. A payout is skipped, leaving the
+// reward claimable through request_reward, when the reward address is not a writable account of the
+// transaction or the pool cannot cover it; a skipped payout never fails the submission.
+fn auto_pay_newly_eligible<'info>(
+    state: &mut OracleContractState,
+    contributor_data_account: &mut ContributorDataAccount,
+    eligible_before: &[Pubkey],
+    reward_pool_account: &AccountInfo<'info>,
+    reward_pool_bump: u8,
+    payee_accounts: &[AccountInfo<'info>],
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let rent = Rent::get()?;
+    for (reward_address, reward_amount) in
+        newly_eligible_rewards(state, contributor_data_account, eligible_before)
+    {
+        let Some(payee_account) = payee_accounts
+            .iter()
+            .find(|account| *account.key == reward_address && account.is_writable)
+        else {
+            msg!(
+                "Auto-pay skipped, reward address not in transaction: Contributor: {}",
+                reward_address
+            );
+            continue;
+        };
+        if reward_amount > transferable_lamports(reward_pool_account, &rent) {
+            msg!(
+                "Auto-pay skipped, insufficient funds in reward pool: Contributor: {}",
+                reward_address
+            );
+            continue;
+        }
+
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: reward_pool_account.clone(),
+                    to: payee_account.clone(),
+                },
+            )
+            .with_signer(&[&[b"reward_pool", &[reward_pool_bump]]]),
+            reward_amount,
+        )?;
+
+        let contributor = contributor_data_account
+            .find_contributor_mut(&reward_address)
+            .ok_or(OracleError::UnregisteredOracle)?;
+        contributor.record_reward_payout(reward_amount);
+        contributor.owed_participation_rewards_lamports = 0;
+        state.record_reward_payout(reward_amount);
+        msg!(
+            "Auto-paid reward on eligibility: Contributor: {}, Amount: {}, Total Rewards Earned: {}",
+            reward_address,
+            reward_amount,
+            contributor.total_rewards_earned_lamports
+        );
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FundRewardPool<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    /// CHECK: Canonical reward pool PDA holding lamports only, so it must stay owned by the system program
+    #[account(mut, seeds = [b"reward_pool"], bump, owner = system_program::ID @ OracleError::InvalidPoolAccountOwner)]
+    pub reward_pool_account: UncheckedAccount<'info>,
+
+    #[account(mut, constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized)]
+    pub oracle_contract_state: Account<'info, OracleContractState>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn fund_reward_pool_helper(ctx: Context<FundRewardPool>, amount: u64) -> Result<()> {
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.funder.to_account_info(),
+                to: ctx.accounts.reward_pool_account.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    ctx.accounts
+        .oracle_contract_state
+        .record_pool_funding(amount);
+
+    msg!(
+        "Reward pool funded: Funder: {}, Amount: {}",
+        ctx.accounts.funder.key(),
+        amount
+    );
+    Ok(())
+}
+
+#[event]
+pub struct RewardRunway {
+    pub reward_pool_balance: u64,
+    pub reserve: u64,
+    pub reward_amount: u64, // Largest per-claim accuracy reward, so the runway is a lower bound
+    pub remaining_claims: u64,
+}
+
+#[derive(Accounts)]
+pub struct GetRewardRunway<'info> {
+    /// CHECK: Canonical reward pool PDA holding lamports only, so it must stay owned by the system program
+    #[account(seeds = [b"reward_pool"], bump, owner = system_program::ID @ OracleError::InvalidPoolAccountOwner)]
+    pub reward_pool_account: UncheckedAccount<'info>,
+    #[account(constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized)]
+    pub oracle_contract_state: Account<'info, OracleContractState>,
+}
+
+// Number of reward claims the pool can pay out before its balance drops into the reserve
+pub fn compute_reward_runway(reward_pool_balance: u64, reserve: u64, reward_amount: u64) -> u64 {
+    if reward_amount == 0 {
+        return 0;
+    }
+    reward_pool_balance.saturating_sub(reserve) / reward_amount
+}
+
+pub fn get_reward_runway_helper(ctx: Context<GetRewardRunway>) -> Result<()> {
+    let reward_pool_balance = ctx.accounts.reward_pool_account.lamports();
+    // The pool is a data-less system account, so the reserve is its rent-exempt minimum
+    let reserve = Rent::get()?.minimum_balance(0);
+    let reward_amount = max_claim_reward_amount(&ctx.accounts.oracle_contract_state);
+    let remaining_claims = compute_reward_runway(reward_pool_balance, reserve, reward_amount);
+
+    msg!(
+        "Reward pool runway: Balance: {}, Reserve: {}, Reward Amount: {}, Remaining Claims: {}",
+        reward_pool_balance,
+        reserve,
+        reward_amount,
+        remaining_claims
+    );
+    emit!(RewardRunway {
+        reward_pool_balance,
+        reserve,
+        reward_amount,
+        remaining_claims,
+    });
+    Ok(())
+}
+
+// The oracle's financial health in one read: the reward pool runway plus lifetime inflows and payouts
+#[event]
+pub struct RewardEconomics {
+    pub reward_pool_balance: u64,
+    pub reserve: u64,
+    pub reward_amount: u64, // Largest per-claim accuracy reward, as in RewardRunway
+    pub remaining_claims: u64,
+    pub total_rewards_paid_lamports: u64,
+    pub total_pool_funding_lamports: u64,
+    pub total_registration_fees_lamports: u64,
+    pub total_submission_fees_lamports: u64,
+    pub total_fees_collected_lamports: u64,
+}
+
+#[derive(Accounts)]
+pub struct GetEconomics<'info> {
+    /// CHECK: Canonical reward pool PDA holding lamports only, so it must stay owned by the system program
+    #[account(seeds = [b"reward_pool"], bump, owner = system_program::ID @ OracleError::InvalidPoolAccountOwner)]
+    pub reward_pool_account: UncheckedAccount<'info>,
+    #[account(constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized)]
+    pub oracle_contract_state: Account<'info, OracleContractState>,
+}
+
+pub fn reward_economics(
+    state: &OracleContractState,
+    reward_pool_balance: u64,
+    reserve: u64,
+) -> RewardEconomics {
+    let reward_amount = max_claim_reward_amount(state);
+    RewardEconomics {
+        reward_pool_balance,
+        reserve,
+        reward_amount,
+        remaining_claims: compute_reward_runway(reward_pool_balance, reserve, reward_amount),
+        total_rewards_paid_lamports: state.total_rewards_paid_lamports,
+        total_pool_funding_lamports: state.total_pool_funding_lamports,
+        total_registration_fees_lamports: state.total_registration_fees_lamports,
+        total_submission_fees_lamports: state.total_submission_fees_lamports,
+        total_fees_collected_lamports: state
+            .total_registration_fees_lamports
+            .saturating_add(state.total_submission_fees_lamports),
+    }
+}
+
+pub fn get_economics_helper(ctx: Context<GetEconomics>) -> Result<()> {
+    // The pool is a data-less system account, so the reserve is its rent-exempt minimum
+    let economics = reward_economics(
+        &ctx.accounts.oracle_contract_state,
+        ctx.accounts.reward_pool_account.lamports(),
+        Rent::get()?.minimum_balance(0),
+    );
+    msg!(
+        "Reward economics: Pool Balance: {}, Remaining Claims: {}, Total Paid: {}, Total Funded: {}, Total Fees: {}",
+        economics.reward_pool_balance,
+        economics.remaining_claims,
+        economics.total_rewards_paid_lamports,
+        economics.total_pool_funding_lamports,
+        economics.total_fees_collected_lamports
+    );
+    emit!(economics);
+    Ok(())
+}
+
+#[event]
+pub struct ContributorDetails {
+    pub contributor: Contributor,
+}
+
+#[derive(Accounts)]
+pub struct GetContributor<'info> {
+    #[account(seeds = [b"contributor_data"], bump)]
+    pub contributor_data_account: Account<'info, ContributorDataAccount>,
+}
+
+pub fn get_contributor_helper(ctx: Context<GetContributor>, reward_address: Pubkey) -> Result<()> {
+    let contributor = ctx
+        .accounts
+        .contributor_data_account
+        .find_contributor(&reward_address)
+        .ok_or(OracleError::ContributorNotRegistered)?;
+
+    emit!(ContributorDetails {
+        contributor: contributor.clone(),
+    });
+    Ok(())
+}
+
+// Seconds until a contributor can next act; 0 means now, u32::MAX means no time will do it (a permanent
+// ban, or a reward that is paused or not yet earned)
+#[event]
+pub struct ContributorTiming {
+    pub reward_address: Pubkey,
+    pub seconds_until_can_submit: u32,
+    pub seconds_until_activated: u32, // Until the contributor counts toward quorum scaling (contributor_activation_delay)
+    pub seconds_until_reward_claimable: u32,
+    pub seconds_until_heartbeat_allowed: u32,
+}
+
+pub fn contributor_timing(
+    state: &OracleContractState,
+    contributor: &Contributor,
+    current_timestamp: u32,
+) -> ContributorTiming {
+    let seconds_until_unbanned = if contributor.ban_expiry == u32::MAX {
+        u32::MAX
+    } else {
+        contributor.ban_expiry.saturating_sub(current_timestamp)
+    };
+    // A first report is also held back until the submission quiet period after registration has passed
+    let seconds_until_quiet_period_ends =
+        if contributor.is_in_quiet_period(state.submission_quiet_period, current_timestamp) {
+            contributor
+                .registered_at
+                .saturating_add(state.submission_quiet_period)
+                .saturating_sub(current_timestamp)
+        } else {
+            0
+        };
+    // request_reward refuses while rewards are paused or nothing is claimable, however long the caller waits
+    let seconds_until_reward_claimable =
+        if state.rewards_paused || claimable_reward_amount(state, contributor) == 0 {
+            u32::MAX
+        } else {
+            seconds_until_unbanned
+        };
+    let activated_at = contributor
+        .registered_at
+        .saturating_add(state.contributor_activation_delay);
+    let next_heartbeat = contributor
+        .last_active_timestamp
+        .saturating_add(MIN_HEARTBEAT_INTERVAL);
+
+    ContributorTiming {
+        reward_address: contributor.reward_address,
+        seconds_until_can_submit: seconds_until_unbanned.max(seconds_until_quiet_period_ends),
+        seconds_until_activated: activated_at.saturating_sub(current_timestamp),
+        seconds_until_reward_claimable,
+        seconds_until_heartbeat_allowed: next_heartbeat.saturating_sub(current_timestamp),
+    }
+}
+
+#[derive(Accounts)]
+pub struct GetContributorTiming<'info> {
+    #[account(constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized)]
+    pub oracle_contract_state: Account<'info, OracleContractState>,
+    #[account(seeds = [b"contributor_data"], bump)]
+    pub contributor_data_account: Account<'info, ContributorDataAccount>,
+}
+
+pub fn get_contributor_timing_helper(
+    ctx: Context<GetContributorTiming>,
+    reward_address: Pubkey,
+) -> Result<()> {
+    let contributor = ctx
+        .accounts
+        .contributor_data_account
+        .find_contributor(&reward_address)
+        .ok_or(OracleError::ContributorNotRegistered)?;
+
+    emit!(contributor_timing(
+        &ctx.accounts.oracle_contract_state,
+        contributor,
+        Clock::get()?.unix_timestamp as u32
+    ));
+    Ok(())
+}
+
+// Scores a contributor would have after its next report is judged, computed without persisting anything
+#[event]
+pub struct ProjectedScoreUpdate {
+    pub reward_address: Pubkey,
+    pub would_be_accurate: bool,
+    pub compliance_score: u64,
+    pub reliability_score: u64,
+    pub is_eligible_for_rewards: bool,
+}
+
+#[derive(Accounts)]
+pub struct SimulateScoreUpdate<'info> {
+    #[account(seeds = [b"contributor_data"], bump)]
+    pub contributor_data_account: Account<'info, ContributorDataAccount>,
+    #[account(constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized)]
+    pub oracle_contract_state: Account<'info, OracleContractState>,
+}
+
+// Runs the same update a consensus round would apply, on a copy of the contributor
+pub fn simulate_score_update(
+    contributor: &Contributor,
+    oracle_contract_state: &OracleContractState,
+    current_timestamp: u32,
+    would_be_accurate: bool,
+) -> ProjectedScoreUpdate {
+    let mut projected = contributor.clone();
+    update_contributor(
+        &mut projected,
+        oracle_contract_state,
+        current_timestamp,
+        would_be_accurate,
+        None,
+        0,
+    );
+    ProjectedScoreUpdate {
+        reward_address: projected.reward_address,
+        would_be_accurate,
+        compliance_score: projected.compliance_score,
+        reliability_score: projected.reliability_score,
+        is_eligible_for_rewards: projected.is_eligible_for_rewards,
+    }
+}
+
+pub fn simulate_score_update_helper(
+    ctx: Context<SimulateScoreUpdate>,
+    reward_address: Pubkey,
+    would_be_accurate: bool,
+) -> Result<()> {
+    let contributor = ctx
+        .accounts
+        .contributor_data_account
+        .find_contributor(&reward_address)
+        .ok_or(OracleError::ContributorNotRegistered)?;
+
+    emit!(simulate_score_update(
+        contributor,
+        &ctx.accounts.oracle_contract_state,
+        Clock::get()?.unix_timestamp as u32,
+        would_be_accurate,
+    ));
+    Ok(())
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct ContributorStanding {
+    pub reward_address: Pubkey,
+    pub compliance_score: u64,
+    pub reliability_score: u64,
+    pub total_rewards_earned_lamports: u64,
+}
+
+// One page of a contributor snapshot; pages taken at the same slot form a consistent snapshot
+#[event]
+pub struct ContributorSnapshot {
+    pub slot: u64,
+    pub start_index: u32,
+    pub total_contributors: u32,
+    pub standings: Vec<ContributorStanding>,
+}
+
+#[derive(Accounts)]
+pub struct SnapshotContributors<'info> {
+    #[account(seeds = [b"contributor_data"], bump)]
+    pub contributor_data_account: Account<'info, ContributorDataAccount>,
+}
+
+pub fn contributor_snapshot_page(
+    contributors: &[Contributor],
+    start_index: u32,
+    limit: u32,
+) -> Vec<ContributorStanding> {
+    contributors
+        .iter()
+        .skip(start_index as usize)
+        .take(limit.min(MAX_CONTRIBUTOR_SNAPSHOT_PAGE_SIZE) as usize)
+        .map(|c| ContributorStanding {
+            reward_address: c.reward_address,
+            compliance_score: c.compliance_score,
+            reliability_score: c.reliability_score,
+            total_rewards_earned_lamports: c.total_rewards_earned_lamports,
+        })
+        .collect()
+}
+
+pub fn snapshot_contributors_helper(
+    ctx: Context<SnapshotContributors>,
+    start_index: u32,
+    limit: u32,
+) -> Result<()> {
+    let contributors = &ctx.accounts.contributor_data_account.contributors;
+    emit!(ContributorSnapshot {
+        slot: Clock::get()?.slot,
+        start_index,
+        total_contributors: contributors.len() as u32,
+        standings: contributor_snapshot_page(contributors, start_index, limit),
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RegisterNewDataContributor<'info> {
+    /// CHECK: Manual checks are performed in the instruction to ensure the contributor_account is valid and safe to use.
+    #[account(mut)]
+    pub contributor_account: Signer<'info>,
+
+    /// CHECK: Canonical reward pool PDA holding lamports only, so it must stay owned by the system program
+    #[account(mut, seeds = [b"reward_pool"], bump, owner = system_program::ID @ OracleError::InvalidPoolAccountOwner)]
+    pub reward_pool_account: UncheckedAccount<'info>,
+
+    /// CHECK: Canonical fee PDA holding lamports only, so it must stay owned by the system program
+    #[account(mut, seeds = [b"fee_receiving_contract"], bump, owner = system_program::ID @ OracleError::InvalidPoolAccountOwner)]
+    pub fee_receiving_contract_account: UncheckedAccount<'info>,
+
+    /// CHECK: Canonical treasury PDA holding lamports only, so it must stay owned by the system program
+    #[account(mut, seeds = [b"treasury"], bump, owner = system_program::ID @ OracleError::InvalidPoolAccountOwner)]
+    pub treasury_account: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub contributor_data_account: Account<'info, ContributorDataAccount>,
+
+    #[account(mut, constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized)]
+    pub oracle_contract_state: Account<'info, OracleContractState>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Splits a registration fee into its (reward pool, treasury) shares; rounding favours the reward pool
+pub fn split_registration_fee(fee: u64, fee_split_bps: u16) -> (u64, u64) {
+    let treasury_share = (fee as u128 * fee_split_bps.min(MAX_FEE_SPLIT_BPS) as u128
+        / MAX_FEE_SPLIT_BPS as u128) as u64;
+    (fee - treasury_share, treasury_share)
+}
+
+pub fn register_new_data_contributor_helper(
+    ctx: Context<RegisterNewDataContributor>,
+) -> Result<()> {
+    let contributor_data_account = &mut ctx.accounts.contributor_data_account;
+    msg!(
+        "Initiating new contributor registration: {}",
+        ctx.accounts.contributor_account.key()
+    );
+
+    ctx.accounts
+        .oracle_contract_state
+        .require_not_blacklisted(ctx.accounts.contributor_account.key)?;
+
+    // Check if the contributor is already registered
+    if contributor_data_account
+        .find_contributor(ctx.accounts.contributor_account.key)
+        .is_some()
+    {
+        msg!(
+            "Registration failed: Contributor already registered: {}",
+            ctx.accounts.contributor_account.key
+        );
+        return Err(OracleError::ContributorAlreadyRegistered.into());
+    }
+
+    msg!(
+        "Registration fee verified. Attempting to register new contributor {}",
+        ctx.accounts.contributor_account.key
+    );
+
+    // Deduct the registration fee from the fee_receiving_contract_account and split it between the
+    // reward pool and the treasury
+    let (reward_pool_share, treasury_share) = split_registration_fee(
+        REGISTRATION_ENTRANCE_FEE_IN_LAMPORTS,
+        ctx.accounts.oracle_contract_state.fee_split_bps,
+    );
+    require_rent_exempt_after_transfer(
+        &ctx.accounts
+            .fee_receiving_contract_account
+            .to_account_info(),
+        REGISTRATION_ENTRANCE_FEE_IN_LAMPORTS,
+        &Rent::get()?,
+    )?;
+    for (destination, amount) in [
+        (&ctx.accounts.reward_pool_account, reward_pool_share),
+        (&ctx.accounts.treasury_account, treasury_share),
+    ] {
+        if amount == 0 {
+            continue;
+        }
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx
+                        .accounts
+                        .fee_receiving_contract_account
+                        .to_account_info(),
+                    to: destination.to_account_info(),
+                },
+            )
+            .with_signer(&[&[
+                b"fee_receiving_contract",
+                &[ctx.bumps.fee_receiving_contract_account],
+            ]]),
+            amount,
+        )?;
+    }
+    msg!(
+        "Registration fee split: {} lamports to the reward pool, {} lamports to the treasury",
+        reward_pool_share,
+        treasury_share
+    );
+    ctx.accounts
+        .oracle_contract_state
+        .record_registration_fee(REGISTRATION_ENTRANCE_FEE_IN_LAMPORTS);
+
+    let last_active_timestamp = Clock::get()?.unix_timestamp as u32;
+
+    let registration_entrance_fee_transaction_signature = String::new();
+    validate_bounded_string(
+        &registration_entrance_fee_transaction_signature,
+        MAX_TRANSACTION_SIGNATURE_LENGTH,
+    )?;
+
+    // Create and add the new contributor
+    let new_contributor = configured_new_contributor(
+        &ctx.accounts.oracle_contract_state,
+        *ctx.accounts.contributor_account.key,
+        registration_entrance_fee_transaction_signature,
+        last_active_timestamp,
+    );
+
+    // Insert the new contributor into the ContributorDataAccount, keeping it sorted by reward address
+    contributor_data_account.insert_contributor(new_contributor)?;
+
+    // Logging for debug purposes
+    msg!(
+        "New Contributor successfully Registered: Address: {}, Timestamp: {}",
+        ctx.accounts.contributor_account.key,
+        last_active_timestamp
+    );
+    Ok(())
+}
+
+// A freshly registered contributor, starting from the initial scores
+fn new_contributor(
+    reward_address: Pubkey,
+    registration_entrance_fee_transaction_signature: String,
+    registered_at: u32,
+) -> Contributor {
+    Contributor {
+        reward_address,
+        registration_entrance_fee_transaction_signature, // Replace with actual data if available
+        compliance_score: ONE,                           // Initial compliance score
+        last_active_timestamp: registered_at, // Set the last active timestamp to the current time
+        total_reports_submitted: 0,           // Initially, no reports have been submitted
+        accurate_reports_count: 0,            // Initially, no accurate reports
+        current_streak: 0,                    // No streak at the beginning
+        reliability_score: ONE,               // Initial reliability score
+        consensus_failures: 0,                // No consensus failures at the start
+        ban_expiry: 0,                        // No ban initially set
+        is_eligible_for_rewards: false,       // Initially not eligible for rewards
+        is_recently_active: false,            // Initially not considered active
+        is_reliable: false,                   // Initially not considered reliable
+        total_rewards_earned_lamports: 0,     // No rewards paid out yet
+        distinct_hash_mismatches: 0,          // No hash mismatches yet
+        stake_weight: 0,                      // No stake until one is assigned
+        owed_participation_rewards_lamports: 0, // No consensus rounds participated in yet
+        last_submission_timestamp: 0,         // No reports submitted yet
+        is_deactivated: false,                // Active from registration
+        registered_at, // Counts toward quorum scaling once the activation delay has passed
+        probation_until: 0, // Never unbanned
+        report_window_start: 0,
+        report_window_count: 0,
+        recent_outcomes: [ReportOutcome::default(); RECENT_OUTCOMES_CAPACITY], // No reports judged yet
+        recent_outcomes_head: 0,
+    }
+}
+
+// A freshly registered contributor, with the head start the admin configured for new contributors
+fn configured_new_contributor(
+    oracle_contract_state: &OracleContractState,
+    reward_address: Pubkey,
+    registration_entrance_fee_transaction_signature: String,
+    registered_at: u32,
+) -> Contributor {
+    let mut contributor = new_contributor(
+        reward_address,
+        registration_entrance_fee_transaction_signature,
+        registered_at,
+    );
+    contributor.compliance_score =
+        effective_initial_compliance_score(oracle_contract_state.initial_compliance_score);
+    contributor
+}
+
+// The compliance score new contributors actually start from; an unset initial score starts them at ONE
+pub fn effective_initial_compliance_score(initial_compliance_score: u64) -> u64 {
+    if initial_compliance_score == 0 {
+        ONE
+    } else {
+        initial_compliance_score
+    }
+}
+
+// New contributors must still earn their way to rewards, so they start strictly below the threshold
+pub fn validate_initial_compliance_score(
+    initial_compliance_score: u64,
+    min_compliance_score_for_reward: u64,
+) -> Result<()> {
+    if effective_initial_compliance_score(initial_compliance_score)
+        >= min_compliance_score_for_reward
+    {
+        return Err(OracleError::InvalidConfigValue.into());
+    }
+    Ok(())
+}
+
+#[event]
+pub struct ContributorsBatchRegistered {
+    pub requested: u32,
+    pub added: u32,
+    pub skipped: u32, // Already registered, or repeated within the batch
+}
+
+#[derive(Accounts)]
+pub struct BatchRegisterContributors<'info> {
+    #[account(has_one = admin_pubkey, constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized)]
+    pub oracle_contract_state: Account<'info, OracleContractState>,
+    pub admin_pubkey: Signer<'info>,
+    #[account(mut, seeds = [b"contributor_data"], bump)]
+    pub contributor_data_account: Account<'info, ContributorDataAccount>,
+    #[account(mut, seeds = [b"admin_audit_log"], bump)]
+    pub admin_audit_log: Account<'info, AdminAuditLogAccount>,
+}
+
+// Registers every address not already registered or blacklisted, free of the entrance fee; returns how
+// many were added
+pub fn batch_register_contributors(
+    oracle_contract_state: &OracleContractState,
+    contributor_data_account: &mut ContributorDataAccount,
+    reward_addresses: &[Pubkey],
+    current_timestamp: u32,
+) -> Result<u32> {
+    if reward_addresses.len() > MAX_BATCH_REGISTRATION_SIZE {
+        return Err(OracleError::BatchTooLarge.into());
+    }
+    let mut added = 0;
+    for reward_address in reward_addresses {
+        if contributor_data_account
+            .find_contributor(reward_address)
+            .is_some()
+        {
+            msg!(
+                "Skipping already registered contributor: {}",
+                reward_address
+            );
+            continue;
+        }
+        if oracle_contract_state.is_blacklisted(reward_address) {
+            msg!("Skipping blacklisted reward address: {}", reward_address);
+            continue;
+        }
+        contributor_data_account.insert_contributor(configured_new_contributor(
+            oracle_contract_state,
+            *reward_address,
+            String::new(),
+            current_timestamp,
+        ))?;
+        added += 1;
+    }
+    Ok(added)
+}
+
+pub fn batch_register_contributors_helper(
+    ctx: Context<BatchRegisterContributors>,
+    reward_addresses: Vec<Pubkey>,
+) -> Result<()> {
+    let current_timestamp = Clock::get()?.unix_timestamp as u32;
+    let added = batch_register_contributors(
+        &ctx.accounts.oracle_contract_state,
+        &mut ctx.accounts.contributor_data_account,
+        &reward_addresses,
+        current_timestamp,
+    )?;
+    let requested = reward_addresses.len() as u32;
+    msg!(
+        "Batch registration complete: Requested: {}, Added: {}",
+        requested,
+        added
+    );
+    emit!(ContributorsBatchRegistered {
+        requested,
+        added,
+        skipped: requested - added,
+    });
+
+    ctx.accounts.admin_audit_log.record(
+        AdminActionCode::BatchRegisterContributors,
+        ctx.accounts.admin_pubkey.key(),
+        current_timestamp,
+        &format!("requested={},added={}", requested, added),
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ChangeRewardAddress<'info> {
+    #[account(mut, seeds = [b"contributor_data"], bump)]
+    pub contributor_data_account: Account<'info, ContributorDataAccount>,
+    pub contributor: Signer<'info>,
+    #[account(constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized)]
+    pub oracle_contract_state: Account<'info, OracleContractState>,
+    #[account(seeds = [b"temp_tx_status_report"], bump)]
+    pub temp_report_account: Account<'info, TempTxStatusReportAccount>,
+}
+
+// Reports awaiting consensus, and their report PDAs, stay keyed by the old address, so their outcomes would
+// never reach the moved contributor; the change waits until none are pending
+pub fn require_no_active_reports(
+    temp_report_account: &TempTxStatusReportAccount,
+    reward_address: &Pubkey,
+) -> Result<()> {
+    let active_reports = temp_report_account.active_report_count(reward_address);
+    if active_reports > 0 {
+        msg!(
+            "Contributor {} has {} reports awaiting consensus",
+            reward_address,
+            active_reports
+        );
+        return Err(OracleError::ReportsAwaitingConsensus.into());
+    }
+    Ok(())
+}
+
+pub fn change_reward_address_helper(
+    ctx: Context<ChangeRewardAddress>,
+    new_reward_address: Pubkey,
+) -> Result<()> {
+    let current_reward_address = ctx.accounts.contributor.key();
+    ctx.accounts
+        .oracle_contract_state
+        .require_not_blacklisted(&new_reward_address)?;
+    require_no_active_reports(&ctx.accounts.temp_report_account, &current_reward_address)?;
+    ctx.accounts
+        .contributor_data_account
+        .change_reward_address(&current_reward_address, new_reward_address)?;
+    msg!(
+        "Reward address changed: Contributor: {} is now {}",
+        current_reward_address,
+        new_reward_address
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Heartbeat<'info> {
+    #[account(mut, seeds = [b"contributor_data"], bump)]
+    pub contributor_data_account: Account<'info, ContributorDataAccount>,
+    pub contributor: Signer<'info>,
+    #[account(constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized)]
+    pub oracle_contract_state: Account<'info, OracleContractState>,
+}
+
+// Marks the contributor as active without touching its scores or report counts
+pub fn record_heartbeat(
+    contributor: &mut Contributor,
+    oracle_contract_state: &OracleContractState,
+    current_timestamp: u32,
+) -> Result<()> {
+    if current_timestamp.saturating_sub(contributor.last_active_timestamp) < MIN_HEARTBEAT_INTERVAL
+    {
+        return Err(OracleError::HeartbeatTooFrequent.into());
+    }
+    contributor.last_active_timestamp = current_timestamp;
+    update_statuses(contributor, oracle_contract_state, current_timestamp);
+    Ok(())
+}
+
+pub fn heartbeat_helper(ctx: Context<Heartbeat>) -> Result<()> {
+    let current_timestamp = Clock::get()?.unix_timestamp as u32;
+    let contributor = ctx
+        .accounts
+        .contributor_data_account
+        .find_contributor_mut(ctx.accounts.contributor.key)
+        .ok_or(OracleError::ContributorNotRegistered)?;
+
+    record_heartbeat(
+        contributor,
+        &ctx.accounts.oracle_contract_state,
+        current_timestamp,
+    )?;
+    msg!(
+        "Heartbeat recorded: Contributor: {}, Timestamp: {}",
+        contributor.reward_address,
+        current_timestamp
+    );
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AddTxidForMonitoringData {
+    pub txid: String,
+}
+
+#[derive(Accounts)]
+pub struct AddTxidForMonitoring<'info> {
+    #[account(mut, constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized)]
+    pub oracle_contract_state: Account<'info, OracleContractState>,
+
+    /// CHECK: The caller is manually verified in the instruction logic to ensure it's the correct and authorized account.
+    pub caller: Signer<'info>,
+
+    // The `pending_payment_account` will be initialized in the function
+    #[account(mut)]
+    pub pending_payment_account: Account<'info, PendingPaymentAccount>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn add_txid_for_monitoring_helper(
+    ctx: Context<AddTxidForMonitoring>,
+    data: AddTxidForMonitoringData,
+) -> Result<()> {
+    let state = &mut ctx.accounts.oracle_contract_state;
+
+    if !state.is_authorized_bridge(ctx.accounts.caller.key) {
+        return Err(OracleError::NotBridgeContractAddress.into());
+    }
+
+    // Explicitly cast txid to String and ensure it meets requirements
+    let txid = normalize_txid(&data.txid);
+    validate_txid(&txid)?;
+
+    // Add the TXID to the monitored list
+    add_monitored_txid(state, &txid)?;
+
+    // Initialize pending_payment_account here using the txid
+    let pending_payment_account = &mut ctx.accounts.pending_payment_account;
+    pending_payment_account.pending_payment = PendingPayment {
+        txid: txid.clone(),
+        expected_amount: COST_IN_LAMPORTS_OF_ADDING_PASTEL_TXID_FOR_MONITORING,
+        payment_status: PaymentStatus::Pending, // Enum, no need for casting
+        payer: pending_payment_account.pending_payment.payer, // Whoever created the account paid its rent
+        created_at: Clock::get()?.unix_timestamp as u32,
+    };
+
+    msg!(
+        "Added Pastel TXID for Monitoring: {}",
+        pending_payment_account.pending_payment.txid
+    );
+    Ok(())
+}
+
+// Monitored TXIDs are stored in the state account itself, so the list can only hold as many TXIDs of the maximum
+// length as fit in the space the rest of the state leaves free
+pub fn monitored_txids_capacity(
+    state: &OracleContractState,
+    account_data_len: usize,
+) -> Result<u32> {
+    let monitored_txids_space: usize = state
+        .monitored_txids
+        .iter()
+        .map(|txid| 4 + txid.len())
+        .sum();
+    let other_space = 8 + state.try_to_vec()?.len() - monitored_txids_space;
+    let capacity = account_data_len.saturating_sub(other_space) / MONITORED_TXID_SPACE;
+    Ok(capacity.min(u32::MAX as usize) as u32)
+}
+
+pub fn validate_max_monitored_txids(
+    state: &OracleContractState,
+    account_data_len: usize,
+    max_monitored_txids: u32,
+) -> Result<()> {
+    if max_monitored_txids == 0 || (max_monitored_txids as usize) < state.monitored_txids.len() {
+        return Err(OracleError::InvalidConfigValue.into());
+    }
+    let capacity = monitored_txids_capacity(state, account_data_len)?;
+    if max_monitored_txids > capacity {
+        msg!(
+            "Max monitored txids {} exceeds the {} that fit in the state account; reallocate it first",
+            max_monitored_txids,
+            capacity
+        );
+        return Err(OracleError::InvalidConfigValue.into());
+    }
+    Ok(())
+}
+
+fn add_monitored_txid(state: &mut OracleContractState, txid: &str) -> Result<()> {
+    if state.monitored_txids.len() >= state.max_monitored_txids as usize {
+        msg!(
+            "Cannot monitor TXID {}: the monitored list is at its maximum of {} entries; remove stale TXIDs first",
+            txid,
+            state.max_monitored_txids
+        );
+        return Err(OracleError::MaxSizeExceeded.into());
+    }
+    state.monitored_txids.push(txid.to_string());
+    Ok(())
+}
+
+fn remove_monitored_txid(state: &mut OracleContractState, txid: &str) -> Result<()> {
+    let index = state
+        .monitored_txids
+        .iter()
+        .position(|monitored_txid| monitored_txid == txid)
+        .ok_or(OracleError::TxidNotMonitored)?;
+    state.monitored_txids.remove(index);
+    Ok(())
+}
+
+#[event]
+pub struct EligibilityRecomputed {
+    pub start_index: u32,
+    pub processed_count: u32,
+    pub newly_eligible_count: u32,
+    pub newly_ineligible_count: u32,
+    pub next_cursor: u32, // Index to pass as start_index on the next call
+    pub is_complete: bool,
+}
+
+// Re-runs the status update for a bounded slice of contributors so eligibility reflects the current thresholds
+pub fn recompute_eligibility_batch(
+    oracle_contract_state: &OracleContractState,
+    contributors: &mut [Contributor],
+    start_index: u32,
+    max_contributors: u32,
+    current_timestamp: u32,
+) -> EligibilityRecomputed {
+    let start = (start_index as usize).min(contributors.len());
+    let end = start
+        .saturating_add(max_contributors.min(MAX_ELIGIBILITY_RECOMPUTE_BATCH_SIZE) as usize)
+        .min(contributors.len());
+
+    let mut newly_eligible_count = 0;
+    let mut newly_ineligible_count = 0;
+    for contributor in contributors[start..end].iter_mut() {
+        let was_eligible = contributor.is_eligible_for_rewards;
+        update_statuses(contributor, oracle_contract_state, current_timestamp);
+        match (was_eligible, contributor.is_eligible_for_rewards) {
+            (false, true) => newly_eligible_count += 1,
+            (true, false) => newly_ineligible_count += 1,
+            _ => {}
+        }
+    }
+
+    EligibilityRecomputed {
+        start_index,
+        processed_count: (end - start) as u32,
+        newly_eligible_count,
+        newly_ineligible_count,
+        next_cursor: end as u32,
+        is_complete: end == contributors.len(),
+    }
+}
+
+#[derive(Accounts)]
+pub struct RecomputeAllEligibility<'info> {
+    #[account(has_one = admin_pubkey, constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized)]
+    pub oracle_contract_state: Account<'info, OracleContractState>,
+    pub admin_pubkey: Signer<'info>,
+    #[account(mut, seeds = [b"contributor_data"], bump)]
+    pub contributor_data_account: Account<'info, ContributorDataAccount>,
+    #[account(mut, seeds = [b"admin_audit_log"], bump)]
+    pub admin_audit_log: Account<'info, AdminAuditLogAccount>,
+}
+
+pub fn recompute_all_eligibility_helper(
+    ctx: Context<RecomputeAllEligibility>,
+    start_index: u32,
+    max_contributors: u32,
+) -> Result<()> {
+    let current_timestamp = Clock::get()?.unix_timestamp as u32;
+    let summary = recompute_eligibility_batch(
+        &ctx.accounts.oracle_contract_state,
+        &mut ctx.accounts.contributor_data_account.contributors,
+        start_index,
+        max_contributors,
+        current_timestamp,
+    );
+    msg!(
+        "Recomputed eligibility for {} contributors starting at index {}: {} newly eligible, {} newly ineligible",
+        summary.processed_count,
+        start_index,
+        summary.newly_eligible_count,
+        summary.newly_ineligible_count
+    );
+
+    ctx.accounts.admin_audit_log.record(
+        AdminActionCode::RecomputeAllEligibility,
+        ctx.accounts.admin_pubkey.key(),
+        current_timestamp,
+        &format!(
+            "start={},processed={}",
+            start_index, summary.processed_count
+        ),
+    );
+    emit!(summary);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RepairSubmissionCounts<'info> {
+    #[account(has_one = admin_pubkey, constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized)]
+    pub oracle_contract_state: Account<'info, OracleContractState>,
+    pub admin_pubkey: Signer<'info>,
+    #[account(mut, seeds = [b"txid_submission_counts"], bump)]
+    pub txid_submission_counts_account: Account<'info, TxidSubmissionCountsAccount>,
+    #[account(mut, seeds = [b"admin_audit_log"], bump)]
+    pub admin_audit_log: Account<'info, AdminAuditLogAccount>,
+}
+
+pub fn repair_submission_counts_helper(ctx: Context<RepairSubmissionCounts>) -> Result<()> {
+    let merged_count = ctx
+        .accounts
+        .txid_submission_counts_account
+        .repair_submission_counts();
+    msg!(
+        "Repaired TxidSubmissionCountsAccount: merged {} duplicate entries, {} entries remain",
+        merged_count,
+        ctx.accounts
+            .txid_submission_counts_account
+            .submission_counts
+            .len()
+    );
+
+    ctx.accounts.admin_audit_log.record(
+        AdminActionCode::RepairSubmissionCounts,
+        ctx.accounts.admin_pubkey.key(),
+        Clock::get()?.unix_timestamp as u32,
+        &format!("merged={}", merged_count),
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SweepOrphanedTempReports<'info> {
+    #[account(has_one = admin_pubkey, constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized)]
+    pub oracle_contract_state: Account<'info, OracleContractState>,
+    pub admin_pubkey: Signer<'info>,
+    #[account(mut, seeds = [b"temp_tx_status_report"], bump)]
+    pub temp_report_account: Account<'info, TempTxStatusReportAccount>,
+    #[account(seeds = [b"aggregated_consensus_data"], bump)]
+    pub aggregated_consensus_data_account: Account<'info, AggregatedConsensusDataAccount>,
+    #[account(mut, seeds = [b"admin_audit_log"], bump)]
+    pub admin_audit_log: Account<'info, AdminAuditLogAccount>,
+}
+
+pub fn sweep_orphaned_temp_reports_helper(ctx: Context<SweepOrphanedTempReports>) -> Result<()> {
+    let current_timestamp = Clock::get()?.unix_timestamp as u32;
+    let accounts = ctx.accounts;
+    let swept_count = accounts.temp_report_account.sweep_orphaned_reports(
+        &accounts.aggregated_consensus_data_account,
+        accounts.oracle_contract_state.temp_report_retention_period,
+        current_timestamp,
+    );
+    msg!(
+        "Swept {} orphaned temp reports, {} temp reports remain",
+        swept_count,
+        accounts.temp_report_account.reports.len()
+    );
+
+    accounts.admin_audit_log.record(
+        AdminActionCode::SweepOrphanedTempReports,
+        accounts.admin_pubkey.key(),
+        current_timestamp,
+        &format!("swept={}", swept_count),
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetContributorStakeWeight<'info> {
+    #[account(has_one = admin_pubkey, constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized)]
+    pub oracle_contract_state: Account<'info, OracleContractState>,
+    pub admin_pubkey: Signer<'info>,
+    #[account(mut, seeds = [b"contributor_data"], bump)]
+    pub contributor_data_account: Account<'info, ContributorDataAccount>,
+    #[account(mut, seeds = [b"admin_audit_log"], bump)]
+    pub admin_audit_log: Account<'info, AdminAuditLogAccount>,
+}
+
+pub fn set_contributor_stake_weight_helper(
+    ctx: Context<SetContributorStakeWeight>,
+    reward_address: Pubkey,
+    stake_weight: u64,
+) -> Result<()> {
+    if stake_weight > MAX_STAKE_WEIGHT {
+        return Err(OracleError::InvalidConfigValue.into());
+    }
+    ctx.accounts
+        .contributor_data_account
+        .find_contributor_mut(&reward_address)
+        .ok_or(OracleError::ContributorNotRegistered)?
+        .stake_weight = stake_weight;
+    msg!(
+        "Stake weight for contributor {} updated: {}",
+        reward_address,
+        stake_weight
+    );
+
+    ctx.accounts.admin_audit_log.record(
+        AdminActionCode::SetContributorStakeWeight,
+        ctx.accounts.admin_pubkey.key(),
+        Clock::get()?.unix_timestamp as u32,
+        &format!("{}={}", reward_address, stake_weight),
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AdminUnbanContributor<'info> {
+    #[account(has_one = admin_pubkey, constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized)]
+    pub oracle_contract_state: Account<'info, OracleContractState>,
+    pub admin_pubkey: Signer<'info>,
+    #[account(mut, seeds = [b"contributor_data"], bump)]
+    pub contributor_data_account: Account<'info, ContributorDataAccount>,
+    #[account(mut, seeds = [b"admin_audit_log"], bump)]
+    pub admin_audit_log: Account<'info, AdminAuditLogAccount>,
+}
+
+// Lifts the ban and starts probation; the failure counts restart, but count double until probation ends
+pub fn unban_contributor(
+    contributor: &mut Contributor,
+    oracle_contract_state: &OracleContractState,
+    current_timestamp: u32,
+) -> Result<()> {
+    if !contributor.calculate_is_banned(current_timestamp) {
+        return Err(OracleError::ContributorNotBanned.into());
+    }
+    contributor.ban_expiry = 0;
+    contributor.consensus_failures = 0;
+    contributor.distinct_hash_mismatches = 0;
+    contributor.probation_until =
+        current_timestamp.saturating_add(oracle_contract_state.probation_period);
+    update_statuses(contributor, oracle_contract_state, current_timestamp);
+    Ok(())
+}
+
+pub fn admin_unban_contributor_helper(
+    ctx: Context<AdminUnbanContributor>,
+    reward_address: Pubkey,
+) -> Result<()> {
+    let current_timestamp = Clock::get()?.unix_timestamp as u32;
+    let contributor = ctx
+        .accounts
+        .contributor_data_account
+        .find_contributor_mut(&reward_address)
+        .ok_or(OracleError::ContributorNotRegistered)?;
+    unban_contributor(
+        contributor,
+        &ctx.accounts.oracle_contract_state,
+        current_timestamp,
+    )?;
+    msg!(
+        "Contributor {} unbanned; on probation until {}",
+        reward_address,
+        contributor.probation_until
+    );
+
+    ctx.accounts.admin_audit_log.record(
+        AdminActionCode::UnbanContributor,
+        ctx.accounts.admin_pubkey.key(),
+        current_timestamp,
+        &format!(
+            "{} probation_until={}",
+            reward_address, contributor.probation_until
+        ),
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RemoveTxidFromMonitoring<'info> {
+    #[account(mut, has_one = admin_pubkey, constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized)]
+    pub oracle_contract_state: Account<'info, OracleContractState>,
+    pub admin_pubkey: Signer<'info>,
+    #[account(mut, seeds = [b"admin_audit_log"], bump)]
+    pub admin_audit_log: Account<'info, AdminAuditLogAccount>,
+    // The txid's pending payment, resolved along with it; omitted for txids without one
+    #[account(mut)]
+    pub pending_payment_account: Option<Account<'info, PendingPaymentAccount>>,
+    /// CHECK: Checked in the handler against the payer recorded on the pending payment
+    #[account(mut)]
+    pub payment_payer: Option<UncheckedAccount<'info>>,
+}
+
+// Stops monitoring the txid and decides how its pending payment, if any, is settled
+pub fn remove_txid_and_resolve_payment(
+    state: &mut OracleContractState,
+    txid: &str,
+    pending_payment: Option<&PendingPayment>,
+) -> Result<Option<PaymentResolution>> {
+    if pending_payment.is_some_and(|payment| payment.txid != txid) {
+        return Err(OracleError::PaymentNotFound.into());
+    }
+    remove_monitored_txid(state, txid)?;
+    Ok(pending_payment.map(PendingPayment::resolution_on_removal))
+}
+
+pub fn remove_txid_from_monitoring_helper(
+    ctx: Context<RemoveTxidFromMonitoring>,
+    txid: String,
+) -> Result<()> {
+    let resolution = remove_txid_and_resolve_payment(
+        &mut ctx.accounts.oracle_contract_state,
+        &txid,
+        ctx.accounts
+            .pending_payment_account
+            .as_ref()
+            .map(|account| &account.pending_payment),
+    )?;
+    msg!("Removed Pastel TXID from Monitoring: {}", txid);
+    if let (Some(resolution), Some(pending_payment_account)) =
+        (resolution, &ctx.accounts.pending_payment_account)
+    {
+        let payment_payer = ctx
+            .accounts
+            .payment_payer
+            .as_ref()
+            .ok_or(OracleError::InvalidPaymentPayer)?;
+        close_pending_payment(
+            pending_payment_account,
+            payment_payer.to_account_info(),
+            resolution,
+        )?;
+    }
+
+    ctx.accounts.admin_audit_log.record(
+        AdminActionCode::RemoveTxidFromMonitoring,
+        ctx.accounts.admin_pubkey.key(),
+        Clock::get()?.unix_timestamp as u32,
+        &txid,
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProcessPastelTxStatusReport<'info> {
+    #[account(mut, constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized)]
+    pub oracle_contract_state: Account<'info, OracleContractState>,
+
+    /// CHECK: Manual checks are performed in the instruction to ensure the contributor is valid and authorized. This includes verifying signatures and other relevant validations.
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+    // You can add other accounts as needed
+}
+
+// The number of submissions required before consensus is calculated for a txid. With auto-scaling enabled
+// the quorum grows with the number of mature contributors, but never drops below min_quorum.
+pub fn effective_quorum(state: &OracleContractState, mature_contributor_count: usize) -> u32 {
+    effective_quorum_for_ticket_type(state, mature_contributor_count, None)
+}
+
+// As effective_quorum, with the ticket type's quorum override, if set, in place of min_quorum
+pub fn effective_quorum_for_ticket_type(
+    state: &OracleContractState,
+    mature_contributor_count: usize,
+    pastel_ticket_type: Option<PastelTicketType>,
+) -> u32 {
+    let min_quorum = match pastel_ticket_type.map_or(0, |ticket_type| {
+        state.ticket_type_quorums[ticket_type as usize]
+    }) {
+        0 => state.min_quorum,
+        ticket_type_quorum => ticket_type_quorum,
+    };
+    if !state.quorum_auto_scaling_enabled {
+        return min_quorum;
+    }
+    let scaled_quorum = (mature_contributor_count as u64)
+        .to_fixed_giga()
+        .mul_up(state.quorum_fraction)
+        .div_ceil(ONE);
+    // The submission cap bounds the scaled quorum, otherwise a large registry could never reach it
+    let max_quorum = match state.max_submissions_per_txid {
+        0 => u32::MAX,
+        max_submissions_per_txid => max_submissions_per_txid,
+    };
+    min_quorum.max(scaled_quorum.min(max_quorum as u64) as u32)
+}
+
+// The ticket type most of a txid's pending reports carry; a tie goes to the type with the larger quorum
+// override, so splitting reports across ticket types cannot lower the quorum
+pub fn dominant_ticket_type(
+    state: &OracleContractState,
+    temp_report_account: &TempTxStatusReportAccount,
+    txid: &str,
+) -> Option<PastelTicketType> {
+    let mut report_counts = [0u32; PASTEL_TICKET_TYPE_VARIANT_COUNT];
+    for temp_report in &temp_report_account.reports {
+        let Ok(common_data) = temp_report_account.common_data_of(temp_report) else {
+            continue;
+        };
+        if let Some(ticket_type) = common_data
+            .pastel_ticket_type
+            .filter(|_| common_data.txid == txid)
+        {
+            report_counts[ticket_type as usize] += 1;
+        }
+    }
+    PastelTicketType::ALL
+        .into_iter()
+        .filter(|&ticket_type| report_counts[ticket_type as usize] > 0)
+        .max_by_key(|&ticket_type| {
+            (
+                report_counts[ticket_type as usize],
+                state.ticket_type_quorums[ticket_type as usize],
+            )
+        })
+}
+
+// The quorum a txid must reach, based on the dominant ticket type of its pending reports
+pub fn txid_quorum(
+    state: &OracleContractState,
+    temp_report_account: &TempTxStatusReportAccount,
+    mature_contributor_count: usize,
+    txid: &str,
+) -> u32 {
+    effective_quorum_for_ticket_type(
+        state,
+        mature_contributor_count,
+        dominant_ticket_type(state, temp_report_account, txid),
+    )
+}
+
+// Active consensus configuration, including values that are still compiled-in constants
+#[event]
+pub struct ConsensusConfig {
+    pub registered_contributor_count: u32,
+    pub active_contributor_count: u32,
+    pub mature_contributor_count: u32,
+    pub effective_quorum: u32,
+    pub min_quorum: u32,
+    pub quorum_auto_scaling_enabled: bool,
+    pub quorum_fraction: u64,
+    pub accuracy_policies: [AccuracyPolicy; PASTEL_TICKET_TYPE_VARIANT_COUNT],
+    pub max_wait_before_consensus: u32,
+    pub temp_report_retention_period: u32,
+    pub aggregated_data_retention_period: u32,
+    pub submission_count_retention_period: u32,
+    pub early_bird_multiplier: u64,
+    pub early_bird_decay_period: u32,
+    pub compliance_weight_coefficient: u64,
+    pub reliability_weight_coefficient: u64,
+    pub stake_weight_coefficient: u64,
+    pub hash_mismatch_ban_threshold: u32,
+    pub tie_break_strategy: TieBreakStrategy,
+    pub min_consensus_weight_sum: u64,
+    pub deferred_consensus: bool,
+    pub max_submissions_per_txid: u32,
+    pub split_consensus_margin: u64,
+    pub min_report_weight: u64,
+    pub max_report_weight: u64,
+    pub near_miss_hash_prefix_length: u8,
+    pub consensus_cool_down: u32,
+    pub contributor_activation_delay: u32,
+    pub status_weight_multiplier: u64,
+    pub hash_weight_multiplier: u64,
+    pub ticket_type_quorums: [u32; PASTEL_TICKET_TYPE_VARIANT_COUNT],
+    pub recency_weight_floor: u64,
+    pub recency_ramp_period: u32,
+}
+
+pub fn consensus_config(
+    state: &OracleContractState,
+    registered_contributor_count: usize,
+    active_contributor_count: usize,
+    mature_contributor_count: usize,
+) -> ConsensusConfig {
+    ConsensusConfig {
+        registered_contributor_count: registered_contributor_count as u32,
+        active_contributor_count: active_contributor_count as u32,
+        mature_contributor_count: mature_contributor_count as u32,
+        effective_quorum: effective_quorum(state, mature_contributor_count),
+        min_quorum: state.min_quorum,
+        quorum_auto_scaling_enabled: state.quorum_auto_scaling_enabled,
+        quorum_fraction: state.quorum_fraction,
+        accuracy_policies: state.accuracy_policies,
+        max_wait_before_consensus:
+            MAX_DURATION_IN_SECONDS_FROM_LAST_REPORT_SUBMISSION_BEFORE_COMPUTING_CONSENSUS,
+        temp_report_retention_period: state.temp_report_retention_period,
+        aggregated_data_retention_period: state.aggregated_data_retention_period,
+        submission_count_retention_period: state.submission_count_retention_period,
+        early_bird_multiplier: state.early_bird_multiplier,
+        early_bird_decay_period: state.early_bird_decay_period,
+        compliance_weight_coefficient: state.compliance_weight_coefficient,
+        reliability_weight_coefficient: state.reliability_weight_coefficient,
+        stake_weight_coefficient: state.stake_weight_coefficient,
+        hash_mismatch_ban_threshold: state.hash_mismatch_ban_threshold,
+        tie_break_strategy: state.tie_break_strategy,
+        min_consensus_weight_sum: state.min_consensus_weight_sum,
+        deferred_consensus: state.deferred_consensus,
+        max_submissions_per_txid: state.max_submissions_per_txid,
+        split_consensus_margin: state.split_consensus_margin,
+        min_report_weight: state.min_report_weight,
+        max_report_weight: state.max_report_weight,
+        near_miss_hash_prefix_length: state.near_miss_hash_prefix_length,
+        consensus_cool_down: state.consensus_cool_down,
+        contributor_activation_delay: state.contributor_activation_delay,
+        status_weight_multiplier: state.status_weight_multiplier,
+        hash_weight_multiplier: state.hash_weight_multiplier,
+        ticket_type_quorums: state.ticket_type_quorums,
+        recency_weight_floor: state.recency_weight_floor,
+        recency_ramp_period: state.recency_ramp_period,
+    }
+}
+
+#[derive(Accounts)]
+pub struct GetConsensusConfig<'info> {
+    #[account(constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized)]
+    pub oracle_contract_state: Account<'info, OracleContractState>,
+    #[account(seeds = [b"contributor_data"], bump)]
+    pub contributor_data_account: Account<'info, ContributorDataAccount>,
+}
+
+pub fn get_consensus_config_helper(ctx: Context<GetConsensusConfig>) -> Result<()> {
+    let contributor_data_account = &ctx.accounts.contributor_data_account;
+    emit!(consensus_config(
+        &ctx.accounts.oracle_contract_state,
+        contributor_data_account.contributors.len(),
+        contributor_data_account.active_contributor_count(),
+        contributor_data_account.mature_contributor_count(
+            &ctx.accounts.oracle_contract_state,
+            Clock::get()?.unix_timestamp as u32,
+        ),
+    ));
+    Ok(())
+}
+
+pub fn should_calculate_consensus(
+    state: &OracleContractState,
+    txid_submission_counts_account: &TxidSubmissionCountsAccount,
+    temp_report_account: &TempTxStatusReportAccount,
+    mature_contributor_count: usize,
+    txid: &str,
+    current_unix_timestamp: u32,
+) -> bool {
+    // Retrieve the count of submissions and last updated timestamp for the given txid
+    let (submission_count, last_updated, quorum_reached_at) = txid_submission_counts_account
+        .find_submission_count(txid)
+        .map(|c| (c.count, c.last_updated, c.quorum_reached_at))
+        .unwrap_or((0, 0, 0));
+
+    // Check if the minimum threshold of reports is met
+    let quorum = txid_quorum(state, temp_report_account, mature_contributor_count, txid);
+    let min_threshold_met = submission_count >= quorum;
+
+    // Check if N minutes have elapsed since the last update
+    let max_waiting_period_elapsed_for_txid = current_unix_timestamp - last_updated
+        >= MAX_DURATION_IN_SECONDS_FROM_LAST_REPORT_SUBMISSION_BEFORE_COMPUTING_CONSENSUS;
+
+    // Once the quorum is met, hold consensus back for the cool-down so late reports join this round. A quorum
+    // met without being recorded (e.g. the quorum shrank since) counts from the latest submission.
+    let quorum_reached_at = if quorum_reached_at != 0 {
+        quorum_reached_at
+    } else {
+        last_updated
+    };
+    let cool_down_elapsed =
+        current_unix_timestamp.saturating_sub(quorum_reached_at) >= state.consensus_cool_down;
+
+    // Calculate consensus if minimum threshold is met or if N minutes have passed with at least the quorum of reports
+    (min_threshold_met || (max_waiting_period_elapsed_for_txid && submission_count >= quorum))
+        && cool_down_elapsed
+}
+
+pub fn cleanup_old_submission_counts(state: &mut OracleContractState, current_time: u32) {
+    state.txid_submission_counts.retain(|count| {
+        current_time.saturating_sub(count.last_updated) < state.submission_count_retention_period
+    });
+}
+
+pub fn usize_to_txid_status(index: usize) -> Option<TxidStatus> {
+    match index {
+        0 => Some(TxidStatus::Invalid),
+        1 => Some(TxidStatus::PendingMining),
+        2 => Some(TxidStatus::MinedPendingActivation),
+        3 => Some(TxidStatus::MinedActivated),
+        _ => None,
+    }
+}
+
+// Validates a string before it is written into an account. Strings are already valid UTF-8, so the bound
+// is on the encoded byte length, which is what account space calculations are based on.
+pub fn validate_bounded_string(value: &str, max_length: usize) -> Result<()> {
+    if value.len() > max_length {
+        msg!(
+            "Error: StringTooLong ({} bytes exceeds the maximum of {} bytes)",
+            value.len(),
+            max_length
+        );
+        return Err(OracleError::StringTooLong.into());
+    }
+    Ok(())
+}
+
+// Truncates a string to at most max_length bytes without splitting a multi-byte character
+pub fn truncate_to_byte_length(value: &str, max_length: usize) -> String {
+    let mut end = value.len().min(max_length);
+    while !value.is_char_boundary(end) {
+        end -= 1;
+    }
+    value[..end].to_string()
+}
+
+// Validates a TXID received at an instruction boundary: non-empty, at most MAX_TXID_LENGTH characters, and hex-encoded
+pub fn validate_txid(txid: &str) -> Result<()> {
+    if txid.is_empty() {
+        msg!("Error: InvalidTxid (TXID is empty)");
+        return Err(OracleError::InvalidTxid.into());
+    }
+    if validate_bounded_string(txid, MAX_TXID_LENGTH).is_err() {
+        msg!("Error: InvalidTxid (TXID exceeds maximum length)");
+        return Err(OracleError::InvalidTxid.into());
+    }
+    if !txid.chars().all(|c| c.is_ascii_hexdigit()) {
+        msg!("Error: InvalidTxid (TXID contains non-hex characters)");
+        return Err(OracleError::InvalidTxid.into());
+    }
+    Ok(())
+}
+
+// Function to handle the submission of Pastel transaction status reports
+pub fn validate_data_contributor_report(
+    report: &PastelTxStatusReport,
+    oracle_contract_state: &OracleContractState,
+) -> Result<()> {
+    // Direct return in case of invalid data, reducing nested if conditions
+    validate_txid(&report.txid)?;
+    // Simplified TXID status validation
+    if !matches!(
+        report.txid_status,
+        TxidStatus::MinedActivated
+            | TxidStatus::MinedPendingActivation
+            | TxidStatus::PendingMining
+            | TxidStatus::Invalid
+    ) {
+        return Err(OracleError::InvalidTxidStatus.into());
+    }
+    // Direct return in case of missing data, reducing nested if conditions
+    let Some(pastel_ticket_type) = report.pastel_ticket_type else {
+        msg!("Error: Missing Pastel Ticket Type");
+        return Err(OracleError::MissingPastelTicketType.into());
+    };
+    // Direct return in case of invalid hash, reducing nested if conditions
+    if let Some(hash) = &report.first_6_characters_of_sha3_256_hash_of_corresponding_file {
+        if hash.len() != 6 || !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+            msg!("Error: Invalid File Hash Length or Non-hex characters");
+            return Err(OracleError::InvalidFileHashLength.into());
+        }
+    } else if oracle_contract_state.file_hash_required[pastel_ticket_type as usize] {
+        msg!(
+            "Error: Missing File Hash for ticket type {:?}",
+            pastel_ticket_type
+        );
+        return Err(OracleError::MissingFileHash.into());
+    }
+    Ok(())
+}
+
+impl Contributor {
+    // Borsh-serialized size, which unlike size_of counts the signature string's bytes and length prefix
+    pub fn serialized_len(&self) -> usize {
+        32 // reward_address
+            + 4 + self.registration_entrance_fee_transaction_signature.len()
+            + 8 // compliance_score
+            + 4 // last_active_timestamp
+            + 4 // total_reports_submitted
+            + 4 // accurate_reports_count
+            + 4 // current_streak
+            + 8 // reliability_score
+            + 4 // consensus_failures
+            + 4 // ban_expiry
+            + 3 // is_eligible_for_rewards, is_recently_active, is_reliable
+            + 8 // total_rewards_earned_lamports
+            + 4 // distinct_hash_mismatches
+            + 8 // stake_weight
+            + 8 // owed_participation_rewards_lamports
+            + 4 // last_submission_timestamp
+            + 1 // is_deactivated
+            + 4 // registered_at
+            + 4 // probation_until
+            + 4 // report_window_start
+            + 4 // report_window_count
+            + RECENT_OUTCOMES_CAPACITY * (4 + 1) // recent_outcomes
+            + 1 // recent_outcomes_head
+    }
+
+    // Overwrites the oldest outcome once the ring buffer is full
+    pub fn record_outcome(&mut self, timestamp: u32, was_accurate: bool) {
+        let head = self.recent_outcomes_head as usize % RECENT_OUTCOMES_CAPACITY;
+        self.recent_outcomes[head] = ReportOutcome {
+            timestamp,
+            was_accurate,
+        };
+        self.recent_outcomes_head = ((head + 1) % RECENT_OUTCOMES_CAPACITY) as u8;
+    }
+
+    // Recorded outcomes, oldest first
+    pub fn recent_outcomes(&self) -> impl Iterator<Item = &ReportOutcome> {
+        let head = self.recent_outcomes_head as usize % RECENT_OUTCOMES_CAPACITY;
+        self.recent_outcomes[head..]
+            .iter()
+            .chain(&self.recent_outcomes[..head])
+            .filter(|outcome| outcome.timestamp != 0)
+    }
+
+    // Check if the contributor is currently banned
+    pub fn calculate_is_banned(&self, current_time: u32) -> bool {
+        current_time < self.ban_expiry
+    }
+
+    pub fn is_on_probation(&self, current_time: u32) -> bool {
+        current_time < self.probation_until
+    }
+
+    // Only a contributor's first report waits out the quiet period; contributors registered
+    // before registration times were recorded are treated as long past it
+    pub fn is_in_quiet_period(&self, quiet_period: u32, current_time: u32) -> bool {
+        self.last_submission_timestamp == 0
+            && current_time.saturating_sub(self.registered_at) < quiet_period
+    }
+
+    // Consensus failures while on probation count double toward the next ban
+    pub fn consensus_failure_increment(&self, current_time: u32) -> u32 {
+        if self.is_on_probation(current_time) {
+            2
+        } else {
+            1
+        }
+    }
+
+    // Counts a judged report toward the current window; returns whether it is within the cap and so still
+    // advances the report counters that eligibility is based on
+    pub fn record_counted_report(
+        &mut self,
+        oracle_contract_state: &OracleContractState,
+        current_time: u32,
+    ) -> bool {
+        let max_counted_reports = oracle_contract_state.max_counted_reports_per_window;
+        if max_counted_reports == 0 {
+            return true;
+        }
+        if self.report_window_count == 0
+            || current_time.saturating_sub(self.report_window_start)
+                >= oracle_contract_state.counted_reports_window
+        {
+            self.report_window_start = current_time;
+            self.report_window_count = 0;
+        }
+        self.report_window_count = self.report_window_count.saturating_add(1);
+        self.report_window_count <= max_counted_reports
+    }
+
+    // Latest report or heartbeat
+    pub fn last_seen(&self) -> u32 {
+        self.last_active_timestamp
+            .max(self.last_submission_timestamp)
+    }
+
+    // Idle once neither a report nor a heartbeat has been seen for the configured period
+    pub fn calculate_is_idle(
+        &self,
+        oracle_contract_state: &OracleContractState,
+        current_time: u32,
+    ) -> bool {
+        oracle_contract_state.contributor_idle_period != 0
+            && current_time.saturating_sub(self.last_seen())
+                >= oracle_contract_state.contributor_idle_period
+    }
+
+    // Method to determine if the contributor is eligible for rewards
+    pub fn calculate_is_eligible_for_rewards(
+        &self,
+        oracle_contract_state: &OracleContractState,
+    ) -> bool {
+        self.total_reports_submitted >= oracle_contract_state.min_reports_for_reward
+            && self.reliability_score >= oracle_contract_state.min_reliability_score_for_reward
+            && self.compliance_score >= oracle_contract_state.min_compliance_score_for_reward
+    }
+
+    // Add a paid out reward to the contributor's running total
+    pub fn record_reward_payout(&mut self, reward_amount: u64) {
+        self.total_rewards_earned_lamports = self
+            .total_rewards_earned_lamports
+            .saturating_add(reward_amount);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Copy, AnchorSerialize, AnchorDeserialize)]
+pub enum AdminActionCode {
+    ReallocateOracleState,
+    SetBridgeContract, // Retired with the single bridge pubkey; kept so earlier audit records still decode
+    TransferAdmin,
+    SetAccuracyPolicy,
+    SetMaxMonitoredTxids,
+    SetQuorumConfig,
+    RemoveTxidFromMonitoring,
+    WithdrawFunds,
+    SetRewardThresholds,
+    RecomputeAllEligibility,
+    SetEarlyBirdConfig,
+    RepairSubmissionCounts,
+    SetHashMismatchBanThreshold,
+    SetWeightCoefficients,
+    SetContributorStakeWeight,
+    SetLogisticScaling,
+    SetRetentionPeriods,
+    SetReliabilityBonusFactor,
+    SetTieBreakStrategy,
+    SetAutoReplenishConfig,
+    SetFileHashRequirement,
+    SetParticipationReward,
+    SetMinConsensusWeightSum,
+    SetAuditSelectionRate,
+    SetDeferredConsensus,
+    SetMaxSubmissionsPerTxid,
+    SetFeeSplit,
+    SetContributorIdlePeriod,
+    SetSplitConsensusMargin,
+    SetAutoPayRewards,
+    SetReportWeightBounds,
+    SetRewardsPaused,
+    SetStreakDecayWindow,
+    SetNearMissHashPrefixLength,
+    AddBridgeContract,
+    RemoveBridgeContract,
+    SetConsensusCoolDown,
+    SetContributorActivationDelay,
+    SetUnderReportedRewardBoost,
+    SetProbationConfig,
+    UnbanContributor,
+    SetPaymentExpiryPeriod,
+    BatchRegisterContributors,
+    SetTallyWeightMultipliers,
+    SetSubmissionQuietPeriod,
+    SweepOrphanedTempReports,
+    SetRewardMode,
+    AddToBlacklist,
+    RemoveFromBlacklist,
+    SetFinalizationBounty,
+    SetReliabilityDecay,
+    SetCountedReportsCap,
+    SetMaxAggregationAge,
+    MigrateSchema,
+    SetTicketTypeQuorum,
+    ReverseConsensus,
+    SetMaxActiveReports,
+    SetInitialComplianceScore,
+    SetSubmissionFee,
+    SetRecencyDecayConfig,
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct AdminAuditRecord {
+    pub action_code: AdminActionCode,
+    pub actor: Pubkey,
+    pub timestamp: u32,
+    pub param_summary: String,
+}
+
+// Ring buffer of the most recent admin actions; once full, each new record overwrites the oldest one
+#[account]
+pub struct AdminAuditLogAccount {
+    pub records: Vec<AdminAuditRecord>,
+    pub next_index: u32, // Slot the next record is written to once the buffer is full
+    pub total_records: u64, // Number of records ever appended, including evicted ones
+}
+
+impl AdminAuditLogAccount {
+    pub fn record(
+        &mut self,
+        action_code: AdminActionCode,
+        actor: Pubkey,
+        timestamp: u32,
+        param_summary: &str,
+    ) {
+        let record = AdminAuditRecord {
+            action_code,
+            actor,
+            timestamp,
+            param_summary: truncate_to_byte_length(param_summary, MAX_AUDIT_PARAM_SUMMARY_LENGTH),
+        };
+
+        if self.records.len() < ADMIN_AUDIT_LOG_CAPACITY {
+            self.records.push(record);
+        } else {
+            self.records[self.next_index as usize] = record;
+            self.next_index = (self.next_index + 1) % ADMIN_AUDIT_LOG_CAPACITY as u32;
+        }
+        self.total_records = self.total_records.saturating_add(1);
+    }
+
+    // Returns up to `limit` retained records in chronological order, starting `start` records after the oldest
+    pub fn page(&self, start: u32, limit: u32) -> Vec<AdminAuditRecord> {
+        let len = self.records.len();
+        let oldest_index = if len < ADMIN_AUDIT_LOG_CAPACITY {
+            0
+        } else {
+            self.next_index as usize
+        };
+        (start as usize..len)
+            .take(limit.min(MAX_AUDIT_LOG_PAGE_SIZE) as usize)
+            .map(|position| self.records[(oldest_index + position) % len].clone())
+            .collect()
+    }
+}
+
+#[event]
+pub struct AdminAuditLogPage {
+    pub start: u32,
+    pub total_records: u64,
+    pub records: Vec<AdminAuditRecord>,
+}
+
+#[derive(Accounts)]
+pub struct GetAdminAuditLog<'info> {
+    #[account(seeds = [b"admin_audit_log"], bump)]
+    pub admin_audit_log: Account<'info, AdminAuditLogAccount>,
+}
+
+pub fn get_admin_audit_log_helper(
+    ctx: Context<GetAdminAuditLog>,
+    start: u32,
+    limit: u32,
+) -> Result<()> {
+    let admin_audit_log = &ctx.accounts.admin_audit_log;
+    emit!(AdminAuditLogPage {
+        start,
+        total_records: admin_audit_log.total_records,
+        records: admin_audit_log.page(start, limit),
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ManageBridgeContracts<'info> {
+    #[account(mut, has_one = admin_pubkey, constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized)]
+    pub oracle_contract_state: Account<'info, OracleContractState>,
+    pub admin_pubkey: Signer<'info>,
+    #[account(mut, seeds = [b"admin_audit_log"], bump)]
+    pub admin_audit_log: Account<'info, AdminAuditLogAccount>,
+}
+
+impl<'info> ManageBridgeContracts<'info> {
+    pub fn add_bridge_contract(
+        ctx: Context<ManageBridgeContracts>,
+        bridge_contract_pubkey: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts
+            .oracle_contract_state
+            .add_bridge_contract(bridge_contract_pubkey)?;
+        msg!("Bridge contract authorized: {:?}", bridge_contract_pubkey);
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::AddBridgeContract,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &bridge_contract_pubkey.to_string(),
+        );
+        Ok(())
+    }
+
+    pub fn remove_bridge_contract(
+        ctx: Context<ManageBridgeContracts>,
+        bridge_contract_pubkey: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts
+            .oracle_contract_state
+            .remove_bridge_contract(&bridge_contract_pubkey)?;
+        msg!("Bridge contract deauthorized: {:?}", bridge_contract_pubkey);
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::RemoveBridgeContract,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &bridge_contract_pubkey.to_string(),
+        );
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct ManageBlacklist<'info> {
+    #[account(mut, has_one = admin_pubkey, constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized)]
+    pub oracle_contract_state: Account<'info, OracleContractState>,
+    pub admin_pubkey: Signer<'info>,
+    #[account(mut, seeds = [b"admin_audit_log"], bump)]
+    pub admin_audit_log: Account<'info, AdminAuditLogAccount>,
+}
+
+impl<'info> ManageBlacklist<'info> {
+    pub fn add_to_blacklist(ctx: Context<ManageBlacklist>, reward_address: Pubkey) -> Result<()> {
+        ctx.accounts
+            .oracle_contract_state
+            .add_to_blacklist(reward_address)?;
+        msg!("Reward address blacklisted: {:?}", reward_address);
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::AddToBlacklist,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &reward_address.to_string(),
+        );
+        Ok(())
+    }
+
+    pub fn remove_from_blacklist(
+        ctx: Context<ManageBlacklist>,
+        reward_address: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts
+            .oracle_contract_state
+            .remove_from_blacklist(&reward_address)?;
+        msg!(
+            "Reward address removed from blacklist: {:?}",
+            reward_address
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::RemoveFromBlacklist,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &reward_address.to_string(),
+        );
+        Ok(())
+    }
+}
+
+// The admin and the bridge contract must be distinct keys; otherwise the admin could add txids for
+// monitoring and process the payments for them on their own
+fn validate_role_assignment(admin_pubkey: &Pubkey, bridge_contract_pubkey: &Pubkey) -> Result<()> {
+    if admin_pubkey == bridge_contract_pubkey {
+        msg!(
+            "Conflicting role assignment: {} cannot be both the admin and the bridge contract",
+            admin_pubkey
+        );
+        return Err(OracleError::ConflictingRoleAssignment.into());
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct TransferAdmin<'info> {
+    #[account(mut, has_one = admin_pubkey, constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized)]
+    pub oracle_contract_state: Account<'info, OracleContractState>,
+    pub admin_pubkey: Signer<'info>,
+    #[account(mut, seeds = [b"admin_audit_log"], bump)]
+    pub admin_audit_log: Account<'info, AdminAuditLogAccount>,
+}
+
+impl<'info> TransferAdmin<'info> {
+    pub fn transfer_admin(ctx: Context<TransferAdmin>, new_admin_pubkey: Pubkey) -> Result<()> {
+        let state = &mut ctx.accounts.oracle_contract_state;
+        for bridge_contract_pubkey in state.authorized_bridges() {
+            validate_role_assignment(&new_admin_pubkey, bridge_contract_pubkey)?;
+        }
+        state.admin_pubkey = new_admin_pubkey;
+        msg!("Admin pubkey updated: {:?}", new_admin_pubkey);
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::TransferAdmin,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &new_admin_pubkey.to_string(),
+        );
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct SetOracleConfig<'info> {
+    #[account(mut, has_one = admin_pubkey, constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized)]
+    pub oracle_contract_state: Account<'info, OracleContractState>,
+    pub admin_pubkey: Signer<'info>,
+    #[account(mut, seeds = [b"admin_audit_log"], bump)]
+    pub admin_audit_log: Account<'info, AdminAuditLogAccount>,
+}
+
+impl<'info> SetOracleConfig<'info> {
+    pub fn set_accuracy_policy(
+        ctx: Context<SetOracleConfig>,
+        pastel_ticket_type: PastelTicketType,
+        accuracy_policy: AccuracyPolicy,
+    ) -> Result<()> {
+        let state = &mut ctx.accounts.oracle_contract_state;
+        state.accuracy_policies[pastel_ticket_type as usize] = accuracy_policy;
+        msg!(
+            "Accuracy policy for ticket type {:?} updated: {:?}",
+            pastel_ticket_type,
+            accuracy_policy
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetAccuracyPolicy,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!("{:?}={:?}", pastel_ticket_type, accuracy_policy),
+        );
+        Ok(())
+    }
+
+    pub fn set_file_hash_requirement(
+        ctx: Context<SetOracleConfig>,
+        pastel_ticket_type: PastelTicketType,
+        file_hash_required: bool,
+    ) -> Result<()> {
+        let state = &mut ctx.accounts.oracle_contract_state;
+        state.file_hash_required[pastel_ticket_type as usize] = file_hash_required;
+        msg!(
+            "File hash requirement for ticket type {:?} updated: {}",
+            pastel_ticket_type,
+            file_hash_required
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetFileHashRequirement,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!("{:?}={}", pastel_ticket_type, file_hash_required),
+        );
+        Ok(())
+    }
+
+    pub fn set_max_monitored_txids(
+        ctx: Context<SetOracleConfig>,
+        max_monitored_txids: u32,
+    ) -> Result<()> {
+        let state = &mut ctx.accounts.oracle_contract_state;
+        validate_max_monitored_txids(
+            state,
+            state.to_account_info().data_len(),
+            max_monitored_txids,
+        )?;
+        state.max_monitored_txids = max_monitored_txids;
+        msg!("Max monitored txids updated: {}", max_monitored_txids);
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetMaxMonitoredTxids,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!("max_monitored_txids={}", max_monitored_txids),
+        );
+        Ok(())
+    }
+
+    pub fn set_quorum_config(
+        ctx: Context<SetOracleConfig>,
+        min_quorum: u32,
+        quorum_auto_scaling_enabled: bool,
+        quorum_fraction: u64,
+    ) -> Result<()> {
+        let state = &mut ctx.accounts.oracle_contract_state;
+        if min_quorum == 0
+            || quorum_fraction > ONE
+            || (state.max_submissions_per_txid != 0 && min_quorum > state.max_submissions_per_txid)
+        {
+            return Err(OracleError::InvalidConfigValue.into());
+        }
+        state.min_quorum = min_quorum;
+        state.quorum_auto_scaling_enabled = quorum_auto_scaling_enabled;
+        state.quorum_fraction = quorum_fraction;
+        msg!(
+            "Quorum config updated: Min Quorum: {}, Auto-Scaling Enabled: {}, Quorum Fraction: {}",
+            min_quorum,
+            quorum_auto_scaling_enabled,
+            quorum_fraction
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetQuorumConfig,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!(
+                "min={},scaling={},fraction={}",
+                min_quorum, quorum_auto_scaling_enabled, quorum_fraction
+            ),
+        );
+        Ok(())
+    }
+
+    pub fn set_reward_thresholds(
+        ctx: Context<SetOracleConfig>,
+        min_reports_for_reward: u32,
+        min_reliability_score_for_reward: u64,
+        min_compliance_score_for_reward: u64,
+    ) -> Result<()> {
+        let state = &mut ctx.accounts.oracle_contract_state;
+        if min_reliability_score_for_reward > MAX_COMPLIANCE_SCORE
+            || min_compliance_score_for_reward > MAX_COMPLIANCE_SCORE
+        {
+            return Err(OracleError::InvalidConfigValue.into());
+        }
+        validate_initial_compliance_score(
+            state.initial_compliance_score,
+            min_compliance_score_for_reward,
+        )?;
+        state.min_reports_for_reward = min_reports_for_reward;
+        state.min_reliability_score_for_reward = min_reliability_score_for_reward;
+        state.min_compliance_score_for_reward = min_compliance_score_for_reward;
+        msg!(
+            "Reward thresholds updated: Min Reports: {}, Min Reliability Score: {}, Min Compliance Score: {}",
+            min_reports_for_reward,
+            min_reliability_score_for_reward,
+            min_compliance_score_for_reward
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetRewardThresholds,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!(
+                "reports={},reliability={},compliance={}",
+                min_reports_for_reward,
+                min_reliability_score_for_reward,
+                min_compliance_score_for_reward
+            ),
+        );
+        Ok(())
+    }
+
+    pub fn set_early_bird_config(
+        ctx: Context<SetOracleConfig>,
+        early_bird_multiplier: u64,
+        early_bird_decay_period: u32,
+    ) -> Result<()> {
+        if !(ONE..=MAX_EARLY_BIRD_MULTIPLIER).contains(&early_bird_multiplier)
+            || early_bird_decay_period == 0
+        {
+            return Err(OracleError::InvalidConfigValue.into());
+        }
+        let state = &mut ctx.accounts.oracle_contract_state;
+        state.early_bird_multiplier = early_bird_multiplier;
+        state.early_bird_decay_period = early_bird_decay_period;
+        msg!(
+            "Early-bird config updated: Multiplier: {}, Decay Period: {}",
+            early_bird_multiplier,
+            early_bird_decay_period
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetEarlyBirdConfig,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!(
+                "multiplier={},decay_period={}",
+                early_bird_multiplier, early_bird_decay_period
+            ),
+        );
+        Ok(())
+    }
+
+    pub fn set_near_miss_hash_prefix_length(
+        ctx: Context<SetOracleConfig>,
+        near_miss_hash_prefix_length: u8,
+    ) -> Result<()> {
+        // A full-length prefix is an exact match, which is already accurate
+        if near_miss_hash_prefix_length >= FILE_HASH_PREFIX_LENGTH {
+            return Err(OracleError::InvalidConfigValue.into());
+        }
+        ctx.accounts
+            .oracle_contract_state
+            .near_miss_hash_prefix_length = near_miss_hash_prefix_length;
+        msg!(
+            "Near-miss hash prefix length updated: {}",
+            near_miss_hash_prefix_length
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetNearMissHashPrefixLength,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!("prefix_length={}", near_miss_hash_prefix_length),
+        );
+        Ok(())
+    }
+
+    pub fn set_hash_mismatch_ban_threshold(
+        ctx: Context<SetOracleConfig>,
+        hash_mismatch_ban_threshold: u32,
+    ) -> Result<()> {
+        let state = &mut ctx.accounts.oracle_contract_state;
+        state.hash_mismatch_ban_threshold = hash_mismatch_ban_threshold;
+        msg!(
+            "Hash mismatch ban threshold updated: {}",
+            hash_mismatch_ban_threshold
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetHashMismatchBanThreshold,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!("threshold={}", hash_mismatch_ban_threshold),
+        );
+        Ok(())
+    }
+
+    pub fn set_weight_coefficients(
+        ctx: Context<SetOracleConfig>,
+        compliance_weight_coefficient: u64,
+        reliability_weight_coefficient: u64,
+        stake_weight_coefficient: u64,
+    ) -> Result<()> {
+        let coefficients = [
+            compliance_weight_coefficient,
+            reliability_weight_coefficient,
+            stake_weight_coefficient,
+        ];
+        if coefficients.iter().any(|&c| c > MAX_WEIGHT_COEFFICIENT)
+            || coefficients.iter().all(|&c| c == ZERO)
+        {
+            return Err(OracleError::InvalidConfigValue.into());
+        }
+        let state = &mut ctx.accounts.oracle_contract_state;
+        state.compliance_weight_coefficient = compliance_weight_coefficient;
+        state.reliability_weight_coefficient = reliability_weight_coefficient;
+        state.stake_weight_coefficient = stake_weight_coefficient;
+        msg!(
+            "Report weight coefficients updated: Compliance: {}, Reliability: {}, Stake: {}",
+            compliance_weight_coefficient,
+            reliability_weight_coefficient,
+            stake_weight_coefficient
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetWeightCoefficients,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!(
+                "compliance={},reliability={},stake={}",
+                compliance_weight_coefficient,
+                reliability_weight_coefficient,
+                stake_weight_coefficient
+            ),
+        );
+        Ok(())
+    }
+
+    pub fn set_report_weight_bounds(
+        ctx: Context<SetOracleConfig>,
+        min_report_weight: u64,
+        max_report_weight: u64,
+    ) -> Result<()> {
+        if max_report_weight != 0 && min_report_weight > max_report_weight {
+            return Err(OracleError::InvalidConfigValue.into());
+        }
+        let state = &mut ctx.accounts.oracle_contract_state;
+        state.min_report_weight = min_report_weight;
+        state.max_report_weight = max_report_weight;
+        msg!(
+            "Report weight bounds updated: Floor: {}, Ceiling: {}",
+            min_report_weight,
+            max_report_weight
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetReportWeightBounds,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!("min={},max={}", min_report_weight, max_report_weight),
+        );
+        Ok(())
+    }
+
+    pub fn set_tally_weight_multipliers(
+        ctx: Context<SetOracleConfig>,
+        status_weight_multiplier: u64,
+        hash_weight_multiplier: u64,
+    ) -> Result<()> {
+        if status_weight_multiplier > MAX_WEIGHT_COEFFICIENT
+            || hash_weight_multiplier > MAX_WEIGHT_COEFFICIENT
+        {
+            return Err(OracleError::InvalidConfigValue.into());
+        }
+        let state = &mut ctx.accounts.oracle_contract_state;
+        state.status_weight_multiplier = status_weight_multiplier;
+        state.hash_weight_multiplier = hash_weight_multiplier;
+        msg!(
+            "Tally weight multipliers updated: Status: {}, Hash: {}",
+            status_weight_multiplier,
+            hash_weight_multiplier
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetTallyWeightMultipliers,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!(
+                "status={},hash={}",
+                status_weight_multiplier, hash_weight_multiplier
+            ),
+        );
+        Ok(())
+    }
+
+    pub fn set_logistic_scaling(
+        ctx: Context<SetOracleConfig>,
+        logistic_steepness: u64,
+        logistic_midpoint: u64,
+    ) -> Result<()> {
+        validate_logistic_config(logistic_steepness, logistic_midpoint)?;
+        let state = &mut ctx.accounts.oracle_contract_state;
+        state.logistic_steepness = logistic_steepness;
+        state.logistic_midpoint = logistic_midpoint;
+        msg!(
+            "Logistic scaling updated: Steepness: {}, Midpoint: {}",
+            logistic_steepness,
+            logistic_midpoint
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetLogisticScaling,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!(
+                "steepness={},midpoint={}",
+                logistic_steepness, logistic_midpoint
+            ),
+        );
+        Ok(())
+    }
+
+    pub fn set_retention_periods(
+        ctx: Context<SetOracleConfig>,
+        temp_report_retention_period: u32,
+        aggregated_data_retention_period: u32,
+        submission_count_retention_period: u32,
+    ) -> Result<()> {
+        if temp_report_retention_period == 0
+            || aggregated_data_retention_period == 0
+            || submission_count_retention_period == 0
+        {
+            return Err(OracleError::InvalidConfigValue.into());
+        }
+        let state = &mut ctx.accounts.oracle_contract_state;
+        state.temp_report_retention_period = temp_report_retention_period;
+        state.aggregated_data_retention_period = aggregated_data_retention_period;
+        state.submission_count_retention_period = submission_count_retention_period;
+        msg!(
+            "Retention periods updated: Temp Reports: {}, Aggregated Data: {}, Submission Counts: {}",
+            temp_report_retention_period,
+            aggregated_data_retention_period,
+            submission_count_retention_period
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetRetentionPeriods,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!(
+                "temp={},aggregated={},counts={}",
+                temp_report_retention_period,
+                aggregated_data_retention_period,
+                submission_count_retention_period
+            ),
+        );
+        Ok(())
+    }
+
+    pub fn set_reliability_bonus_factor(
+        ctx: Context<SetOracleConfig>,
+        reliability_bonus_factor: u64,
+    ) -> Result<()> {
+        if !(ONE..=MAX_RELIABILITY_BONUS_FACTOR).contains(&reliability_bonus_factor) {
+            return Err(OracleError::InvalidConfigValue.into());
+        }
+        ctx.accounts.oracle_contract_state.reliability_bonus_factor = reliability_bonus_factor;
+        msg!(
+            "Reliability bonus factor updated: {}",
+            reliability_bonus_factor
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetReliabilityBonusFactor,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!("factor={}", reliability_bonus_factor),
+        );
+        Ok(())
+    }
+
+    pub fn set_reward_mode(
+        ctx: Context<SetOracleConfig>,
+        reward_mode: RewardMode,
+        report_count_reward_cap: u64,
+    ) -> Result<()> {
+        if !(ONE..=MAX_REPORT_COUNT_REWARD_CAP).contains(&report_count_reward_cap) {
+            return Err(OracleError::InvalidConfigValue.into());
+        }
+        let state = &mut ctx.accounts.oracle_contract_state;
+        state.reward_mode = reward_mode;
+        state.report_count_reward_cap = report_count_reward_cap;
+        msg!(
+            "Reward mode updated: {:?}, Report-count reward cap: {}",
+            reward_mode,
+            report_count_reward_cap
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetRewardMode,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!("{:?},cap={}", reward_mode, report_count_reward_cap),
+        );
+        Ok(())
+    }
+
+    pub fn set_auto_replenish_config(
+        ctx: Context<SetOracleConfig>,
+        auto_replenish_threshold: u64,
+        auto_replenish_amount: u64,
+        fee_account_reserve: u64,
+    ) -> Result<()> {
+        if auto_replenish_threshold > 0 && auto_replenish_amount == 0 {
+            return Err(OracleError::InvalidConfigValue.into());
+        }
+        let state = &mut ctx.accounts.oracle_contract_state;
+        state.auto_replenish_threshold = auto_replenish_threshold;
+        state.auto_replenish_amount = auto_replenish_amount;
+        state.fee_account_reserve = fee_account_reserve;
+        msg!(
+            "Auto-replenish config updated: Threshold: {}, Amount: {}, Fee Account Reserve: {}",
+            auto_replenish_threshold,
+            auto_replenish_amount,
+            fee_account_reserve
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetAutoReplenishConfig,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!(
+                "threshold={},amount={},reserve={}",
+                auto_replenish_threshold, auto_replenish_amount, fee_account_reserve
+            ),
+        );
+        Ok(())
+    }
+
+    pub fn set_split_consensus_margin(
+        ctx: Context<SetOracleConfig>,
+        split_consensus_margin: u64,
+    ) -> Result<()> {
+        if split_consensus_margin > ONE {
+            return Err(OracleError::InvalidConfigValue.into());
+        }
+        ctx.accounts.oracle_contract_state.split_consensus_margin = split_consensus_margin;
+        msg!("Split consensus margin updated: {}", split_consensus_margin);
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetSplitConsensusMargin,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!("margin={}", split_consensus_margin),
+        );
+        Ok(())
+    }
+
+    pub fn set_consensus_cool_down(
+        ctx: Context<SetOracleConfig>,
+        consensus_cool_down: u32,
+    ) -> Result<()> {
+        // The cool-down never holds a round back longer than the maximum wait before consensus
+        if consensus_cool_down
+            > MAX_DURATION_IN_SECONDS_FROM_LAST_REPORT_SUBMISSION_BEFORE_COMPUTING_CONSENSUS
+        {
+            return Err(OracleError::InvalidConfigValue.into());
+        }
+        ctx.accounts.oracle_contract_state.consensus_cool_down = consensus_cool_down;
+        msg!(
+            "Consensus cool-down updated: {} seconds",
+            consensus_cool_down
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetConsensusCoolDown,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!("cool_down={}", consensus_cool_down),
+        );
+        Ok(())
+    }
+
+    pub fn set_streak_decay_window(
+        ctx: Context<SetOracleConfig>,
+        streak_decay_window: u32,
+    ) -> Result<()> {
+        ctx.accounts.oracle_contract_state.streak_decay_window = streak_decay_window;
+        msg!(
+            "Streak decay window updated: {} seconds",
+            streak_decay_window
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetStreakDecayWindow,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!("window={}", streak_decay_window),
+        );
+        Ok(())
+    }
+
+    pub fn set_contributor_activation_delay(
+        ctx: Context<SetOracleConfig>,
+        contributor_activation_delay: u32,
+    ) -> Result<()> {
+        ctx.accounts
+            .oracle_contract_state
+            .contributor_activation_delay = contributor_activation_delay;
+        msg!(
+            "Contributor activation delay updated: {} seconds",
+            contributor_activation_delay
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetContributorActivationDelay,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!("activation_delay={}", contributor_activation_delay),
+        );
+        Ok(())
+    }
+
+    pub fn set_under_reported_reward_boost(
+        ctx: Context<SetOracleConfig>,
+        volume_threshold: u32,
+        reward_boost: u64,
+    ) -> Result<()> {
+        if !(ONE..=MAX_UNDER_REPORTED_REWARD_BOOST).contains(&reward_boost) {
+            return Err(OracleError::InvalidConfigValue.into());
+        }
+        let state = &mut ctx.accounts.oracle_contract_state;
+        state.under_reported_volume_threshold = volume_threshold;
+        state.under_reported_reward_boost = reward_boost;
+        msg!(
+            "Under-reported reward boost updated: Volume Threshold: {}, Boost: {}",
+            volume_threshold,
+            reward_boost
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetUnderReportedRewardBoost,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!("threshold={},boost={}", volume_threshold, reward_boost),
+        );
+        Ok(())
+    }
+
+    pub fn set_probation_config(
+        ctx: Context<SetOracleConfig>,
+        probation_period: u32,
+        probation_weight_factor: u64,
+    ) -> Result<()> {
+        if probation_weight_factor > ONE {
+            return Err(OracleError::InvalidConfigValue.into());
+        }
+        let state = &mut ctx.accounts.oracle_contract_state;
+        state.probation_period = probation_period;
+        state.probation_weight_factor = probation_weight_factor;
+        msg!(
+            "Probation config updated: Period: {} seconds, Weight Factor: {}",
+            probation_period,
+            probation_weight_factor
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetProbationConfig,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!(
+                "period={},weight_factor={}",
+                probation_period, probation_weight_factor
+            ),
+        );
+        Ok(())
+    }
+
+    pub fn set_payment_expiry_period(
+        ctx: Context<SetOracleConfig>,
+        payment_expiry_period: u32,
+    ) -> Result<()> {
+        ctx.accounts.oracle_contract_state.payment_expiry_period = payment_expiry_period;
+        msg!(
+            "Payment expiry period updated: {} seconds",
+            payment_expiry_period
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetPaymentExpiryPeriod,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!("expiry_period={}", payment_expiry_period),
+        );
+        Ok(())
+    }
+
+    pub fn set_submission_quiet_period(
+        ctx: Context<SetOracleConfig>,
+        submission_quiet_period: u32,
+    ) -> Result<()> {
+        ctx.accounts.oracle_contract_state.submission_quiet_period = submission_quiet_period;
+        msg!(
+            "Submission quiet period updated: {} seconds",
+            submission_quiet_period
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetSubmissionQuietPeriod,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!("quiet_period={}", submission_quiet_period),
+        );
+        Ok(())
+    }
+
+    pub fn set_contributor_idle_period(
+        ctx: Context<SetOracleConfig>,
+        contributor_idle_period: u32,
+    ) -> Result<()> {
+        ctx.accounts.oracle_contract_state.contributor_idle_period = contributor_idle_period;
+        msg!(
+            "Contributor idle period updated: {} seconds",
+            contributor_idle_period
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetContributorIdlePeriod,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!("idle_period={}", contributor_idle_period),
+        );
+        Ok(())
+    }
+
+    pub fn set_fee_split(ctx: Context<SetOracleConfig>, fee_split_bps: u16) -> Result<()> {
+        if fee_split_bps > MAX_FEE_SPLIT_BPS {
+            return Err(OracleError::InvalidConfigValue.into());
+        }
+        ctx.accounts.oracle_contract_state.fee_split_bps = fee_split_bps;
+        msg!(
+            "Registration fee treasury split updated: {} bps",
+            fee_split_bps
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetFeeSplit,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!("bps={}", fee_split_bps),
+        );
+        Ok(())
+    }
+
+    pub fn set_max_submissions_per_txid(
+        ctx: Context<SetOracleConfig>,
+        max_submissions_per_txid: u32,
+    ) -> Result<()> {
+        let state = &mut ctx.accounts.oracle_contract_state;
+        validate_max_submissions_per_txid(state, max_submissions_per_txid)?;
+        state.max_submissions_per_txid = max_submissions_per_txid;
+        msg!(
+            "Max submissions per txid updated: {}",
+            max_submissions_per_txid
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetMaxSubmissionsPerTxid,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!("max={}", max_submissions_per_txid),
+        );
+        Ok(())
+    }
+
+    pub fn set_rewards_paused(ctx: Context<SetOracleConfig>, rewards_paused: bool) -> Result<()> {
+        ctx.accounts.oracle_contract_state.rewards_paused = rewards_paused;
+        msg!("Reward claims paused: {}", rewards_paused);
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetRewardsPaused,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!("paused={}", rewards_paused),
+        );
+        Ok(())
+    }
+
+    pub fn set_auto_pay_rewards(
+        ctx: Context<SetOracleConfig>,
+        auto_pay_rewards: bool,
+    ) -> Result<()> {
+        ctx.accounts.oracle_contract_state.auto_pay_rewards = auto_pay_rewards;
+        msg!("Auto-pay of rewards updated: {}", auto_pay_rewards);
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetAutoPayRewards,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!("auto_pay={}", auto_pay_rewards),
+        );
+        Ok(())
+    }
+
+    pub fn set_finalization_bounty(
+        ctx: Context<SetOracleConfig>,
+        finalization_bounty_lamports: u64,
+        finalization_bounty_pool_reserve: u64,
+    ) -> Result<()> {
+        if finalization_bounty_lamports > MAX_FINALIZATION_BOUNTY_LAMPORTS {
+            return Err(OracleError::InvalidConfigValue.into());
+        }
+        let state = &mut ctx.accounts.oracle_contract_state;
+        state.finalization_bounty_lamports = finalization_bounty_lamports;
+        state.finalization_bounty_pool_reserve = finalization_bounty_pool_reserve;
+        msg!(
+            "Finalization bounty updated: {} lamports, Reward pool reserve: {} lamports",
+            finalization_bounty_lamports,
+            finalization_bounty_pool_reserve
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetFinalizationBounty,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!(
+                "bounty={},reserve={}",
+                finalization_bounty_lamports, finalization_bounty_pool_reserve
+            ),
+        );
+        Ok(())
+    }
+
+    pub fn set_reliability_decay(
+        ctx: Context<SetOracleConfig>,
+        reliability_decay_enabled: bool,
+    ) -> Result<()> {
+        ctx.accounts.oracle_contract_state.reliability_decay_enabled = reliability_decay_enabled;
+        msg!(
+            "Reliability inactivity decay updated: {}",
+            reliability_decay_enabled
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetReliabilityDecay,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!("enabled={}", reliability_decay_enabled),
+        );
+        Ok(())
+    }
+
+    pub fn set_counted_reports_cap(
+        ctx: Context<SetOracleConfig>,
+        max_counted_reports_per_window: u32,
+        counted_reports_window: u32,
+    ) -> Result<()> {
+        if max_counted_reports_per_window != 0 && counted_reports_window == 0 {
+            return Err(OracleError::InvalidConfigValue.into());
+        }
+        let state = &mut ctx.accounts.oracle_contract_state;
+        state.max_counted_reports_per_window = max_counted_reports_per_window;
+        state.counted_reports_window = counted_reports_window;
+        msg!(
+            "Counted reports cap updated: {} reports per {} seconds",
+            max_counted_reports_per_window,
+            counted_reports_window
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetCountedReportsCap,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!(
+                "max={},window={}",
+                max_counted_reports_per_window, counted_reports_window
+            ),
+        );
+        Ok(())
+    }
+
+    pub fn set_max_aggregation_age(
+        ctx: Context<SetOracleConfig>,
+        max_aggregation_age: u32,
+    ) -> Result<()> {
+        ctx.accounts.oracle_contract_state.max_aggregation_age = max_aggregation_age;
+        msg!("Maximum aggregation age updated: {}", max_aggregation_age);
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetMaxAggregationAge,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!("max_age={}", max_aggregation_age),
+        );
+        Ok(())
+    }
+
+    pub fn set_ticket_type_quorum(
+        ctx: Context<SetOracleConfig>,
+        pastel_ticket_type: PastelTicketType,
+        quorum: u32,
+    ) -> Result<()> {
+        let state = &mut ctx.accounts.oracle_contract_state;
+        if state.max_submissions_per_txid != 0 && quorum > state.max_submissions_per_txid {
+            return Err(OracleError::InvalidConfigValue.into());
+        }
+        state.ticket_type_quorums[pastel_ticket_type as usize] = quorum;
+        msg!(
+            "Quorum for ticket type {:?} updated: {}",
+            pastel_ticket_type,
+            quorum
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetTicketTypeQuorum,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!("{:?}={}", pastel_ticket_type, quorum),
+        );
+        Ok(())
+    }
+
+    pub fn set_max_active_reports(
+        ctx: Context<SetOracleConfig>,
+        max_active_reports_per_contributor: u32,
+    ) -> Result<()> {
+        ctx.accounts
+            .oracle_contract_state
+            .max_active_reports_per_contributor = max_active_reports_per_contributor;
+        msg!(
+            "Max active reports per contributor updated: {}",
+            max_active_reports_per_contributor
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetMaxActiveReports,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!("max_active_reports={}", max_active_reports_per_contributor),
+        );
+        Ok(())
+    }
+
+    pub fn set_initial_compliance_score(
+        ctx: Context<SetOracleConfig>,
+        initial_compliance_score: u64,
+    ) -> Result<()> {
+        let state = &mut ctx.accounts.oracle_contract_state;
+        validate_initial_compliance_score(
+            initial_compliance_score,
+            state.min_compliance_score_for_reward,
+        )?;
+        state.initial_compliance_score = initial_compliance_score;
+        msg!(
+            "Initial compliance score updated: {}",
+            initial_compliance_score
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetInitialComplianceScore,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!("score={}", initial_compliance_score),
+        );
+        Ok(())
+    }
+
+    pub fn set_submission_fee(
+        ctx: Context<SetOracleConfig>,
+        submission_fee_lamports: u64,
+    ) -> Result<()> {
+        if submission_fee_lamports > MAX_SUBMISSION_FEE_LAMPORTS {
+            return Err(OracleError::InvalidConfigValue.into());
+        }
+        ctx.accounts.oracle_contract_state.submission_fee_lamports = submission_fee_lamports;
+        msg!("Submission fee updated: {}", submission_fee_lamports);
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetSubmissionFee,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!("fee={}", submission_fee_lamports),
+        );
+        Ok(())
+    }
+
+    pub fn set_recency_decay_config(
+        ctx: Context<SetOracleConfig>,
+        recency_weight_floor: u64,
+        recency_ramp_period: u32,
+    ) -> Result<()> {
+        if recency_weight_floor == 0 || recency_weight_floor > ONE || recency_ramp_period == 0 {
+            return Err(OracleError::InvalidConfigValue.into());
+        }
+        let state = &mut ctx.accounts.oracle_contract_state;
+        state.recency_weight_floor = recency_weight_floor;
+        state.recency_ramp_period = recency_ramp_period;
+        msg!(
+            "Recency decay config updated: Weight Floor: {}, Ramp Period: {}",
+            recency_weight_floor,
+            recency_ramp_period
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetRecencyDecayConfig,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!(
+                "floor={},ramp_period={}",
+                recency_weight_floor, recency_ramp_period
+            ),
+        );
+        Ok(())
+    }
+
+    pub fn set_deferred_consensus(
+        ctx: Context<SetOracleConfig>,
+        deferred_consensus: bool,
+    ) -> Result<()> {
+        ctx.accounts.oracle_contract_state.deferred_consensus = deferred_consensus;
+        msg!("Deferred consensus updated: {}", deferred_consensus);
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetDeferredConsensus,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!("deferred={}", deferred_consensus),
+        );
+        Ok(())
+    }
+
+    pub fn set_audit_selection_rate(
+        ctx: Context<SetOracleConfig>,
+        audit_selection_rate: u32,
+    ) -> Result<()> {
+        ctx.accounts.oracle_contract_state.audit_selection_rate = audit_selection_rate;
+        msg!("Audit selection rate updated: {}", audit_selection_rate);
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetAuditSelectionRate,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!("rate={}", audit_selection_rate),
+        );
+        Ok(())
+    }
+
+    pub fn set_min_consensus_weight_sum(
+        ctx: Context<SetOracleConfig>,
+        min_consensus_weight_sum: u64,
+    ) -> Result<()> {
+        ctx.accounts.oracle_contract_state.min_consensus_weight_sum = min_consensus_weight_sum;
+        msg!(
+            "Minimum consensus weight sum updated: {}",
+            min_consensus_weight_sum
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetMinConsensusWeightSum,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!("weight_sum={}", min_consensus_weight_sum),
+        );
+        Ok(())
+    }
+
+    pub fn set_participation_reward(
+        ctx: Context<SetOracleConfig>,
+        participation_reward_lamports: u64,
+    ) -> Result<()> {
+        if participation_reward_lamports > BASE_REWARD_AMOUNT_IN_LAMPORTS {
+            return Err(OracleError::InvalidConfigValue.into());
+        }
+        ctx.accounts
+            .oracle_contract_state
+            .participation_reward_lamports = participation_reward_lamports;
+        msg!(
+            "Participation reward updated: {} lamports",
+            participation_reward_lamports
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetParticipationReward,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!("lamports={}", participation_reward_lamports),
+        );
+        Ok(())
+    }
+
+    pub fn set_tie_break_strategy(
+        ctx: Context<SetOracleConfig>,
+        tie_break_strategy: TieBreakStrategy,
+    ) -> Result<()> {
+        ctx.accounts.oracle_contract_state.tie_break_strategy = tie_break_strategy;
+        msg!(
+            "Consensus tie-break strategy updated: {:?}",
+            tie_break_strategy
+        );
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::SetTieBreakStrategy,
+            ctx.accounts.admin_pubkey.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!("{:?}", tie_break_strategy),
+        );
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(txid: String)] // Include txid as part of the instruction
+pub struct ProcessPayment<'info> {
+    /// CHECK: The payer the pending payment was recorded for; only used to derive its PDA, so it does not sign
+    pub source_account: UncheckedAccount<'info>,
+
+    /// CHECK: Checked in the handler function against the authorized bridge contracts
+    pub caller: Signer<'info>,
+
+    #[account(mut, constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized)]
+    pub oracle_contract_state: Account<'info, OracleContractState>,
+
+    #[account(
+        mut,
+        seeds = [create_seed("pending_payment", &txid, &source_account.key()).as_ref()],
+        bump // You won't explicitly include the bump here; it's handled by Anchor
+    )]
+    pub pending_payment_account: Account<'info, PendingPaymentAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// A zero amount is rejected outright, so a pending payment created with a zero expected amount can never
+// be marked received by a no-op payment
+pub fn validate_payment_amount(pending_payment: &PendingPayment, amount: u64) -> Result<()> {
+    if amount == 0 {
+        return Err(OracleError::ZeroAmount.into());
+    }
+    // Verify the payment amount matches the expected amount
+    if pending_payment.expected_amount != amount {
+        return Err(OracleError::InvalidPaymentAmount.into());
+    }
+    Ok(())
+}
+
+pub fn process_payment_helper(
+    ctx: Context<ProcessPayment>,
+    txid: String,
+    amount: u64,
+) -> Result<()> {
+    validate_txid(&txid)?;
+
+    if !ctx
+        .accounts
+        .oracle_contract_state
+        .is_authorized_bridge(ctx.accounts.caller.key)
+    {
+        return Err(OracleError::NotBridgeContractAddress.into());
+    }
+
+    // Access the pending payment account using the txid as a seed
+    let pending_payment_account = &mut ctx.accounts.pending_payment_account;
+
+    // Ensure the payment corresponds to the provided txid
+    if pending_payment_account.pending_payment.txid != txid {
+        return Err(OracleError::PaymentNotFound.into());
+    }
+
+    validate_payment_amount(&pending_payment_account.pending_payment, amount)?;
+
+    // Mark the payment as received
+    pending_payment_account.pending_payment.payment_status = PaymentStatus::Received;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawFunds<'info> {
+    #[account(
+        mut,
+        constraint = oracle_contract_state.admin_pubkey == *admin_account.key @ OracleError::UnauthorizedWithdrawalAccount,
+        constraint = oracle_contract_state.is_initialized @ OracleError::NotInitialized,
+    )]
+    pub oracle_contract_state: Account<'info, OracleContractState>,
+
+    /// CHECK: The admin_account is manually verified in the instruction to ensure it's the correct and authorized account for withdrawal operations. This includes checking if the account matches the admin_pubkey stored in oracle_contract_state.
+    #[account(mut)]
+    pub admin_account: Signer<'info>,
+
+    /// CHECK: Canonical reward pool PDA holding lamports only, so it must stay owned by the system program
+    #[account(mut, seeds = [b"reward_pool"], bump, owner = system_program::ID @ OracleError::InvalidPoolAccountOwner)]
+    pub reward_pool_account: UncheckedAccount<'info>,
+    /// CHECK: Canonical fee PDA holding lamports only, so it must stay owned by the system program
+    #[account(mut, seeds = [b"fee_receiving_contract"], bump, owner = system_program::ID @ OracleError::InvalidPoolAccountOwner)]
+    pub fee_receiving_contract_account: UncheckedAccount<'info>,
+
+    #[account(mut, seeds = [b"admin_audit_log"], bump)]
+    pub admin_audit_log: Account<'info, AdminAuditLogAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// The seeds already pin both pools to distinct PDAs; this keeps a single account from ever being debited
+// as both, should those constraints be loosened
+pub fn validate_distinct_pool_accounts(
+    reward_pool_account: &Pubkey,
+    fee_receiving_contract_account: &Pubkey,
+) -> Result<()> {
+    require_keys_neq!(
+        *reward_pool_account,
+        *fee_receiving_contract_account,
+        OracleError::DuplicatePoolAccounts
+    );
+    Ok(())
+}
+
+// Either pool may be left untouched, but a withdrawal has to move something
+pub fn validate_withdrawal_amounts(
+    reward_pool_amount: u64,
+    fee_receiving_amount: u64,
+) -> Result<()> {
+    if reward_pool_amount == 0 && fee_receiving_amount == 0 {
+        return Err(OracleError::ZeroAmount.into());
+    }
+    Ok(())
+}
+
+impl<'info> WithdrawFunds<'info> {
+    pub fn execute(
+        ctx: Context<WithdrawFunds>,
+        reward_pool_amount: u64,
+        fee_receiving_amount: u64,
+    ) -> Result<()> {
+        // The account constraint has already checked that admin_account is the stored admin
+        validate_withdrawal_amounts(reward_pool_amount, fee_receiving_amount)?;
+        validate_distinct_pool_accounts(
+            ctx.accounts.reward_pool_account.key,
+            ctx.accounts.fee_receiving_contract_account.key,
+        )?;
+        let reward_pool_account = &mut ctx.accounts.reward_pool_account;
+        let fee_receiving_contract_account = &mut ctx.accounts.fee_receiving_contract_account;
+        let rent = Rent::get()?;
+
+        // Transfer SOL from the reward pool account to the admin account
+        if reward_pool_amount > 0 {
+            require_rent_exempt_after_transfer(
+                &reward_pool_account.to_account_info(),
+                reward_pool_amount,
+                &rent,
+            )?;
+            transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.reward_pool_account.to_account_info(),
+                        to: ctx.accounts.admin_account.to_account_info(),
+                    },
+                )
+                .with_signer(&[&[b"reward_pool", &[ctx.bumps.reward_pool_account]]]),
+                reward_pool_amount,
+            )?;
+        }
+
+        // Transfer SOL from the fee receiving contract account to the admin account
+        if fee_receiving_amount > 0 {
+            require_rent_exempt_after_transfer(
+                &fee_receiving_contract_account.to_account_info(),
+                fee_receiving_amount,
+                &rent,
+            )?;
+            transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: fee_receiving_contract_account.to_account_info(),
+                        to: ctx.accounts.admin_account.to_account_info(),
+                    },
+                )
+                .with_signer(&[&[
+                    b"fee_receiving_contract",
+                    &[ctx.bumps.fee_receiving_contract_account],
+                ]]),
+                fee_receiving_amount,
+            )?;
+        }
+
+        msg!("Withdrawal successful: {} lamports transferred from reward pool and {} lamports from fee receiving contract to admin account", reward_pool_amount, fee_receiving_amount);
+
+        ctx.accounts.admin_audit_log.record(
+            AdminActionCode::WithdrawFunds,
+            ctx.accounts.admin_account.key(),
+            Clock::get()?.unix_timestamp as u32,
+            &format!(
+                "reward_pool={},fee_receiving={}",
+                reward_pool_amount, fee_receiving_amount
+            ),
+        );
+        Ok(())
+    }
+}
+
+declare_id!("AfP1c4sFcY1FeiGjQEtyxCim8BRnw22okNbKAsH2sBsB");
+
+#[program]
+pub mod solana_pastel_oracle_program {
+    use super::*;
+
+    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
+        msg!("Initializing Oracle Contract State");
+        ctx.accounts.initialize_oracle_state()?;
+        // msg!("Oracle Contract State Initialized with Admin Pubkey: {:?}", admin_pubkey);
+
+        // Logging for Reward Pool and Fee Receiving Contract Accounts
+        // msg!("Reward Pool Account: {:?}", ctx.accounts.reward_pool_account.key());
+        // msg!("Fee Receiving Contract Account: {:?}", ctx.accounts.fee_receiving_contract_account.key());
+        msg!(
+            "Temp Report Account: {:?}",
+            ctx.accounts.temp_report_account.key()
+        );
+        msg!(
+            "Contributor Data Account: {:?}",
+            ctx.accounts.contributor_data_account.key()
+        );
+        msg!(
+            "Txid Submission Counts Account: {:?}",
+            ctx.accounts.txid_submission_counts_account.key()
+        );
+        msg!(
+            "Aggregated Consensus Data Account: {:?}",
+            ctx.accounts.aggregated_consensus_data_account.key()
+        );
+        msg!(
+            "Consensus Summary Account: {:?}",
+            ctx.accounts.consensus_summary_account.key()
+        );
+
+        Ok(())
+    }
+
+    pub fn reallocate_oracle_state(ctx: Context<ReallocateOracleState>) -> Result<()> {
+        ReallocateOracleState::execute(ctx)
+    }
+
+    pub fn batch_register_contributors(
+        ctx: Context<BatchRegisterContributors>,
+        reward_addresses: Vec<Pubkey>,
+    ) -> Result<()> {
+        batch_register_contributors_helper(ctx, reward_addresses)
+    }
+
+    pub fn register_new_data_contributor(ctx: Context<RegisterNewDataContributor>) -> Result<()> {
+        register_new_data_contributor_helper(ctx)
+    }
+
+    pub fn heartbeat(ctx: Context<Heartbeat>) -> Result<()> {
+        heartbeat_helper(ctx)
+    }
+
+    pub fn change_reward_address(
+        ctx: Context<ChangeRewardAddress>,
+        new_reward_address: Pubkey,
+    ) -> Result<()> {
+        change_reward_address_helper(ctx, new_reward_address)
     }
 
     pub fn add_txid_for_monitoring(
         ctx: Context<AddTxidForMonitoring>,
         data: AddTxidForMonitoringData,
     ) -> Result<()> {
-        add_txid_for_monitoring_helper(ctx, data)
+        add_txid_for_monitoring_helper(ctx, data)
+    }
+
+    pub fn remove_txid_from_monitoring(
+        ctx: Context<RemoveTxidFromMonitoring>,
+        txid: String,
+    ) -> Result<()> {
+        remove_txid_from_monitoring_helper(ctx, normalize_txid(&txid))
+    }
+
+    pub fn add_pending_payment(
+        ctx: Context<HandlePendingPayment>,
+        txid: String,
+        expected_amount: u64,
+        payment_status: PaymentStatus,
+    ) -> Result<()> {
+        let txid = normalize_txid(&txid);
+        let pending_payment = PendingPayment {
+            txid: txid.clone(),
+            expected_amount,
+            payment_status,
+            payer: ctx.accounts.user.key(),
+            created_at: Clock::get()?.unix_timestamp as u32,
+        };
+
+        add_pending_payment_helper(ctx, txid, pending_payment)
+    }
+
+    pub fn process_payment(ctx: Context<ProcessPayment>, txid: String, amount: u64) -> Result<()> {
+        process_payment_helper(ctx, normalize_txid(&txid), amount)
+    }
+
+    pub fn expire_pending_payment(ctx: Context<ExpirePendingPayment>) -> Result<()> {
+        expire_pending_payment_helper(ctx)
+    }
+
+    pub fn submit_data_report<'info>(
+        ctx: Context<'_, '_, '_, 'info, SubmitDataReport<'info>>,
+        txid: String,
+        txid_status: TxidStatus,
+        pastel_ticket_type: PastelTicketType,
+        first_6_characters_hash: String,
+        contributor_reward_address: Pubkey,
+    ) -> Result<()> {
+        msg!("In `submit_data_report` function -- Params: txid={}, txid_status={:?}, pastel_ticket_type={:?}, first_6_chars_hash={}, contributor_addr={}",
+            txid, txid_status, pastel_ticket_type, first_6_characters_hash, contributor_reward_address);
+
+        // An empty hash means none was given, which is allowed for ticket types without a file
+        let report = PackedTxStatusReport {
+            txid,
+            txid_status,
+            pastel_ticket_type,
+            first_6_characters_hash,
+        }
+        .into_report(
+            contributor_reward_address,
+            Clock::get()?.unix_timestamp as u32,
+        );
+
+        submit_data_report_helper(ctx, report)
+    }
+
+    // Takes the report as a single compact Borsh-encoded argument instead of one argument per field
+    pub fn submit_packed_data_report<'info>(
+        ctx: Context<'_, '_, '_, 'info, SubmitPackedDataReport<'info>>,
+        report: PackedTxStatusReport,
+    ) -> Result<()> {
+        // The timestamp is assigned on-chain, exactly as for submit_data_report
+        let report =
+            report.into_report(ctx.accounts.user.key(), Clock::get()?.unix_timestamp as u32);
+
+        submit_packed_data_report_helper(ctx, report)
+    }
+
+    pub fn update_data_report(
+        ctx: Context<UpdateDataReport>,
+        txid: String,
+        txid_status: TxidStatus,
+        pastel_ticket_type: PastelTicketType,
+        first_6_characters_hash: String,
+    ) -> Result<()> {
+        let report = PastelTxStatusReport {
+            txid: normalize_txid(&txid),
+            txid_status,
+            pastel_ticket_type: Some(pastel_ticket_type),
+            // An empty hash means none was given, which is allowed for ticket types without a file
+            first_6_characters_of_sha3_256_hash_of_corresponding_file: (!first_6_characters_hash
+                .is_empty())
+            .then_some(first_6_characters_hash),
+            timestamp: Clock::get()?.unix_timestamp as u32,
+            contributor_reward_address: ctx.accounts.user.key(),
+        };
+
+        update_data_report_helper(ctx, report)
+    }
+
+    pub fn request_reward(ctx: Context<RequestReward>, contributor_address: Pubkey) -> Result<()> {
+        request_reward_helper(ctx, contributor_address)
+    }
+
+    pub fn get_contributor(ctx: Context<GetContributor>, reward_address: Pubkey) -> Result<()> {
+        get_contributor_helper(ctx, reward_address)
+    }
+
+    pub fn get_contributor_timing(
+        ctx: Context<GetContributorTiming>,
+        reward_address: Pubkey,
+    ) -> Result<()> {
+        get_contributor_timing_helper(ctx, reward_address)
+    }
+
+    pub fn simulate_score_update(
+        ctx: Context<SimulateScoreUpdate>,
+        reward_address: Pubkey,
+        would_be_accurate: bool,
+    ) -> Result<()> {
+        simulate_score_update_helper(ctx, reward_address, would_be_accurate)
+    }
+
+    pub fn get_consensus_config(ctx: Context<GetConsensusConfig>) -> Result<()> {
+        get_consensus_config_helper(ctx)
+    }
+
+    pub fn get_program_info(ctx: Context<GetProgramInfo>) -> Result<()> {
+        get_program_info_helper(ctx)
+    }
+
+    pub fn require_schema_version(
+        ctx: Context<GetProgramInfo>,
+        required_schema_version: u16,
+    ) -> Result<()> {
+        require_schema_version_helper(ctx, required_schema_version)
+    }
+
+    pub fn get_aggregated_data(ctx: Context<GetAggregatedData>, txid: String) -> Result<()> {
+        get_aggregated_data_helper(ctx, txid)
+    }
+
+    pub fn get_consensus_summary(ctx: Context<GetConsensusSummary>, txid: String) -> Result<()> {
+        get_consensus_summary_helper(ctx, txid)
+    }
+
+    pub fn derive_report_pda(
+        ctx: Context<DeriveReportPda>,
+        txid: String,
+        contributor_reward_address: Pubkey,
+    ) -> Result<()> {
+        derive_report_pda_helper(ctx, txid, contributor_reward_address)
+    }
+
+    pub fn get_active_aggregations(
+        ctx: Context<GetActiveAggregations>,
+        start_index: u32,
+        limit: u32,
+    ) -> Result<()> {
+        get_active_aggregations_helper(ctx, start_index, limit)
+    }
+
+    pub fn preview_consensus_readiness(
+        ctx: Context<PreviewConsensusReadiness>,
+        txid: String,
+    ) -> Result<()> {
+        preview_consensus_readiness_helper(ctx, txid)
+    }
+
+    pub fn snapshot_contributors(
+        ctx: Context<SnapshotContributors>,
+        start_index: u32,
+        limit: u32,
+    ) -> Result<()> {
+        snapshot_contributors_helper(ctx, start_index, limit)
+    }
+
+    pub fn fund_reward_pool(ctx: Context<FundRewardPool>, amount: u64) -> Result<()> {
+        fund_reward_pool_helper(ctx, amount)
+    }
+
+    pub fn get_reward_runway(ctx: Context<GetRewardRunway>) -> Result<()> {
+        get_reward_runway_helper(ctx)
+    }
+
+    pub fn get_economics(ctx: Context<GetEconomics>) -> Result<()> {
+        get_economics_helper(ctx)
+    }
+
+    pub fn add_bridge_contract(
+        ctx: Context<ManageBridgeContracts>,
+        bridge_contract_pubkey: Pubkey,
+    ) -> Result<()> {
+        ManageBridgeContracts::add_bridge_contract(ctx, bridge_contract_pubkey)
+    }
+
+    pub fn remove_bridge_contract(
+        ctx: Context<ManageBridgeContracts>,
+        bridge_contract_pubkey: Pubkey,
+    ) -> Result<()> {
+        ManageBridgeContracts::remove_bridge_contract(ctx, bridge_contract_pubkey)
+    }
+
+    pub fn add_to_blacklist(ctx: Context<ManageBlacklist>, reward_address: Pubkey) -> Result<()> {
+        ManageBlacklist::add_to_blacklist(ctx, reward_address)
+    }
+
+    pub fn remove_from_blacklist(
+        ctx: Context<ManageBlacklist>,
+        reward_address: Pubkey,
+    ) -> Result<()> {
+        ManageBlacklist::remove_from_blacklist(ctx, reward_address)
+    }
+
+    pub fn transfer_admin(ctx: Context<TransferAdmin>, new_admin_pubkey: Pubkey) -> Result<()> {
+        TransferAdmin::transfer_admin(ctx, new_admin_pubkey)
+    }
+
+    pub fn get_admin_audit_log(
+        ctx: Context<GetAdminAuditLog>,
+        start: u32,
+        limit: u32,
+    ) -> Result<()> {
+        get_admin_audit_log_helper(ctx, start, limit)
+    }
+
+    pub fn set_accuracy_policy(
+        ctx: Context<SetOracleConfig>,
+        pastel_ticket_type: PastelTicketType,
+        accuracy_policy: AccuracyPolicy,
+    ) -> Result<()> {
+        SetOracleConfig::set_accuracy_policy(ctx, pastel_ticket_type, accuracy_policy)
+    }
+
+    pub fn set_file_hash_requirement(
+        ctx: Context<SetOracleConfig>,
+        pastel_ticket_type: PastelTicketType,
+        file_hash_required: bool,
+    ) -> Result<()> {
+        SetOracleConfig::set_file_hash_requirement(ctx, pastel_ticket_type, file_hash_required)
+    }
+
+    pub fn set_max_monitored_txids(
+        ctx: Context<SetOracleConfig>,
+        max_monitored_txids: u32,
+    ) -> Result<()> {
+        SetOracleConfig::set_max_monitored_txids(ctx, max_monitored_txids)
+    }
+
+    pub fn set_quorum_config(
+        ctx: Context<SetOracleConfig>,
+        min_quorum: u32,
+        quorum_auto_scaling_enabled: bool,
+        quorum_fraction: u64,
+    ) -> Result<()> {
+        SetOracleConfig::set_quorum_config(
+            ctx,
+            min_quorum,
+            quorum_auto_scaling_enabled,
+            quorum_fraction,
+        )
+    }
+
+    pub fn set_reward_thresholds(
+        ctx: Context<SetOracleConfig>,
+        min_reports_for_reward: u32,
+        min_reliability_score_for_reward: u64,
+        min_compliance_score_for_reward: u64,
+    ) -> Result<()> {
+        SetOracleConfig::set_reward_thresholds(
+            ctx,
+            min_reports_for_reward,
+            min_reliability_score_for_reward,
+            min_compliance_score_for_reward,
+        )
+    }
+
+    pub fn set_early_bird_config(
+        ctx: Context<SetOracleConfig>,
+        early_bird_multiplier: u64,
+        early_bird_decay_period: u32,
+    ) -> Result<()> {
+        SetOracleConfig::set_early_bird_config(ctx, early_bird_multiplier, early_bird_decay_period)
+    }
+
+    pub fn set_hash_mismatch_ban_threshold(
+        ctx: Context<SetOracleConfig>,
+        hash_mismatch_ban_threshold: u32,
+    ) -> Result<()> {
+        SetOracleConfig::set_hash_mismatch_ban_threshold(ctx, hash_mismatch_ban_threshold)
+    }
+
+    pub fn set_near_miss_hash_prefix_length(
+        ctx: Context<SetOracleConfig>,
+        near_miss_hash_prefix_length: u8,
+    ) -> Result<()> {
+        SetOracleConfig::set_near_miss_hash_prefix_length(ctx, near_miss_hash_prefix_length)
+    }
+
+    pub fn set_consensus_cool_down(
+        ctx: Context<SetOracleConfig>,
+        consensus_cool_down: u32,
+    ) -> Result<()> {
+        SetOracleConfig::set_consensus_cool_down(ctx, consensus_cool_down)
+    }
+
+    pub fn set_weight_coefficients(
+        ctx: Context<SetOracleConfig>,
+        compliance_weight_coefficient: u64,
+        reliability_weight_coefficient: u64,
+        stake_weight_coefficient: u64,
+    ) -> Result<()> {
+        SetOracleConfig::set_weight_coefficients(
+            ctx,
+            compliance_weight_coefficient,
+            reliability_weight_coefficient,
+            stake_weight_coefficient,
+        )
+    }
+
+    pub fn set_report_weight_bounds(
+        ctx: Context<SetOracleConfig>,
+        min_report_weight: u64,
+        max_report_weight: u64,
+    ) -> Result<()> {
+        SetOracleConfig::set_report_weight_bounds(ctx, min_report_weight, max_report_weight)
+    }
+
+    pub fn set_tally_weight_multipliers(
+        ctx: Context<SetOracleConfig>,
+        status_weight_multiplier: u64,
+        hash_weight_multiplier: u64,
+    ) -> Result<()> {
+        SetOracleConfig::set_tally_weight_multipliers(
+            ctx,
+            status_weight_multiplier,
+            hash_weight_multiplier,
+        )
+    }
+
+    pub fn set_logistic_scaling(
+        ctx: Context<SetOracleConfig>,
+        logistic_steepness: u64,
+        logistic_midpoint: u64,
+    ) -> Result<()> {
+        SetOracleConfig::set_logistic_scaling(ctx, logistic_steepness, logistic_midpoint)
+    }
+
+    pub fn set_retention_periods(
+        ctx: Context<SetOracleConfig>,
+        temp_report_retention_period: u32,
+        aggregated_data_retention_period: u32,
+        submission_count_retention_period: u32,
+    ) -> Result<()> {
+        SetOracleConfig::set_retention_periods(
+            ctx,
+            temp_report_retention_period,
+            aggregated_data_retention_period,
+            submission_count_retention_period,
+        )
+    }
+
+    pub fn set_reliability_bonus_factor(
+        ctx: Context<SetOracleConfig>,
+        reliability_bonus_factor: u64,
+    ) -> Result<()> {
+        SetOracleConfig::set_reliability_bonus_factor(ctx, reliability_bonus_factor)
+    }
+
+    pub fn set_reward_mode(
+        ctx: Context<SetOracleConfig>,
+        reward_mode: RewardMode,
+        report_count_reward_cap: u64,
+    ) -> Result<()> {
+        SetOracleConfig::set_reward_mode(ctx, reward_mode, report_count_reward_cap)
+    }
+
+    pub fn set_tie_break_strategy(
+        ctx: Context<SetOracleConfig>,
+        tie_break_strategy: TieBreakStrategy,
+    ) -> Result<()> {
+        SetOracleConfig::set_tie_break_strategy(ctx, tie_break_strategy)
+    }
+
+    pub fn set_participation_reward(
+        ctx: Context<SetOracleConfig>,
+        participation_reward_lamports: u64,
+    ) -> Result<()> {
+        SetOracleConfig::set_participation_reward(ctx, participation_reward_lamports)
+    }
+
+    pub fn set_min_consensus_weight_sum(
+        ctx: Context<SetOracleConfig>,
+        min_consensus_weight_sum: u64,
+    ) -> Result<()> {
+        SetOracleConfig::set_min_consensus_weight_sum(ctx, min_consensus_weight_sum)
+    }
+
+    pub fn set_audit_selection_rate(
+        ctx: Context<SetOracleConfig>,
+        audit_selection_rate: u32,
+    ) -> Result<()> {
+        SetOracleConfig::set_audit_selection_rate(ctx, audit_selection_rate)
+    }
+
+    pub fn set_deferred_consensus(
+        ctx: Context<SetOracleConfig>,
+        deferred_consensus: bool,
+    ) -> Result<()> {
+        SetOracleConfig::set_deferred_consensus(ctx, deferred_consensus)
+    }
+
+    pub fn set_max_submissions_per_txid(
+        ctx: Context<SetOracleConfig>,
+        max_submissions_per_txid: u32,
+    ) -> Result<()> {
+        SetOracleConfig::set_max_submissions_per_txid(ctx, max_submissions_per_txid)
+    }
+
+    pub fn set_fee_split(ctx: Context<SetOracleConfig>, fee_split_bps: u16) -> Result<()> {
+        SetOracleConfig::set_fee_split(ctx, fee_split_bps)
+    }
+
+    pub fn set_contributor_idle_period(
+        ctx: Context<SetOracleConfig>,
+        contributor_idle_period: u32,
+    ) -> Result<()> {
+        SetOracleConfig::set_contributor_idle_period(ctx, contributor_idle_period)
+    }
+
+    pub fn set_contributor_activation_delay(
+        ctx: Context<SetOracleConfig>,
+        contributor_activation_delay: u32,
+    ) -> Result<()> {
+        SetOracleConfig::set_contributor_activation_delay(ctx, contributor_activation_delay)
+    }
+
+    pub fn set_under_reported_reward_boost(
+        ctx: Context<SetOracleConfig>,
+        volume_threshold: u32,
+        reward_boost: u64,
+    ) -> Result<()> {
+        SetOracleConfig::set_under_reported_reward_boost(ctx, volume_threshold, reward_boost)
+    }
+
+    pub fn set_probation_config(
+        ctx: Context<SetOracleConfig>,
+        probation_period: u32,
+        probation_weight_factor: u64,
+    ) -> Result<()> {
+        SetOracleConfig::set_probation_config(ctx, probation_period, probation_weight_factor)
+    }
+
+    pub fn set_payment_expiry_period(
+        ctx: Context<SetOracleConfig>,
+        payment_expiry_period: u32,
+    ) -> Result<()> {
+        SetOracleConfig::set_payment_expiry_period(ctx, payment_expiry_period)
+    }
+
+    pub fn set_submission_quiet_period(
+        ctx: Context<SetOracleConfig>,
+        submission_quiet_period: u32,
+    ) -> Result<()> {
+        SetOracleConfig::set_submission_quiet_period(ctx, submission_quiet_period)
+    }
+
+    pub fn set_streak_decay_window(
+        ctx: Context<SetOracleConfig>,
+        streak_decay_window: u32,
+    ) -> Result<()> {
+        SetOracleConfig::set_streak_decay_window(ctx, streak_decay_window)
+    }
+
+    pub fn set_split_consensus_margin(
+        ctx: Context<SetOracleConfig>,
+        split_consensus_margin: u64,
+    ) -> Result<()> {
+        SetOracleConfig::set_split_consensus_margin(ctx, split_consensus_margin)
+    }
+
+    pub fn set_finalization_bounty(
+        ctx: Context<SetOracleConfig>,
+        finalization_bounty_lamports: u64,
+        finalization_bounty_pool_reserve: u64,
+    ) -> Result<()> {
+        SetOracleConfig::set_finalization_bounty(
+            ctx,
+            finalization_bounty_lamports,
+            finalization_bounty_pool_reserve,
+        )
+    }
+
+    pub fn set_reliability_decay(
+        ctx: Context<SetOracleConfig>,
+        reliability_decay_enabled: bool,
+    ) -> Result<()> {
+        SetOracleConfig::set_reliability_decay(ctx, reliability_decay_enabled)
+    }
+
+    pub fn set_counted_reports_cap(
+        ctx: Context<SetOracleConfig>,
+        max_counted_reports_per_window: u32,
+        counted_reports_window: u32,
+    ) -> Result<()> {
+        SetOracleConfig::set_counted_reports_cap(
+            ctx,
+            max_counted_reports_per_window,
+            counted_reports_window,
+        )
+    }
+
+    pub fn set_max_aggregation_age(
+        ctx: Context<SetOracleConfig>,
+        max_aggregation_age: u32,
+    ) -> Result<()> {
+        SetOracleConfig::set_max_aggregation_age(ctx, max_aggregation_age)
+    }
+
+    pub fn migrate_schema(ctx: Context<MigrateSchema>) -> Result<()> {
+        MigrateSchema::execute(ctx)
+    }
+
+    pub fn set_ticket_type_quorum(
+        ctx: Context<SetOracleConfig>,
+        pastel_ticket_type: PastelTicketType,
+        quorum: u32,
+    ) -> Result<()> {
+        SetOracleConfig::set_ticket_type_quorum(ctx, pastel_ticket_type, quorum)
+    }
+
+    pub fn set_max_active_reports(
+        ctx: Context<SetOracleConfig>,
+        max_active_reports_per_contributor: u32,
+    ) -> Result<()> {
+        SetOracleConfig::set_max_active_reports(ctx, max_active_reports_per_contributor)
+    }
+
+    pub fn set_initial_compliance_score(
+        ctx: Context<SetOracleConfig>,
+        initial_compliance_score: u64,
+    ) -> Result<()> {
+        SetOracleConfig::set_initial_compliance_score(ctx, initial_compliance_score)
+    }
+
+    pub fn set_submission_fee(
+        ctx: Context<SetOracleConfig>,
+        submission_fee_lamports: u64,
+    ) -> Result<()> {
+        SetOracleConfig::set_submission_fee(ctx, submission_fee_lamports)
+    }
+
+    pub fn set_recency_decay_config(
+        ctx: Context<SetOracleConfig>,
+        recency_weight_floor: u64,
+        recency_ramp_period: u32,
+    ) -> Result<()> {
+        SetOracleConfig::set_recency_decay_config(ctx, recency_weight_floor, recency_ramp_period)
+    }
+
+    pub fn set_auto_pay_rewards(
+        ctx: Context<SetOracleConfig>,
+        auto_pay_rewards: bool,
+    ) -> Result<()> {
+        SetOracleConfig::set_auto_pay_rewards(ctx, auto_pay_rewards)
+    }
+
+    pub fn set_rewards_paused(ctx: Context<SetOracleConfig>, rewards_paused: bool) -> Result<()> {
+        SetOracleConfig::set_rewards_paused(ctx, rewards_paused)
+    }
+
+    pub fn finalize_consensus<'info>(
+        ctx: Context<'_, '_, '_, 'info, FinalizeConsensus<'info>>,
+        txid: String,
+    ) -> Result<()> {
+        finalize_consensus_helper(ctx, normalize_txid(&txid))
+    }
+
+    pub fn set_auto_replenish_config(
+        ctx: Context<SetOracleConfig>,
+        auto_replenish_threshold: u64,
+        auto_replenish_amount: u64,
+        fee_account_reserve: u64,
+    ) -> Result<()> {
+        SetOracleConfig::set_auto_replenish_config(
+            ctx,
+            auto_replenish_threshold,
+            auto_replenish_amount,
+            fee_account_reserve,
+        )
+    }
+
+    pub fn set_contributor_stake_weight(
+        ctx: Context<SetContributorStakeWeight>,
+        reward_address: Pubkey,
+        stake_weight: u64,
+    ) -> Result<()> {
+        set_contributor_stake_weight_helper(ctx, reward_address, stake_weight)
+    }
+
+    pub fn admin_unban_contributor(
+        ctx: Context<AdminUnbanContributor>,
+        reward_address: Pubkey,
+    ) -> Result<()> {
+        admin_unban_contributor_helper(ctx, reward_address)
+    }
+
+    pub fn repair_submission_counts(ctx: Context<RepairSubmissionCounts>) -> Result<()> {
+        repair_submission_counts_helper(ctx)
+    }
+
+    pub fn sweep_orphaned_temp_reports(ctx: Context<SweepOrphanedTempReports>) -> Result<()> {
+        sweep_orphaned_temp_reports_helper(ctx)
+    }
+
+    pub fn reverse_consensus(ctx: Context<ReverseConsensus>, txid: String) -> Result<()> {
+        reverse_consensus_helper(ctx, normalize_txid(&txid))
+    }
+
+    pub fn recompute_all_eligibility(
+        ctx: Context<RecomputeAllEligibility>,
+        start_index: u32,
+        max_contributors: u32,
+    ) -> Result<()> {
+        recompute_all_eligibility_helper(ctx, start_index, max_contributors)
+    }
+
+    pub fn withdraw_funds(
+        ctx: Context<WithdrawFunds>,
+        reward_pool_amount: u64,
+        fee_receiving_amount: u64,
+    ) -> Result<()> {
+        WithdrawFunds::execute(ctx, reward_pool_amount, fee_receiving_amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::entrypoint::{ProgramResult, SUCCESS};
+    use anchor_lang::solana_program::instruction::Instruction;
+    use anchor_lang::solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+    use std::cell::Cell;
+    use std::sync::Once;
+
+    // Off-chain, Clock::get() and CPIs are served by the syscall stubs. Installing stubs that return a
+    // per-thread clock lets handler logic run at injected times without a validator, and keeps parallel
+    // tests apart; system transfers are applied directly to the lamports of the accounts passed in.
+    thread_local! {
+        static TEST_CLOCK: Cell<(i64, u64)> = const { Cell::new((0, 0)) };
+    }
+
+    struct TestSyscallStubs;
+
+    impl SyscallStubs for TestSyscallStubs {
+        fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+            let (unix_timestamp, slot) = TEST_CLOCK.with(Cell::get);
+            let clock = Clock {
+                slot,
+                unix_timestamp,
+                ..Default::default()
+            };
+            // Clock::get passes a pointer to its own Clock, so the write is aligned and in bounds
+            unsafe { var_addr.cast::<Clock>().write(clock) };
+            SUCCESS
+        }
+
+        fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+            unsafe { var_addr.cast::<Rent>().write(Rent::default()) };
+            SUCCESS
+        }
+
+        fn sol_invoke_signed(
+            &self,
+            instruction: &Instruction,
+            account_infos: &[AccountInfo],
+            _signers_seeds: &[&[&[u8]]],
+        ) -> ProgramResult {
+            // Only system transfers are supported: a u32 instruction index of 2 followed by the amount
+            assert_eq!(instruction.program_id, system_program::ID);
+            assert_eq!(instruction.data[..4], 2u32.to_le_bytes());
+            let amount = u64::from_le_bytes(instruction.data[4..12].try_into().unwrap());
+            let account = |index: usize| {
+                account_infos
+                    .iter()
+                    .find(|info| *info.key == instruction.accounts[index].pubkey)
+                    .unwrap()
+            };
+            **account(0).try_borrow_mut_lamports()? -= amount;
+            **account(1).try_borrow_mut_lamports()? += amount;
+            Ok(())
+        }
+    }
+
+    fn set_test_clock(unix_timestamp: u32, slot: u64) {
+        static INSTALL_STUBS: Once = Once::new();
+        INSTALL_STUBS.call_once(|| {
+            set_syscall_stubs(Box::new(TestSyscallStubs));
+        });
+        TEST_CLOCK.with(|clock| clock.set((unix_timestamp as i64, slot)));
+    }
+
+    // Backing storage for an AccountInfo handed to Anchor's account validation
+    struct TestAccount {
+        key: Pubkey,
+        owner: Pubkey,
+        lamports: u64,
+        data: Vec<u8>,
+        is_signer: bool,
+        is_writable: bool,
+        executable: bool,
+    }
+
+    impl TestAccount {
+        fn new(key: Pubkey, owner: Pubkey, data: Vec<u8>) -> Self {
+            TestAccount {
+                key,
+                owner,
+                lamports: Rent::default().minimum_balance(data.len()),
+                data,
+                is_signer: false,
+                is_writable: true,
+                executable: false,
+            }
+        }
+
+        fn program_owned<T: AccountSerialize>(key: Pubkey, account: &T) -> Self {
+            let mut data = Vec::new();
+            account.try_serialize(&mut data).unwrap();
+            Self::new(key, crate::ID, data)
+        }
+
+        fn info(&mut self) -> AccountInfo<'_> {
+            AccountInfo::new(
+                &self.key,
+                self.is_signer,
+                self.is_writable,
+                &mut self.lamports,
+                &mut self.data,
+                &self.owner,
+                self.executable,
+                0,
+            )
+        }
+    }
+
+    fn test_report(
+        txid: &str,
+        txid_status: TxidStatus,
+        hash: &str,
+        contributor_reward_address: Pubkey,
+    ) -> PastelTxStatusReport {
+        PastelTxStatusReport {
+            txid: txid.to_string(),
+            txid_status,
+            pastel_ticket_type: Some(PastelTicketType::Sense),
+            first_6_characters_of_sha3_256_hash_of_corresponding_file: Some(hash.to_string()),
+            timestamp: 1_000,
+            contributor_reward_address,
+        }
+    }
+
+    fn submit_test_report(
+        temp_report_account: &mut TempTxStatusReportAccount,
+        aggregated_data_account: &mut AggregatedConsensusDataAccount,
+        report: &PastelTxStatusReport,
+        weight: u64,
+    ) {
+        let common_data = CommonReportData {
+            txid: report.txid.clone(),
+            txid_status: report.txid_status,
+            pastel_ticket_type: report.pastel_ticket_type,
+            first_6_characters_of_sha3_256_hash_of_corresponding_file: report
+                .first_6_characters_of_sha3_256_hash_of_corresponding_file
+                .clone(),
+        };
+        let common_data_ref = find_or_add_common_report_data(temp_report_account, &common_data);
+        temp_report_account.reports.push(TempTxStatusReport {
+            common_data_ref,
+            specific_data: SpecificReportData {
+                contributor_reward_address: report.contributor_reward_address,
+                timestamp: report.timestamp,
+                common_data_ref,
+                weight,
+            },
+        });
+        aggregate_consensus_data(
+            &OracleContractState::default(),
+            aggregated_data_account,
+            report,
+            weight,
+            &report.txid,
+            report.timestamp,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_update_data_report_replaces_weights() {
+        let mut temp_report_account = TempTxStatusReportAccount {
+            reports: Vec::new(),
+            common_reports: Vec::new(),
+        };
+        let mut aggregated_data_account = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        let contributor = Pubkey::new_unique();
+        let other_contributor = Pubkey::new_unique();
+
+        let original = test_report("abc123", TxidStatus::PendingMining, "aaaaaa", contributor);
+        submit_test_report(
+            &mut temp_report_account,
+            &mut aggregated_data_account,
+            &original,
+            5,
+        );
+        let other = test_report(
+            "abc123",
+            TxidStatus::PendingMining,
+            "aaaaaa",
+            other_contributor,
+        );
+        submit_test_report(
+            &mut temp_report_account,
+            &mut aggregated_data_account,
+            &other,
+            3,
+        );
+
+        let updated = test_report("abc123", TxidStatus::MinedActivated, "bbbbbb", contributor);
+        replace_temp_report(
+            &OracleContractState::default(),
+            &mut temp_report_account,
+            &mut aggregated_data_account,
+            &updated,
+            7,
+            2_000,
+        )
+        .unwrap();
+
+        assert!(!is_consensus_finalized(&aggregated_data_account, "abc123"));
+        let data = &aggregated_data_account.consensus_data[0];
+        assert_eq!(data.status_weights[TxidStatus::PendingMining as usize], 300);
+        assert_eq!(
+            data.status_weights[TxidStatus::MinedActivated as usize],
+            700
+        );
+        let hash_weight = |hash: &str| {
+            data.hash_weights
+                .iter()
+                .find(|h| h.hash == hash)
+                .map_or(0, |h| h.weight)
+        };
+        assert_eq!(hash_weight("aaaaaa"), 300);
+        assert_eq!(hash_weight("bbbbbb"), 700);
+
+        // The contributor still has exactly one temp report, now pointing at the new data
+        let contributor_reports: Vec<_> = temp_report_account
+            .reports
+            .iter()
+            .filter(|r| r.specific_data.contributor_reward_address == contributor)
+            .collect();
+        assert_eq!(contributor_reports.len(), 1);
+        let common_data =
+            &temp_report_account.common_reports[contributor_reports[0].common_data_ref as usize];
+        assert_eq!(common_data.txid_status, TxidStatus::MinedActivated);
+        assert_eq!(contributor_reports[0].specific_data.weight, 7);
+
+        // Once consensus is applied the aggregation is closed to updates
+        aggregated_data_account.consensus_data[0].last_consensus_slot = 5;
+        assert!(is_consensus_finalized(&aggregated_data_account, "abc123"));
+    }
+
+    #[test]
+    fn test_accuracy_policy_status_match_hash_mismatch() {
+        let common_data = CommonReportData {
+            txid: "abc123".to_string(),
+            txid_status: TxidStatus::MinedActivated,
+            pastel_ticket_type: Some(PastelTicketType::Sense),
+            first_6_characters_of_sha3_256_hash_of_corresponding_file: Some("aaaaaa".to_string()),
+        };
+        let accurate =
+            |policy| is_report_accurate(&common_data, TxidStatus::MinedActivated, "bbbbbb", policy);
+        assert!(!accurate(AccuracyPolicy::StatusAndHash));
+        assert!(accurate(AccuracyPolicy::StatusOnly));
+        assert!(!accurate(AccuracyPolicy::HashOnly));
+    }
+
+    #[test]
+    fn test_accuracy_policy_without_hash() {
+        let common_data = CommonReportData {
+            txid: "abc123".to_string(),
+            txid_status: TxidStatus::MinedActivated,
+            pastel_ticket_type: Some(PastelTicketType::InferenceApi),
+            first_6_characters_of_sha3_256_hash_of_corresponding_file: None,
+        };
+        let accurate =
+            |policy| is_report_accurate(&common_data, TxidStatus::MinedActivated, "bbbbbb", policy);
+        assert!(!accurate(AccuracyPolicy::StatusAndHash));
+        assert!(accurate(AccuracyPolicy::StatusOnly));
+        assert!(!accurate(AccuracyPolicy::HashOnly));
+    }
+
+    #[test]
+    fn test_update_data_report_without_existing_report_fails() {
+        let mut temp_report_account = TempTxStatusReportAccount {
+            reports: Vec::new(),
+            common_reports: Vec::new(),
+        };
+        let mut aggregated_data_account = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        let report = test_report(
+            "abc123",
+            TxidStatus::MinedActivated,
+            "bbbbbb",
+            Pubkey::new_unique(),
+        );
+        let result = replace_temp_report(
+            &OracleContractState::default(),
+            &mut temp_report_account,
+            &mut aggregated_data_account,
+            &report,
+            7,
+            2_000,
+        );
+        assert_eq!(result.unwrap_err(), OracleError::ReportNotFound.into());
+    }
+
+    #[test]
+    fn test_compute_reward_runway() {
+        let reserve = 890_880;
+        let reward_amount = BASE_REWARD_AMOUNT_IN_LAMPORTS;
+        assert_eq!(compute_reward_runway(0, reserve, reward_amount), 0);
+        assert_eq!(compute_reward_runway(reserve, reserve, reward_amount), 0);
+        assert_eq!(
+            compute_reward_runway(reserve + 10 * reward_amount + 1, reserve, reward_amount),
+            10
+        );
+        assert_eq!(compute_reward_runway(u64::MAX, reserve, 0), 0);
+
+        // The runway is counted in the largest claim the config allows, not the base reward
+        let mut state = OracleContractState {
+            reliability_bonus_factor: ONE,
+            report_count_reward_cap: DEFAULT_REPORT_COUNT_REWARD_CAP,
+            ..Default::default()
+        };
+        assert_eq!(
+            max_claim_reward_amount(&state),
+            BASE_REWARD_AMOUNT_IN_LAMPORTS
+        );
+        state.reliability_bonus_factor = 2 * ONE;
+        assert_eq!(
+            max_claim_reward_amount(&state),
+            2 * BASE_REWARD_AMOUNT_IN_LAMPORTS
+        );
+        state.reward_mode = RewardMode::ScaledByReportCount;
+        assert_eq!(
+            max_claim_reward_amount(&state),
+            10 * BASE_REWARD_AMOUNT_IN_LAMPORTS
+        );
+        // A reliable contributor at the report-count cap claims exactly that much
+        let capped_contributor = Contributor {
+            is_reliable: true,
+            total_reports_submitted: 1_000,
+            ..Default::default()
+        };
+        assert_eq!(
+            contributor_reward_amount(&state, &capped_contributor),
+            max_claim_reward_amount(&state)
+        );
+    }
+
+    #[test]
+    fn test_validate_txid() {
+        assert!(validate_txid(&"a".repeat(MAX_TXID_LENGTH)).is_ok());
+        assert!(validate_txid("ABCdef0123").is_ok());
+        for invalid in [
+            String::new(),
+            "a".repeat(MAX_TXID_LENGTH + 1),
+            "not-a-hex-txid".to_string(),
+            " abc123".to_string(),
+        ] {
+            assert_eq!(
+                validate_txid(&invalid).unwrap_err(),
+                OracleError::InvalidTxid.into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_monitored_txids_capped() {
+        let mut state = OracleContractState {
+            max_monitored_txids: 3,
+            ..Default::default()
+        };
+        for txid in ["aa", "bb", "cc"] {
+            add_monitored_txid(&mut state, txid).unwrap();
+        }
+        assert_eq!(
+            add_monitored_txid(&mut state, "dd").unwrap_err(),
+            OracleError::MaxSizeExceeded.into()
+        );
+        assert_eq!(state.monitored_txids.len(), 3);
+
+        // Pruning a TXID frees up room for a new one
+        remove_monitored_txid(&mut state, "bb").unwrap();
+        add_monitored_txid(&mut state, "dd").unwrap();
+        assert_eq!(state.monitored_txids, vec!["aa", "cc", "dd"]);
+        assert_eq!(
+            remove_monitored_txid(&mut state, "bb").unwrap_err(),
+            OracleError::TxidNotMonitored.into()
+        );
+    }
+
+    #[test]
+    fn test_record_reward_payout_accumulates() {
+        let mut contributor = Contributor::default();
+        contributor.record_reward_payout(BASE_REWARD_AMOUNT_IN_LAMPORTS);
+        contributor.record_reward_payout(BASE_REWARD_AMOUNT_IN_LAMPORTS);
+        assert_eq!(
+            contributor.total_rewards_earned_lamports,
+            2 * BASE_REWARD_AMOUNT_IN_LAMPORTS
+        );
+
+        contributor.total_rewards_earned_lamports = u64::MAX - 1;
+        contributor.record_reward_payout(BASE_REWARD_AMOUNT_IN_LAMPORTS);
+        assert_eq!(contributor.total_rewards_earned_lamports, u64::MAX);
+    }
+
+    #[test]
+    fn test_effective_quorum_auto_scaling() {
+        let fixed_state = OracleContractState {
+            min_quorum: MIN_NUMBER_OF_ORACLES,
+            ..Default::default()
+        };
+        assert_eq!(effective_quorum(&fixed_state, 1_000), MIN_NUMBER_OF_ORACLES);
+
+        // A third of registered contributors, but never fewer than 3
+        let scaled_state = OracleContractState {
+            min_quorum: 3,
+            quorum_auto_scaling_enabled: true,
+            quorum_fraction: 333_333_334,
+            ..Default::default()
+        };
+        assert_eq!(effective_quorum(&scaled_state, 5), 3);
+        assert_eq!(effective_quorum(&scaled_state, 10), 4);
+        assert_eq!(effective_quorum(&scaled_state, 1_000), 334);
+    }
+
+    #[test]
+    fn test_should_calculate_consensus_uses_scaled_quorum() {
+        let state = OracleContractState {
+            min_quorum: 3,
+            quorum_auto_scaling_enabled: true,
+            quorum_fraction: FIVE_TENTH,
+            ..Default::default()
+        };
+        let counts = TxidSubmissionCountsAccount {
+            submission_counts: vec![TxidSubmissionCount {
+                txid: "abc123".to_string(),
+                count: 5,
+                last_updated: 1_000,
+                quorum_reached_at: 1_000,
+            }],
+        };
+        let temp_report_account = TempTxStatusReportAccount {
+            reports: Vec::new(),
+            common_reports: Vec::new(),
+        };
+        assert!(should_calculate_consensus(
+            &state,
+            &counts,
+            &temp_report_account,
+            6,
+            "abc123",
+            1_000
+        ));
+        assert!(!should_calculate_consensus(
+            &state,
+            &counts,
+            &temp_report_account,
+            100,
+            "abc123",
+            1_000
+        ));
+    }
+
+    #[test]
+    fn test_validate_role_assignment() {
+        let admin = Pubkey::new_unique();
+        let bridge = Pubkey::new_unique();
+        assert!(validate_role_assignment(&admin, &bridge).is_ok());
+        assert_eq!(
+            validate_role_assignment(&admin, &admin).unwrap_err(),
+            OracleError::ConflictingRoleAssignment.into()
+        );
+    }
+
+    #[test]
+    fn test_max_monitored_txids_fits_in_the_state_account() {
+        let mut state = OracleContractState::default();
+        let capacity = monitored_txids_capacity(&state, INITIAL_ACCOUNT_SPACE).unwrap();
+        assert!(capacity > 0);
+
+        // Filling the list up to the capacity with maximum-length TXIDs still serializes into the account
+        state.max_monitored_txids = capacity;
+        for i in 0..capacity {
+            add_monitored_txid(&mut state, &format!("{:064x}", i)).unwrap();
+        }
+        assert!(8 + state.try_to_vec().unwrap().len() <= INITIAL_ACCOUNT_SPACE);
+        assert_eq!(
+            monitored_txids_capacity(&state, INITIAL_ACCOUNT_SPACE).unwrap(),
+            capacity
+        );
+
+        // A larger account holds more
+        assert!(
+            monitored_txids_capacity(&state, INITIAL_ACCOUNT_SPACE + REALLOCATION_INCREMENT)
+                .unwrap()
+                > capacity
+        );
+    }
+
+    #[test]
+    fn test_validate_max_monitored_txids() {
+        let mut state = OracleContractState::default();
+        for txid in ["aa", "bb", "cc"] {
+            state.monitored_txids.push(txid.to_string());
+        }
+        let capacity = monitored_txids_capacity(&state, INITIAL_ACCOUNT_SPACE).unwrap();
+
+        assert!(validate_max_monitored_txids(&state, INITIAL_ACCOUNT_SPACE, 3).is_ok());
+        assert!(validate_max_monitored_txids(&state, INITIAL_ACCOUNT_SPACE, capacity).is_ok());
+        for invalid in [0, 2, capacity + 1] {
+            assert_eq!(
+                validate_max_monitored_txids(&state, INITIAL_ACCOUNT_SPACE, invalid).unwrap_err(),
+                OracleError::InvalidConfigValue.into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_admin_audit_log_ring_buffer() {
+        let mut audit_log = AdminAuditLogAccount {
+            records: Vec::new(),
+            next_index: 0,
+            total_records: 0,
+        };
+        let admin = Pubkey::new_unique();
+
+        for i in 0..3u32 {
+            audit_log.record(
+                AdminActionCode::SetMaxMonitoredTxids,
+                admin,
+                i,
+                &format!("max_monitored_txids={}", i),
+            );
+        }
+        let page = audit_log.page(0, 10);
+        assert_eq!(page.len(), 3);
+        assert_eq!(page[0].param_summary, "max_monitored_txids=0");
+        assert_eq!(page[2].timestamp, 2);
+        assert_eq!(page[0].actor, admin);
+
+        // Overflow the buffer: the oldest records are evicted and paging stays chronological
+        let overflow = ADMIN_AUDIT_LOG_CAPACITY as u32 + 5;
+        for i in 3..overflow {
+            audit_log.record(AdminActionCode::WithdrawFunds, admin, i, &"x".repeat(100));
+        }
+        assert_eq!(audit_log.records.len(), ADMIN_AUDIT_LOG_CAPACITY);
+        assert_eq!(audit_log.total_records, overflow as u64);
+
+        let first_page = audit_log.page(0, MAX_AUDIT_LOG_PAGE_SIZE);
+        assert_eq!(first_page.len(), MAX_AUDIT_LOG_PAGE_SIZE as usize);
+        assert_eq!(first_page[0].timestamp, 5);
+        assert_eq!(
+            first_page[0].param_summary.len(),
+            MAX_AUDIT_PARAM_SUMMARY_LENGTH
+        );
+        let last_page = audit_log.page(ADMIN_AUDIT_LOG_CAPACITY as u32 - 2, 100);
+        assert_eq!(last_page.len(), 2);
+        assert_eq!(last_page[1].timestamp, overflow - 1);
+        assert!(audit_log
+            .page(ADMIN_AUDIT_LOG_CAPACITY as u32, 10)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_recompute_eligibility_after_lowering_thresholds() {
+        let mut state = OracleContractState {
+            min_reports_for_reward: MIN_REPORTS_FOR_REWARD,
+            min_reliability_score_for_reward: MIN_RELIABILITY_SCORE_FOR_REWARD,
+            min_compliance_score_for_reward: MIN_COMPLIANCE_SCORE_FOR_REWARD,
+            ..Default::default()
+        };
+        let current_timestamp = 1_000_000;
+        let mut contributors: Vec<Contributor> = (0..3)
+            .map(|_| Contributor {
+                reward_address: Pubkey::new_unique(),
+                total_reports_submitted: 5,
+                accurate_reports_count: 5,
+                reliability_score: MIN_RELIABILITY_SCORE_FOR_REWARD,
+                compliance_score: MIN_COMPLIANCE_SCORE_FOR_REWARD,
+                last_active_timestamp: current_timestamp,
+                ..Default::default()
+            })
+            .collect();
+
+        let summary =
+            recompute_eligibility_batch(&state, &mut contributors, 0, 10, current_timestamp);
+        assert_eq!(summary.newly_eligible_count, 0);
+        assert!(contributors.iter().all(|c| !c.is_eligible_for_rewards));
+
+        // Lowering the report threshold makes the existing contributors eligible, one batch at a time
+        state.min_reports_for_reward = 5;
+        let first_batch =
+            recompute_eligibility_batch(&state, &mut contributors, 0, 2, current_timestamp);
+        assert_eq!(first_batch.processed_count, 2);
+        assert_eq!(first_batch.newly_eligible_count, 2);
+        assert_eq!(first_batch.next_cursor, 2);
+        assert!(!first_batch.is_complete);
+        assert!(!contributors[2].is_eligible_for_rewards);
+
+        let second_batch = recompute_eligibility_batch(
+            &state,
+            &mut contributors,
+            first_batch.next_cursor,
+            2,
+            current_timestamp,
+        );
+        assert_eq!(second_batch.processed_count, 1);
+        assert_eq!(second_batch.newly_eligible_count, 1);
+        assert!(second_batch.is_complete);
+        assert!(contributors.iter().all(|c| c.is_eligible_for_rewards));
+
+        // A cursor past the end is a no-op
+        let past_end =
+            recompute_eligibility_batch(&state, &mut contributors, 10, 2, current_timestamp);
+        assert_eq!(past_end.processed_count, 0);
+        assert!(past_end.is_complete);
+    }
+
+    #[test]
+    fn test_reallocation_errors_identify_failing_account() {
+        let rent = Rent::default();
+        let well_funded = rent.minimum_balance(MAX_REALLOCATED_ACCOUNT_SIZE);
+        let current_size = 10_240;
+
+        // Accounts below the usage threshold are left alone
+        assert_eq!(
+            compute_reallocation_size(
+                ReallocatableAccount::ContributorDataAccount,
+                current_size,
+                current_size / 2,
+                0,
+                &rent,
+            )
+            .unwrap(),
+            None
+        );
+        assert_eq!(
+            compute_reallocation_size(
+                ReallocatableAccount::ContributorDataAccount,
+                current_size,
+                current_size,
+                well_funded,
+                &rent,
+            )
+            .unwrap(),
+            Some(current_size + REALLOCATION_INCREMENT)
+        );
+
+        let cases = [
+            (
+                ReallocatableAccount::TempReportAccount,
+                OracleError::TempReportAccountMaxSizeExceeded,
+                OracleError::TempReportAccountReallocationUnderfunded,
+            ),
+            (
+                ReallocatableAccount::ContributorDataAccount,
+                OracleError::ContributorDataAccountMaxSizeExceeded,
+                OracleError::ContributorDataAccountReallocationUnderfunded,
+            ),
+            (
+                ReallocatableAccount::SubmissionCountsAccount,
+                OracleError::SubmissionCountsAccountMaxSizeExceeded,
+                OracleError::SubmissionCountsAccountReallocationUnderfunded,
+            ),
+            (
+                ReallocatableAccount::AggregatedConsensusDataAccount,
+                OracleError::AggregatedConsensusDataAccountMaxSizeExceeded,
+                OracleError::AggregatedConsensusDataAccountReallocationUnderfunded,
+            ),
+        ];
+        for (account, max_size_error, underfunded_error) in cases {
+            assert_eq!(
+                compute_reallocation_size(
+                    account,
+                    MAX_REALLOCATED_ACCOUNT_SIZE,
+                    MAX_REALLOCATED_ACCOUNT_SIZE,
+                    well_funded,
+                    &rent,
+                )
+                .unwrap_err(),
+                max_size_error.into()
+            );
+            assert_eq!(
+                compute_reallocation_size(account, current_size, current_size, 0, &rent)
+                    .unwrap_err(),
+                underfunded_error.into()
+            );
+        }
+
+        assert_eq!(
+            check_reallocation_funding(
+                ReallocatableAccount::OracleContractState,
+                current_size + REALLOCATION_INCREMENT,
+                rent.minimum_balance(current_size),
+                &rent,
+            )
+            .unwrap_err(),
+            OracleError::OracleStateReallocationUnderfunded.into()
+        );
+    }
+
+    #[test]
+    fn test_contributor_lookup_stays_sorted_across_registrations_and_bans() {
+        let mut contributor_data = ContributorDataAccount {
+            contributors: Vec::new(),
+        };
+        let new_contributor = |reward_address| Contributor {
+            reward_address,
+            ..Default::default()
+        };
+
+        let mut live: Vec<Pubkey> = (0..6).map(|_| Pubkey::new_unique()).rev().collect();
+        for reward_address in &live {
+            contributor_data
+                .insert_contributor(new_contributor(*reward_address))
+                .unwrap();
+        }
+        assert!(contributor_data.contributors_sorted());
+        assert_eq!(
+            contributor_data
+                .insert_contributor(new_contributor(live[0]))
+                .unwrap_err(),
+            OracleError::ContributorAlreadyRegistered.into()
+        );
+
+        // Permanently ban the first, a middle and the last contributor
+        let banned = [
+            contributor_data.contributors[0].reward_address,
+            contributor_data.contributors[3].reward_address,
+            contributor_data.contributors[5].reward_address,
+        ];
+        for reward_address in &banned {
+            contributor_data
+                .find_contributor_mut(reward_address)
+                .unwrap()
+                .ban_expiry = u32::MAX;
+        }
+        apply_permanent_bans(&mut contributor_data);
+        live.retain(|reward_address| !banned.contains(reward_address));
+
+        // Register more contributors after the removals, including at both ends of the order
+        for reward_address in [Pubkey::new_from_array([0; 32]), Pubkey::new_unique()] {
+            contributor_data
+                .insert_contributor(new_contributor(reward_address))
+                .unwrap();
+            live.push(reward_address);
+        }
+        contributor_data
+            .insert_contributor(new_contributor(Pubkey::new_from_array([255; 32])))
+            .unwrap();
+        live.push(Pubkey::new_from_array([255; 32]));
+
+        assert!(contributor_data.contributors_sorted());
+        assert_eq!(contributor_data.contributors.len(), live.len());
+        for reward_address in &live {
+            assert_eq!(
+                contributor_data
+                    .find_contributor(reward_address)
+                    .map(|c| c.reward_address),
+                Some(*reward_address)
+            );
+        }
+        for reward_address in &banned {
+            assert!(contributor_data.find_contributor(reward_address).is_none());
+        }
+    }
+
+    #[test]
+    fn test_insert_contributor_repairs_unsorted_legacy_data() {
+        let mut reward_addresses: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let mut contributor_data = ContributorDataAccount {
+            contributors: reward_addresses
+                .iter()
+                .rev()
+                .map(|reward_address| Contributor {
+                    reward_address: *reward_address,
+                    ..Default::default()
+                })
+                .collect(),
+        };
+        assert!(!contributor_data.contributors_sorted());
+
+        let reward_address = Pubkey::new_unique();
+        contributor_data
+            .insert_contributor(Contributor {
+                reward_address,
+                ..Default::default()
+            })
+            .unwrap();
+        reward_addresses.push(reward_address);
+
+        assert!(contributor_data.contributors_sorted());
+        for reward_address in &reward_addresses {
+            assert!(contributor_data.find_contributor(reward_address).is_some());
+        }
+    }
+
+    #[test]
+    fn test_early_bird_multiplier_favors_first_submitter() {
+        let state = OracleContractState {
+            early_bird_multiplier: 1_500000000, // 1.5
+            early_bird_decay_period: 600,
+            ..Default::default()
+        };
+        let mut aggregated_data_account = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        let weight = 100_000000000;
+        let first_seen = 10_000;
+
+        // Eight submitters for the same txid, 60 seconds apart
+        let effective_weights: Vec<u64> = (0..8)
+            .map(|i| {
+                let report = test_report(
+                    "abc123",
+                    TxidStatus::MinedActivated,
+                    "aaaaaa",
+                    Pubkey::new_unique(),
+                );
+                aggregate_consensus_data(
+                    &state,
+                    &mut aggregated_data_account,
+                    &report,
+                    weight,
+                    "abc123",
+                    first_seen + i * 60,
+                )
+                .unwrap()
+            })
+            .collect();
+
+        assert_eq!(effective_weights[0], 150_000000000);
+        assert_eq!(effective_weights[7], 115_000000000); // 1.0 + 0.5 * (180 / 600)
+        assert!(effective_weights.windows(2).all(|pair| pair[0] > pair[1]));
+        assert_eq!(
+            aggregated_data_account.consensus_data[0].first_seen,
+            first_seen
+        );
+        assert_eq!(
+            aggregated_data_account.consensus_data[0].status_weights
+                [TxidStatus::MinedActivated as usize],
+            effective_weights.iter().sum::<u64>() * AGGREGATED_WEIGHT_SCALE
+        );
+
+        // Past the decay period, and with the multiplier disabled, submissions keep their base weight
+        assert_eq!(early_bird_multiplier(&state, 600), ONE);
+        assert_eq!(
+            early_bird_multiplier(&OracleContractState::default(), 0),
+            ONE
+        );
+    }
+
+    #[test]
+    fn test_repair_submission_counts_merges_duplicates() {
+        let submission_count = |txid: &str, count, last_updated| TxidSubmissionCount {
+            txid: txid.to_string(),
+            count,
+            last_updated,
+            quorum_reached_at: 0,
+        };
+        let mut submission_counts_account = TxidSubmissionCountsAccount {
+            submission_counts: vec![
+                submission_count("cc", 2, 100),
+                submission_count("aa", 1, 50),
+                submission_count("cc", 3, 300),
+                submission_count("bb", 4, 200),
+                submission_count("aa", 5, 40),
+                submission_count("cc", 1, 250),
+            ],
+        };
+        assert!(!submission_counts_account.submission_counts_sorted());
+
+        assert_eq!(submission_counts_account.repair_submission_counts(), 3);
+        assert!(submission_counts_account.submission_counts_sorted());
+        let repaired: Vec<(&str, u32, u32)> = submission_counts_account
+            .submission_counts
+            .iter()
+            .map(|c| (c.txid.as_str(), c.count, c.last_updated))
+            .collect();
+        assert_eq!(
+            repaired,
+            vec![("aa", 6, 50), ("bb", 4, 200), ("cc", 6, 300)]
+        );
+        assert_eq!(get_submission_count(&submission_counts_account, "cc"), 6);
+
+        // New submissions keep the account sorted and update the merged entry in place
+        submission_counts_account.record_submission("ab", 400);
+        submission_counts_account.record_submission("cc", 500);
+        assert!(submission_counts_account.submission_counts_sorted());
+        assert_eq!(get_submission_count(&submission_counts_account, "ab"), 1);
+        assert_eq!(get_submission_count(&submission_counts_account, "cc"), 7);
+        assert_eq!(submission_counts_account.repair_submission_counts(), 0);
+    }
+
+    #[test]
+    fn test_hash_mismatches_trigger_accelerated_ban() {
+        let state = OracleContractState {
+            hash_mismatch_ban_threshold: 3,
+            ..Default::default()
+        };
+        let mut timestamp = 1_000_000;
+        let mut contributor = Contributor {
+            reward_address: Pubkey::new_unique(),
+            compliance_score: ONE,
+            reliability_score: ONE,
+            // Past the temporary ban window but far from the general permanent ban thresholds
+            total_reports_submitted: 100,
+            last_active_timestamp: timestamp,
+            ..Default::default()
+        };
+        let matching = test_report(
+            "abc123",
+            TxidStatus::MinedActivated,
+            "aaaaaa",
+            contributor.reward_address,
+        );
+        let mismatching = test_report(
+            "abc123",
+            TxidStatus::MinedActivated,
+            "ffffff",
+            contributor.reward_address,
+        );
+        let common_data = |report: &PastelTxStatusReport| CommonReportData {
+            txid: report.txid.clone(),
+            txid_status: report.txid_status,
+            pastel_ticket_type: report.pastel_ticket_type,
+            first_6_characters_of_sha3_256_hash_of_corresponding_file: report
+                .first_6_characters_of_sha3_256_hash_of_corresponding_file
+                .clone(),
+        };
+        let mut submit = |contributor: &mut Contributor, report: &PastelTxStatusReport| {
+            timestamp += 60;
+            let hash_matches = hash_matches_consensus(&common_data(report), "aaaaaa");
+            update_contributor(
+                contributor,
+                &state,
+                timestamp,
+                hash_matches == Some(true),
+                hash_matches,
+                state.participation_reward_lamports,
+            );
+        };
+
+        // A matching hash resets the streak of mismatches
+        submit(&mut contributor, &mismatching);
+        submit(&mut contributor, &mismatching);
+        assert_eq!(contributor.distinct_hash_mismatches, 2);
+        submit(&mut contributor, &matching);
+        assert_eq!(contributor.distinct_hash_mismatches, 0);
+
+        for _ in 0..3 {
+            submit(&mut contributor, &mismatching);
+        }
+        assert_eq!(contributor.distinct_hash_mismatches, 3);
+        assert!(contributor.consensus_failures < PERMANENT_BAN_THRESHOLD);
+        assert_eq!(contributor.ban_expiry, u32::MAX);
+
+        // Reports without a hash, or without a consensus hash, leave the counter alone
+        let mut no_hash = common_data(&matching);
+        no_hash.first_6_characters_of_sha3_256_hash_of_corresponding_file = None;
+        assert_eq!(hash_matches_consensus(&no_hash, "aaaaaa"), None);
+        assert_eq!(hash_matches_consensus(&common_data(&matching), ""), None);
+    }
+
+    #[test]
+    fn test_stake_weighting_shifts_consensus() {
+        let mut state = OracleContractState {
+            compliance_weight_coefficient: ONE,
+            reliability_weight_coefficient: ONE,
+            stake_weight_coefficient: ZERO,
+            ..Default::default()
+        };
+        // Three well-scored contributors without stake, two lower-scored contributors with large stakes
+        let unstaked: Vec<Contributor> = (0..3)
+            .map(|_| Contributor {
+                reward_address: Pubkey::new_unique(),
+                compliance_score: 90_000000000,
+                reliability_score: 90_000000000,
+                ..Default::default()
+            })
+            .collect();
+        let staked: Vec<Contributor> = (0..2)
+            .map(|_| Contributor {
+                reward_address: Pubkey::new_unique(),
+                compliance_score: 60_000000000,
+                reliability_score: 60_000000000,
+                stake_weight: 500_000000000,
+                ..Default::default()
+            })
+            .collect();
+
+        let consensus_status = |state: &OracleContractState| {
+            let mut aggregated_data_account = AggregatedConsensusDataAccount {
+                consensus_data: Vec::new(),
+                ticket_type_volumes: Default::default(),
+            };
+            for (contributors, status) in [
+                (&unstaked, TxidStatus::MinedActivated),
+                (&staked, TxidStatus::Invalid),
+            ] {
+                for contributor in contributors.iter() {
+                    let report =
+                        test_report("abc123", status, "aaaaaa", contributor.reward_address);
+                    aggregate_consensus_data(
+                        state,
+                        &mut aggregated_data_account,
+                        &report,
+                        contributor_report_weight(state, contributor, 1_000),
+                        "abc123",
+                        1_000,
+                    )
+                    .unwrap();
+                }
+            }
+            compute_consensus(
+                &aggregated_data_account.consensus_data[0],
+                TieBreakStrategy::default(),
+            )
+            .unwrap()
+            .0
+        };
+
+        // Default coefficients reproduce the original compliance + reliability weight
+        assert_eq!(
+            contributor_report_weight(&state, &staked[0], 1_000),
+            staked[0].compliance_score + staked[0].reliability_score
+        );
+        assert_eq!(consensus_status(&state), TxidStatus::MinedActivated);
+
+        state.stake_weight_coefficient = ONE;
+        assert_eq!(
+            contributor_report_weight(&state, &staked[0], 1_000),
+            620_000000000
+        );
+        assert_eq!(consensus_status(&state), TxidStatus::Invalid);
+    }
+
+    #[test]
+    fn test_validate_bounded_string_rejects_over_length() {
+        let signature = "5".repeat(MAX_TRANSACTION_SIGNATURE_LENGTH);
+        assert!(validate_bounded_string(&signature, MAX_TRANSACTION_SIGNATURE_LENGTH).is_ok());
+        assert!(validate_bounded_string("", MAX_TRANSACTION_SIGNATURE_LENGTH).is_ok());
+        assert_eq!(
+            validate_bounded_string(&format!("{}5", signature), MAX_TRANSACTION_SIGNATURE_LENGTH)
+                .unwrap_err(),
+            OracleError::StringTooLong.into()
+        );
+
+        // Multi-byte characters count by their encoded length
+        let multi_byte = "é".repeat(MAX_TRANSACTION_SIGNATURE_LENGTH / 2 + 1);
+        assert_eq!(
+            multi_byte.chars().count(),
+            MAX_TRANSACTION_SIGNATURE_LENGTH / 2 + 1
+        );
+        assert_eq!(
+            validate_bounded_string(&multi_byte, MAX_TRANSACTION_SIGNATURE_LENGTH).unwrap_err(),
+            OracleError::StringTooLong.into()
+        );
+
+        // Audit summaries are truncated on a character boundary within the byte limit
+        let truncated = truncate_to_byte_length(&"€".repeat(30), MAX_AUDIT_PARAM_SUMMARY_LENGTH);
+        assert_eq!(truncated, "€".repeat(21));
+        assert!(truncated.len() <= MAX_AUDIT_PARAM_SUMMARY_LENGTH);
+        assert_eq!(
+            truncate_to_byte_length("short", MAX_AUDIT_PARAM_SUMMARY_LENGTH),
+            "short"
+        );
+    }
+
+    #[test]
+    fn test_contributor_snapshot_pages() {
+        let contributors: Vec<Contributor> = (0..5u64)
+            .map(|i| Contributor {
+                reward_address: Pubkey::new_unique(),
+                compliance_score: (50 + i) * ONE,
+                reliability_score: (80 + i) * ONE,
+                total_rewards_earned_lamports: i * BASE_REWARD_AMOUNT_IN_LAMPORTS,
+                ..Default::default()
+            })
+            .collect();
+
+        let snapshot = contributor_snapshot_page(&contributors, 0, 10);
+        assert_eq!(snapshot.len(), 5);
+        for (standing, contributor) in snapshot.iter().zip(&contributors) {
+            assert_eq!(standing.reward_address, contributor.reward_address);
+            assert_eq!(standing.compliance_score, contributor.compliance_score);
+            assert_eq!(standing.reliability_score, contributor.reliability_score);
+            assert_eq!(
+                standing.total_rewards_earned_lamports,
+                contributor.total_rewards_earned_lamports
+            );
+        }
+
+        let second_page = contributor_snapshot_page(&contributors, 3, 2);
+        assert_eq!(second_page.len(), 2);
+        assert_eq!(
+            second_page[0].reward_address,
+            contributors[3].reward_address
+        );
+        assert!(contributor_snapshot_page(&contributors, 5, 2).is_empty());
+    }
+
+    #[test]
+    fn test_decay_factor_never_exceeds_one() {
+        // Includes exactly one and two days, which take the special-cased exponents in pow_up
+        for hours_inactive in [0, 1, 12, 24, 36, 48, 72, 24 * 30, 24 * 365] {
+            let decay_factor = inactivity_decay_factor(hours_inactive * ONE);
+            assert!(
+                decay_factor <= ONE,
+                "decay factor {} exceeds ONE after {} hours",
+                decay_factor,
+                hours_inactive
+            );
+        }
+        assert_eq!(inactivity_decay_factor(48 * ONE), 980100000);
+        assert!(inactivity_decay_factor(72 * ONE) < inactivity_decay_factor(48 * ONE));
+    }
+
+    #[test]
+    fn test_scores_never_increase_without_accurate_reports() {
+        let state = OracleContractState {
+            logistic_steepness: ONE_TENTH,
+            logistic_midpoint: MID_COMPLIANCE_SCORE,
+            ..Default::default()
+        };
+        let mut timestamp = 1_000_000;
+        let mut contributor = Contributor {
+            reward_address: Pubkey::new_unique(),
+            compliance_score: 90_000000000,
+            reliability_score: 100_000000000,
+            total_reports_submitted: 60,
+            accurate_reports_count: 60,
+            last_active_timestamp: timestamp,
+            ..Default::default()
+        };
+
+        for gap_seconds in [60, 3_600, 86_400, 2 * 86_400, 60, 7 * 86_400, 600] {
+            let previous_compliance_score = contributor.compliance_score;
+            let previous_reliability_score = contributor.reliability_score;
+            timestamp += gap_seconds;
+            update_scores(&mut contributor, &state, timestamp, false);
+            assert!(contributor.compliance_score <= previous_compliance_score);
+            assert!(contributor.reliability_score <= previous_reliability_score);
+        }
+    }
+
+    #[test]
+    fn test_higher_logistic_steepness_sharpens_curve() {
+        let gentle = ONE_TENTH;
+        let steep = 300000000; // 0.3
+        validate_logistic_config(gentle, MID_COMPLIANCE_SCORE).unwrap();
+        validate_logistic_config(steep, MID_COMPLIANCE_SCORE).unwrap();
+
+        let scale = |score: u64, steepness: u64| {
+            logistic_scale(score, MAX_COMPLIANCE_SCORE, steepness, MID_COMPLIANCE_SCORE)
+        };
+        assert_eq!(scale(MID_COMPLIANCE_SCORE, gentle), MID_COMPLIANCE_SCORE);
+        assert_eq!(scale(MID_COMPLIANCE_SCORE, steep), MID_COMPLIANCE_SCORE);
+        for offset in [5, 10, 20] {
+            let above = MID_COMPLIANCE_SCORE + offset * ONE;
+            let below = MID_COMPLIANCE_SCORE - offset * ONE;
+            assert!(scale(above, steep) > scale(above, gentle));
+            assert!(scale(below, steep) < scale(below, gentle));
+        }
+
+        // The midpoint moves the center of the curve
+        assert_eq!(
+            logistic_scale(70 * ONE, MAX_COMPLIANCE_SCORE, gentle, 70 * ONE),
+            MID_COMPLIANCE_SCORE
+        );
+    }
+
+    #[test]
+    fn test_validate_logistic_config() {
+        assert!(validate_logistic_config(ONE_TENTH, ZERO).is_ok());
+        assert!(validate_logistic_config(ONE_TENTH, MAX_COMPLIANCE_SCORE).is_ok());
+        for (steepness, midpoint) in [
+            (ZERO, MID_COMPLIANCE_SCORE),
+            (ONE_TENTH, MAX_COMPLIANCE_SCORE + 1),
+            // Exponent would reach 1.0 * 50.0, beyond what the fixed-point exp can represent
+            (ONE, MID_COMPLIANCE_SCORE),
+        ] {
+            assert_eq!(
+                validate_logistic_config(steepness, midpoint).unwrap_err(),
+                OracleError::InvalidConfigValue.into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_consensus_config_reflects_state() {
+        let mut state = OracleContractState {
+            min_quorum: 4,
+            quorum_auto_scaling_enabled: true,
+            quorum_fraction: FIVE_TENTH,
+            early_bird_multiplier: 1_500000000,
+            early_bird_decay_period: 300,
+            compliance_weight_coefficient: ONE,
+            reliability_weight_coefficient: FIVE_TENTH,
+            stake_weight_coefficient: ONE_TENTH,
+            hash_mismatch_ban_threshold: 7,
+            aggregated_data_retention_period: 3_600,
+            ..Default::default()
+        };
+        state.accuracy_policies[PastelTicketType::Sense as usize] = AccuracyPolicy::StatusOnly;
+
+        let config = consensus_config(&state, 25, 22, 20);
+        assert_eq!(config.registered_contributor_count, 25);
+        assert_eq!(config.active_contributor_count, 22);
+        assert_eq!(config.mature_contributor_count, 20);
+        assert_eq!(config.effective_quorum, 10);
+        assert_eq!(config.min_quorum, 4);
+        assert!(config.quorum_auto_scaling_enabled);
+        assert_eq!(config.quorum_fraction, FIVE_TENTH);
+        assert_eq!(
+            config.accuracy_policies[PastelTicketType::Sense as usize],
+            AccuracyPolicy::StatusOnly
+        );
+        assert_eq!(
+            config.accuracy_policies[PastelTicketType::Cascade as usize],
+            AccuracyPolicy::StatusAndHash
+        );
+        assert_eq!(config.aggregated_data_retention_period, 3_600);
+        assert_eq!(config.early_bird_multiplier, 1_500000000);
+        assert_eq!(config.early_bird_decay_period, 300);
+        assert_eq!(config.reliability_weight_coefficient, FIVE_TENTH);
+        assert_eq!(config.stake_weight_coefficient, ONE_TENTH);
+        assert_eq!(config.hash_mismatch_ban_threshold, 7);
+    }
+
+    #[test]
+    fn test_post_consensus_cleanup_uses_per_account_retention() {
+        let state = OracleContractState {
+            temp_report_retention_period: 100,
+            aggregated_data_retention_period: 200,
+            submission_count_retention_period: 300,
+            ..Default::default()
+        };
+        let mut temp_report_account = TempTxStatusReportAccount {
+            reports: Vec::new(),
+            common_reports: Vec::new(),
+        };
+        let mut aggregated_data_account = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        let mut submission_counts_account = TxidSubmissionCountsAccount {
+            submission_counts: Vec::new(),
+        };
+        let mut contributor_data_account = ContributorDataAccount {
+            contributors: Vec::new(),
+        };
+
+        // One txid per age; the txid that just reached consensus is always cleared from temp reports
+        let current_timestamp = 10_000;
+        let ages = [50, 150, 250, 350];
+        for age in ages {
+            let txid = format!("{:02x}", age);
+            let mut report = test_report(
+                &txid,
+                TxidStatus::MinedActivated,
+                "aaaaaa",
+                Pubkey::new_unique(),
+            );
+            report.timestamp = current_timestamp - age;
+            submit_test_report(
+                &mut temp_report_account,
+                &mut aggregated_data_account,
+                &report,
+                ONE,
+            );
+            submission_counts_account.record_submission(&txid, current_timestamp - age);
+        }
+
+        post_consensus_tasks(
+            &state,
+            &mut submission_counts_account,
+            &mut aggregated_data_account,
+            &mut temp_report_account,
+            &mut contributor_data_account,
+            "ff",
+            current_timestamp,
+        )
+        .unwrap();
+
+        assert_eq!(temp_report_account.reports.len(), 1); // age 50 only
+        assert_eq!(aggregated_data_account.consensus_data.len(), 2); // ages 50 and 150
+        assert_eq!(submission_counts_account.submission_counts.len(), 3); // ages 50, 150 and 250
+        let retained_counts: Vec<String> = submission_counts_account
+            .submission_counts
+            .iter()
+            .map(|c| c.txid.clone())
+            .collect();
+        let expected_counts: Vec<String> =
+            ages[..3].iter().map(|age| format!("{:02x}", age)).collect();
+        assert_eq!(retained_counts, expected_counts);
+    }
+
+    #[test]
+    fn test_zero_weight_reports_defer_consensus() {
+        let mut state = OracleContractState::default();
+        let mut temp_report_account = TempTxStatusReportAccount {
+            reports: Vec::new(),
+            common_reports: Vec::new(),
+        };
+        let mut aggregated_data_account = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        let mut consensus_summary_account = ConsensusSummaryAccount::default();
+        let mut contributor_data_account = ContributorDataAccount {
+            contributors: Vec::new(),
+        };
+
+        for _ in 0..3 {
+            let contributor = Contributor {
+                reward_address: Pubkey::new_unique(),
+                last_active_timestamp: 1_000,
+                ..Default::default()
+            };
+            let report = test_report(
+                "abc123",
+                TxidStatus::MinedActivated,
+                "aaaaaa",
+                contributor.reward_address,
+            );
+            contributor_data_account
+                .insert_contributor(contributor)
+                .unwrap();
+            submit_test_report(
+                &mut temp_report_account,
+                &mut aggregated_data_account,
+                &report,
+                0,
+            );
+        }
+
+        assert!(compute_consensus(
+            &aggregated_data_account.consensus_data[0],
+            TieBreakStrategy::default()
+        )
+        .is_none());
+        let consensus_reached = calculate_consensus(
+            &mut state,
+            &mut aggregated_data_account,
+            &mut consensus_summary_account,
+            &temp_report_account,
+            &mut contributor_data_account,
+            "abc123",
+            2_000,
+            1,
+        )
+        .unwrap();
+        assert!(!consensus_reached);
+        assert!(contributor_data_account
+            .contributors
+            .iter()
+            .all(|c| c.total_reports_submitted == 0 && c.consensus_failures == 0));
+
+        // A txid without any aggregated data is deferred too
+        assert!(!calculate_consensus(
+            &mut state,
+            &mut aggregated_data_account,
+            &mut consensus_summary_account,
+            &temp_report_account,
+            &mut contributor_data_account,
+            "def456",
+            2_000,
+            1,
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn test_reliability_bonus_scales_reward() {
+        let state = OracleContractState {
+            reliability_bonus_factor: 1_500000000,
+            ..Default::default()
+        };
+        let reliable = Contributor {
+            is_eligible_for_rewards: true,
+            is_reliable: true,
+            ..Default::default()
+        };
+        let unreliable = Contributor {
+            is_eligible_for_rewards: true,
+            is_reliable: false,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            contributor_reward_amount(&state, &reliable),
+            BASE_REWARD_AMOUNT_IN_LAMPORTS * 3 / 2
+        );
+        assert_eq!(
+            contributor_reward_amount(&state, &unreliable),
+            BASE_REWARD_AMOUNT_IN_LAMPORTS
+        );
+
+        // At the default factor of 1.0 reliability makes no difference
+        let state = OracleContractState {
+            reliability_bonus_factor: ONE,
+            ..Default::default()
+        };
+        assert_eq!(
+            contributor_reward_amount(&state, &reliable),
+            BASE_REWARD_AMOUNT_IN_LAMPORTS
+        );
+    }
+
+    #[test]
+    fn test_mixed_case_txids_aggregate_together() {
+        let contributor = Pubkey::new_unique();
+        assert_eq!(
+            create_seed("pastel_tx_status_report", "ABCDEF12", &contributor),
+            create_seed("pastel_tx_status_report", "abcdef12", &contributor)
+        );
+
+        let mut temp_report_account = TempTxStatusReportAccount {
+            reports: Vec::new(),
+            common_reports: Vec::new(),
+        };
+        let mut aggregated_data_account = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        for txid in ["ABCDEF12", "abcdef12", "AbCdEf12"] {
+            let report = test_report(
+                &normalize_txid(txid),
+                TxidStatus::MinedActivated,
+                "aaaaaa",
+                Pubkey::new_unique(),
+            );
+            submit_test_report(
+                &mut temp_report_account,
+                &mut aggregated_data_account,
+                &report,
+                ONE,
+            );
+        }
+
+        assert_eq!(aggregated_data_account.consensus_data.len(), 1);
+        assert_eq!(aggregated_data_account.consensus_data[0].txid, "abcdef12");
+        assert_eq!(temp_report_account.reports.len(), 3);
+        assert_eq!(temp_report_account.common_reports.len(), 1);
+    }
+
+    #[test]
+    fn test_heartbeat_refreshes_activity_only() {
+        let state = OracleContractState::default();
+        let mut contributor = Contributor {
+            reward_address: Pubkey::new_unique(),
+            compliance_score: 42 * ONE,
+            reliability_score: 50 * ONE,
+            total_reports_submitted: 7,
+            accurate_reports_count: 6,
+            last_active_timestamp: 1_000,
+            ..Default::default()
+        };
+        let two_days_later = 1_000 + 2 * 86_400;
+        update_statuses(&mut contributor, &state, two_days_later);
+        assert!(!contributor.is_recently_active);
+
+        record_heartbeat(&mut contributor, &state, two_days_later).unwrap();
+        assert!(contributor.is_recently_active);
+        assert_eq!(contributor.last_active_timestamp, two_days_later);
+        assert_eq!(contributor.compliance_score, 42 * ONE);
+        assert_eq!(contributor.reliability_score, 50 * ONE);
+        assert_eq!(contributor.total_reports_submitted, 7);
+        assert_eq!(contributor.accurate_reports_count, 6);
+
+        // A second heartbeat within the rate limit is rejected and changes nothing
+        assert_eq!(
+            record_heartbeat(&mut contributor, &state, two_days_later + 60).unwrap_err(),
+            OracleError::HeartbeatTooFrequent.into()
+        );
+        assert_eq!(contributor.last_active_timestamp, two_days_later);
+        assert!(record_heartbeat(
+            &mut contributor,
+            &state,
+            two_days_later + MIN_HEARTBEAT_INTERVAL
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_status_breakdown_labels_accumulated_weights() {
+        for (index, status) in TxidStatus::ALL.iter().enumerate() {
+            assert_eq!(*status as usize, index);
+        }
+
+        let mut temp_report_account = TempTxStatusReportAccount {
+            reports: Vec::new(),
+            common_reports: Vec::new(),
+        };
+        let mut aggregated_data_account = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        for (status, weight) in [
+            (TxidStatus::MinedActivated, 3 * ONE),
+            (TxidStatus::MinedActivated, 2 * ONE),
+            (TxidStatus::PendingMining, ONE),
+        ] {
+            let report = test_report("abc123", status, "aaaaaa", Pubkey::new_unique());
+            submit_test_report(
+                &mut temp_report_account,
+                &mut aggregated_data_account,
+                &report,
+                weight,
+            );
+        }
+
+        let data = &aggregated_data_account.consensus_data[0];
+        let breakdown = data.status_breakdown();
+        assert_eq!(
+            breakdown.iter().map(|s| s.status).collect::<Vec<_>>(),
+            TxidStatus::ALL.to_vec()
+        );
+        for entry in &breakdown {
+            assert_eq!(entry.weight, data.status_weights[entry.status as usize]);
+        }
+        let weight_of = |status: TxidStatus| {
+            breakdown
+                .iter()
+                .find(|s| s.status == status)
+                .unwrap()
+                .weight
+        };
+        assert_eq!(weight_of(TxidStatus::Invalid), 0);
+        assert_eq!(weight_of(TxidStatus::MinedPendingActivation), 0);
+        assert_eq!(
+            weight_of(TxidStatus::MinedActivated),
+            5 * weight_of(TxidStatus::PendingMining)
+        );
+    }
+
+    #[test]
+    fn test_tie_break_strategies_on_identical_tie() {
+        let mut temp_report_account = TempTxStatusReportAccount {
+            reports: Vec::new(),
+            common_reports: Vec::new(),
+        };
+        let mut aggregated_data_account = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        // Two equally weighted camps; the PendingMining/"bbbbbb" camp reports first
+        for (status, hash, timestamp) in [
+            (TxidStatus::PendingMining, "bbbbbb", 1_000),
+            (TxidStatus::MinedActivated, "aaaaaa", 1_060),
+        ] {
+            let mut report = test_report("abc123", status, hash, Pubkey::new_unique());
+            report.timestamp = timestamp;
+            submit_test_report(
+                &mut temp_report_account,
+                &mut aggregated_data_account,
+                &report,
+                ONE,
+            );
+        }
+        let data = &aggregated_data_account.consensus_data[0];
+
+        assert_eq!(
+            compute_consensus(data, TieBreakStrategy::HighestOrdinal),
+            Some((TxidStatus::MinedActivated, "bbbbbb".to_string()))
+        );
+        assert_eq!(
+            compute_consensus(data, TieBreakStrategy::Lexicographic),
+            Some((TxidStatus::PendingMining, "aaaaaa".to_string()))
+        );
+        assert_eq!(
+            compute_consensus(data, TieBreakStrategy::EarliestSubmission),
+            Some((TxidStatus::PendingMining, "bbbbbb".to_string()))
+        );
+
+        // Without a tie the heavier camp wins regardless of strategy
+        let report = test_report(
+            "abc123",
+            TxidStatus::MinedActivated,
+            "aaaaaa",
+            Pubkey::new_unique(),
+        );
+        submit_test_report(
+            &mut temp_report_account,
+            &mut aggregated_data_account,
+            &report,
+            ONE,
+        );
+        let data = &aggregated_data_account.consensus_data[0];
+        for strategy in [
+            TieBreakStrategy::HighestOrdinal,
+            TieBreakStrategy::Lexicographic,
+            TieBreakStrategy::EarliestSubmission,
+        ] {
+            assert_eq!(
+                compute_consensus(data, strategy),
+                Some((TxidStatus::MinedActivated, "aaaaaa".to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_replenishment_tops_up_low_pool() {
+        let rent_reserve = 890_880;
+        let state = OracleContractState {
+            auto_replenish_threshold: 5 * BASE_REWARD_AMOUNT_IN_LAMPORTS,
+            auto_replenish_amount: 10 * BASE_REWARD_AMOUNT_IN_LAMPORTS,
+            fee_account_reserve: 1_000_000,
+            ..Default::default()
+        };
+        let fee_balance = rent_reserve + 1_000_000 + 50 * BASE_REWARD_AMOUNT_IN_LAMPORTS;
+
+        // A low pool is topped up by the configured amount
+        assert_eq!(
+            compute_replenishment_amount(
+                &state,
+                BASE_REWARD_AMOUNT_IN_LAMPORTS,
+                fee_balance,
+                rent_reserve
+            ),
+            10 * BASE_REWARD_AMOUNT_IN_LAMPORTS
+        );
+        // A healthy pool is left alone
+        assert_eq!(
+            compute_replenishment_amount(
+                &state,
+                5 * BASE_REWARD_AMOUNT_IN_LAMPORTS,
+                fee_balance,
+                rent_reserve
+            ),
+            0
+        );
+        // The top-up never dips into the admin's reserve or the rent-exempt minimum
+        assert_eq!(
+            compute_replenishment_amount(
+                &state,
+                0,
+                rent_reserve + 1_000_000 + 3 * BASE_REWARD_AMOUNT_IN_LAMPORTS,
+                rent_reserve
+            ),
+            3 * BASE_REWARD_AMOUNT_IN_LAMPORTS
+        );
+        assert_eq!(
+            compute_replenishment_amount(&state, 0, rent_reserve + 999_999, rent_reserve),
+            0
+        );
+        // A zero threshold disables replenishment
+        let disabled = OracleContractState {
+            auto_replenish_threshold: 0,
+            ..state
+        };
+        assert_eq!(
+            compute_replenishment_amount(&disabled, 0, fee_balance, rent_reserve),
+            0
+        );
+    }
+
+    #[test]
+    fn test_file_hash_requirement_depends_on_ticket_type() {
+        let mut state = OracleContractState {
+            file_hash_required: [true; PASTEL_TICKET_TYPE_VARIANT_COUNT],
+            ..Default::default()
+        };
+        state.file_hash_required[PastelTicketType::InferenceApi as usize] = false;
+
+        let mut report = test_report(
+            "abc123",
+            TxidStatus::MinedActivated,
+            "aaaaaa",
+            Pubkey::new_unique(),
+        );
+        report.first_6_characters_of_sha3_256_hash_of_corresponding_file = None;
+
+        // A file-backed ticket type without a hash is rejected
+        report.pastel_ticket_type = Some(PastelTicketType::Sense);
+        assert_eq!(
+            validate_data_contributor_report(&report, &state).unwrap_err(),
+            OracleError::MissingFileHash.into()
+        );
+
+        // A ticket type without a file may omit it
+        report.pastel_ticket_type = Some(PastelTicketType::InferenceApi);
+        assert!(validate_data_contributor_report(&report, &state).is_ok());
+
+        // A hash that is given must still be well formed
+        report.first_6_characters_of_sha3_256_hash_of_corresponding_file = Some("xyz".to_string());
+        assert_eq!(
+            validate_data_contributor_report(&report, &state).unwrap_err(),
+            OracleError::InvalidFileHashLength.into()
+        );
+
+        // A hashless report matches a consensus without a hash
+        let common_data = CommonReportData {
+            txid: "abc123".to_string(),
+            txid_status: TxidStatus::MinedActivated,
+            pastel_ticket_type: Some(PastelTicketType::InferenceApi),
+            first_6_characters_of_sha3_256_hash_of_corresponding_file: None,
+        };
+        assert!(is_report_accurate(
+            &common_data,
+            TxidStatus::MinedActivated,
+            "",
+            AccuracyPolicy::StatusAndHash
+        ));
+        assert!(!is_report_accurate(
+            &common_data,
+            TxidStatus::MinedActivated,
+            "aaaaaa",
+            AccuracyPolicy::StatusAndHash
+        ));
+    }
+
+    #[test]
+    fn test_participation_reward_accrues_without_accuracy_reward() {
+        let state = OracleContractState {
+            participation_reward_lamports: 1_000,
+            reliability_bonus_factor: ONE,
+            min_reports_for_reward: 1,
+            min_reliability_score_for_reward: MIN_RELIABILITY_SCORE_FOR_REWARD,
+            min_compliance_score_for_reward: MIN_COMPLIANCE_SCORE_FOR_REWARD,
+            ..Default::default()
+        };
+        let mut contributor = Contributor {
+            reward_address: Pubkey::new_unique(),
+            last_active_timestamp: 1_000,
+            ..Default::default()
+        };
+
+        // Two inaccurate reports: participation is credited but no accuracy reward is earned
+        update_contributor(&mut contributor, &state, 2_000, false, Some(false), 1_000);
+        update_contributor(&mut contributor, &state, 3_000, false, Some(false), 1_000);
+        assert!(!contributor.is_eligible_for_rewards);
+        assert_eq!(contributor.owed_participation_rewards_lamports, 2_000);
+        assert_eq!(claimable_reward_amount(&state, &contributor), 2_000);
+
+        // An eligible contributor claims both the accuracy reward and its participation rewards
+        let eligible = Contributor {
+            is_eligible_for_rewards: true,
+            owed_participation_rewards_lamports: 1_000,
+            ..Default::default()
+        };
+        assert_eq!(
+            claimable_reward_amount(&state, &eligible),
+            BASE_REWARD_AMOUNT_IN_LAMPORTS + 1_000
+        );
+
+        // With participation rewards disabled an ineligible contributor has nothing to claim
+        let disabled = OracleContractState {
+            participation_reward_lamports: 0,
+            ..state
+        };
+        let mut contributor = Contributor {
+            reward_address: Pubkey::new_unique(),
+            last_active_timestamp: 1_000,
+            ..Default::default()
+        };
+        update_contributor(&mut contributor, &disabled, 2_000, false, Some(false), 0);
+        assert_eq!(claimable_reward_amount(&disabled, &contributor), 0);
+    }
+
+    #[test]
+    fn test_contributor_timing_for_new_and_banned_contributors() {
+        let state = OracleContractState {
+            contributor_activation_delay: 86_400,
+            ..Default::default()
+        };
+        let registered_at = 1_000;
+        let contributor = Contributor {
+            reward_address: Pubkey::new_unique(),
+            last_active_timestamp: registered_at,
+            registered_at,
+            ..Default::default()
+        };
+
+        // A just-registered contributor can submit right away, has no reward to claim yet and must wait to
+        // send a heartbeat
+        let timing = contributor_timing(&state, &contributor, registered_at + 60);
+        assert_eq!(timing.seconds_until_can_submit, 0);
+        assert_eq!(timing.seconds_until_activated, 86_400 - 60);
+        assert_eq!(timing.seconds_until_reward_claimable, u32::MAX);
+        assert_eq!(
+            timing.seconds_until_heartbeat_allowed,
+            MIN_HEARTBEAT_INTERVAL - 60
+        );
+
+        // A banned contributor with a reward waits out the ban to claim it, unless rewards are paused
+        let banned = Contributor {
+            ban_expiry: registered_at + 500,
+            owed_participation_rewards_lamports: 1_000,
+            ..contributor.clone()
+        };
+        let timing = contributor_timing(&state, &banned, registered_at + 100);
+        assert_eq!(timing.seconds_until_can_submit, 400);
+        assert_eq!(timing.seconds_until_reward_claimable, 400);
+        let paused = OracleContractState {
+            rewards_paused: true,
+            ..state.clone()
+        };
+        let timing = contributor_timing(&paused, &banned, registered_at + 100);
+        assert_eq!(timing.seconds_until_reward_claimable, u32::MAX);
+
+        let permanently_banned = Contributor {
+            ban_expiry: u32::MAX,
+            ..contributor.clone()
+        };
+        let timing = contributor_timing(&state, &permanently_banned, registered_at);
+        assert_eq!(timing.seconds_until_can_submit, u32::MAX);
+
+        // A first report waits out the quiet period, and a ban that ends later still decides
+        let quiet = OracleContractState {
+            submission_quiet_period: 3_600,
+            ..state.clone()
+        };
+        let timing = contributor_timing(&quiet, &contributor, registered_at + 60);
+        assert_eq!(timing.seconds_until_can_submit, 3_600 - 60);
+        let timing = contributor_timing(&quiet, &banned, registered_at + 100);
+        assert_eq!(timing.seconds_until_can_submit, 3_600 - 100);
+        let banned_longer = Contributor {
+            ban_expiry: registered_at + 5_000,
+            ..contributor.clone()
+        };
+        let timing = contributor_timing(&quiet, &banned_longer, registered_at + 100);
+        assert_eq!(timing.seconds_until_can_submit, 4_900);
+        // The quiet period only applies before the first report
+        let has_submitted = Contributor {
+            last_submission_timestamp: registered_at + 30,
+            ..contributor.clone()
+        };
+        let timing = contributor_timing(&quiet, &has_submitted, registered_at + 60);
+        assert_eq!(timing.seconds_until_can_submit, 0);
+
+        // Activation counts from registration and stays at 0 once the delay has passed
+        let timing = contributor_timing(&state, &contributor, registered_at + 86_400);
+        assert_eq!(timing.seconds_until_activated, 0);
+        let timing = contributor_timing(&state, &contributor, registered_at + 100_000);
+        assert_eq!(timing.seconds_until_activated, 0);
+        let longest_delay = OracleContractState {
+            contributor_activation_delay: u32::MAX,
+            ..state
+        };
+        let timing = contributor_timing(&longest_delay, &contributor, registered_at);
+        assert_eq!(timing.seconds_until_activated, u32::MAX - registered_at);
+    }
+
+    #[test]
+    fn test_low_weight_quorum_defers_consensus() {
+        let mut state = OracleContractState {
+            min_quorum: MIN_NUMBER_OF_ORACLES,
+            min_consensus_weight_sum: 100 * ONE,
+            ..Default::default()
+        };
+        let mut temp_report_account = TempTxStatusReportAccount {
+            reports: Vec::new(),
+            common_reports: Vec::new(),
+        };
+        let mut aggregated_data_account = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        let mut consensus_summary_account = ConsensusSummaryAccount::default();
+        let mut contributor_data_account = ContributorDataAccount {
+            contributors: Vec::new(),
+        };
+
+        // A full count quorum, but every contributor carries almost no weight
+        for _ in 0..MIN_NUMBER_OF_ORACLES {
+            let contributor = Contributor {
+                reward_address: Pubkey::new_unique(),
+                last_active_timestamp: 1_000,
+                ..Default::default()
+            };
+            let report = test_report(
+                "abc123",
+                TxidStatus::MinedActivated,
+                "aaaaaa",
+                contributor.reward_address,
+            );
+            contributor_data_account
+                .insert_contributor(contributor)
+                .unwrap();
+            submit_test_report(
+                &mut temp_report_account,
+                &mut aggregated_data_account,
+                &report,
+                ONE,
+            );
+        }
+        assert_eq!(
+            aggregated_data_account.consensus_data[0].total_weight(),
+            MIN_NUMBER_OF_ORACLES as u64 * ONE
+        );
+
+        assert!(!calculate_consensus(
+            &mut state,
+            &mut aggregated_data_account,
+            &mut consensus_summary_account,
+            &temp_report_account,
+            &mut contributor_data_account,
+            "abc123",
+            2_000,
+            1,
+        )
+        .unwrap());
+        assert!(contributor_data_account
+            .contributors
+            .iter()
+            .all(|c| c.total_reports_submitted == 0));
+
+        // Once the weight threshold is met the same reports reach consensus
+        state.min_consensus_weight_sum = MIN_NUMBER_OF_ORACLES as u64 * ONE;
+        assert!(calculate_consensus(
+            &mut state,
+            &mut aggregated_data_account,
+            &mut consensus_summary_account,
+            &temp_report_account,
+            &mut contributor_data_account,
+            "abc123",
+            2_000,
+            1,
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn test_audit_selection_is_reproducible() {
+        let seed = [7u8; 32];
+        let txids: Vec<String> = (0..200).map(|i| format!("{:064x}", i)).collect();
+        let selections = |seed: &[u8; 32], rate| {
+            txids
+                .iter()
+                .map(|txid| is_selected_for_audit(seed, txid, rate))
+                .collect::<Vec<_>>()
+        };
+
+        // The same seed always selects the same rounds
+        assert_eq!(selections(&seed, 10), selections(&seed, 10));
+        let selected = selections(&seed, 10).iter().filter(|&&s| s).count();
+        assert!(selected > 0 && selected < 60, "selected {}", selected);
+        assert_ne!(selections(&seed, 10), selections(&[8u8; 32], 10));
+
+        assert!(selections(&seed, 1).iter().all(|&s| s));
+        assert!(selections(&seed, 0).iter().all(|&s| !s));
+
+        // The seed is the hash of the most recent SlotHashes entry
+        let mut slot_hashes_data = vec![0u8; 16];
+        slot_hashes_data[..8].copy_from_slice(&1u64.to_le_bytes());
+        slot_hashes_data[8..16].copy_from_slice(&42u64.to_le_bytes());
+        slot_hashes_data.extend_from_slice(&seed);
+        assert_eq!(most_recent_slot_hash(&slot_hashes_data).unwrap(), seed);
+        assert!(most_recent_slot_hash(&slot_hashes_data[..40]).is_err());
+    }
+
+    #[test]
+    fn test_consensus_pending_until_finalized() {
+        let state = OracleContractState {
+            min_quorum: 2,
+            deferred_consensus: true,
+            ..Default::default()
+        };
+        let mut submission_counts_account = TxidSubmissionCountsAccount {
+            submission_counts: Vec::new(),
+        };
+        let mut temp_report_account = TempTxStatusReportAccount {
+            reports: Vec::new(),
+            common_reports: Vec::new(),
+        };
+        let mut aggregated_data_account = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        // The maximum aggregation age is disabled, so readiness depends on the quorum alone
+        let no_aggregated_data = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        let pending = |counts: &TxidSubmissionCountsAccount, temp: &TempTxStatusReportAccount| {
+            is_consensus_pending(
+                &state,
+                counts,
+                temp,
+                &no_aggregated_data,
+                2,
+                "abc123",
+                1_000,
+            )
+        };
+
+        let report = test_report(
+            "abc123",
+            TxidStatus::MinedActivated,
+            "aaaaaa",
+            Pubkey::new_unique(),
+        );
+        submit_test_report(
+            &mut temp_report_account,
+            &mut aggregated_data_account,
+            &report,
+            ONE,
+        );
+        submission_counts_account.record_submission("abc123", 1_000);
+        assert!(!pending(&submission_counts_account, &temp_report_account));
+
+        // The submission completing the quorum leaves the reports pending for finalize_consensus
+        submit_test_report(
+            &mut temp_report_account,
+            &mut aggregated_data_account,
+            &report,
+            ONE,
+        );
+        submission_counts_account.record_submission("abc123", 1_000);
+        assert!(pending(&submission_counts_account, &temp_report_account));
+
+        // Once the reports are cleaned up after consensus there is nothing left to finalize
+        temp_report_account.reports.clear();
+        assert!(!pending(&submission_counts_account, &temp_report_account));
+    }
+
+    #[test]
+    fn test_submission_cap_rejects_hot_txid() {
+        let state = OracleContractState {
+            min_quorum: 3,
+            quorum_auto_scaling_enabled: true,
+            quorum_fraction: FIVE_TENTH,
+            max_submissions_per_txid: 4,
+            ..Default::default()
+        };
+        let mut submission_counts_account = TxidSubmissionCountsAccount {
+            submission_counts: Vec::new(),
+        };
+        for _ in 0..4 {
+            check_submission_cap(&state, &submission_counts_account, "abc123").unwrap();
+            submission_counts_account.record_submission("abc123", 1_000);
+        }
+        assert_eq!(
+            check_submission_cap(&state, &submission_counts_account, "abc123").unwrap_err(),
+            OracleError::TooManySubmissionsForTxid.into()
+        );
+        // Other txids are unaffected
+        assert!(check_submission_cap(&state, &submission_counts_account, "def456").is_ok());
+
+        // The scaled quorum never exceeds the cap, so a capped txid can still reach consensus
+        assert_eq!(effective_quorum(&state, 100), 4);
+
+        // A disabled cap lets the count grow, saturating rather than wrapping
+        let uncapped_state = OracleContractState {
+            max_submissions_per_txid: 0,
+            ..state
+        };
+        submission_counts_account.submission_counts[0].count = u32::MAX;
+        assert!(
+            check_submission_cap(&uncapped_state, &submission_counts_account, "abc123").is_ok()
+        );
+        submission_counts_account.record_submission("abc123", 1_000);
+        assert_eq!(
+            get_submission_count(&submission_counts_account, "abc123"),
+            u32::MAX
+        );
+    }
+
+    #[test]
+    fn test_change_reward_address_preserves_standing() {
+        let mut contributor_data = ContributorDataAccount {
+            contributors: Vec::new(),
+        };
+        let other = Pubkey::new_from_array([128; 32]);
+        let old_address = Pubkey::new_from_array([1; 32]);
+        let new_address = Pubkey::new_from_array([255; 32]);
+        for reward_address in [other, old_address] {
+            contributor_data
+                .insert_contributor(Contributor {
+                    reward_address,
+                    ..Default::default()
+                })
+                .unwrap();
+        }
+        let migrated = contributor_data.find_contributor_mut(&old_address).unwrap();
+        migrated.compliance_score = 42 * ONE;
+        migrated.reliability_score = 50 * ONE;
+        migrated.total_reports_submitted = 7;
+        migrated.accurate_reports_count = 6;
+        migrated.owed_participation_rewards_lamports = 1_000;
+
+        assert_eq!(
+            contributor_data
+                .change_reward_address(&old_address, other)
+                .unwrap_err(),
+            OracleError::ContributorAlreadyRegistered.into()
+        );
+        assert_eq!(
+            contributor_data
+                .change_reward_address(&Pubkey::new_unique(), Pubkey::new_unique())
+                .unwrap_err(),
+            OracleError::ContributorNotRegistered.into()
+        );
+
+        contributor_data
+            .change_reward_address(&old_address, new_address)
+            .unwrap();
+        assert!(contributor_data.contributors_sorted());
+        assert_eq!(contributor_data.contributors.len(), 2);
+        assert!(contributor_data.find_contributor(&old_address).is_none());
+        let migrated = contributor_data.find_contributor(&new_address).unwrap();
+        assert_eq!(migrated.compliance_score, 42 * ONE);
+        assert_eq!(migrated.reliability_score, 50 * ONE);
+        assert_eq!(migrated.total_reports_submitted, 7);
+        assert_eq!(migrated.accurate_reports_count, 6);
+        assert_eq!(migrated.owed_participation_rewards_lamports, 1_000);
+    }
+
+    #[test]
+    fn test_change_reward_address_waits_for_pending_reports() {
+        let mut temp_report_account = TempTxStatusReportAccount {
+            reports: Vec::new(),
+            common_reports: Vec::new(),
+        };
+        let mut aggregated_data_account = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        let old_address = Pubkey::new_unique();
+        submit_test_report(
+            &mut temp_report_account,
+            &mut aggregated_data_account,
+            &test_report("abc123", TxidStatus::MinedActivated, "aaaaaa", old_address),
+            ONE,
+        );
+        assert_eq!(
+            require_no_active_reports(&temp_report_account, &old_address).unwrap_err(),
+            OracleError::ReportsAwaitingConsensus.into()
+        );
+        // Another contributor's pending reports do not hold the change back
+        assert!(require_no_active_reports(&temp_report_account, &Pubkey::new_unique()).is_ok());
+
+        // Once consensus has cleared the report, the address can move
+        temp_report_account.reports.clear();
+        assert!(require_no_active_reports(&temp_report_account, &old_address).is_ok());
+    }
+
+    #[test]
+    fn test_consensus_log_line_format() {
+        assert_eq!(
+            consensus_log_line("abc123", TxidStatus::MinedActivated, "aaaaaa", 8, 1_000),
+            "CONSENSUS|abc123|MinedActivated|aaaaaa|8|1000"
+        );
+        // A missing hash leaves its field empty so the field count never changes
+        let line = consensus_log_line("abc123", TxidStatus::Invalid, "", 3, 1_000);
+        assert_eq!(line, "CONSENSUS|abc123|Invalid||3|1000");
+        assert_eq!(line.split(CONSENSUS_LOG_SEPARATOR).count(), 6);
+    }
+
+    #[test]
+    fn test_ban_clears_stale_eligibility_flag() {
+        let state = OracleContractState {
+            min_reports_for_reward: 5,
+            min_reliability_score_for_reward: 50 * ONE,
+            min_compliance_score_for_reward: 50 * ONE,
+            ..Default::default()
+        };
+        let mut contributor = Contributor {
+            reward_address: Pubkey::new_unique(),
+            compliance_score: 90 * ONE,
+            reliability_score: 90 * ONE,
+            total_reports_submitted: 10,
+            accurate_reports_count: 10,
+            last_active_timestamp: 1_000,
+            ..Default::default()
+        };
+        update_statuses(&mut contributor, &state, 1_000);
+        assert!(contributor.is_eligible_for_rewards);
+
+        // A ban clears the flag even though the scores still clear every threshold
+        contributor.ban_expiry = 2_000;
+        update_statuses(&mut contributor, &state, 1_500);
+        assert!(!contributor.is_eligible_for_rewards);
+
+        // A report arriving during the ban is skipped but still clears a stale flag
+        contributor.is_eligible_for_rewards = true;
+        update_contributor(&mut contributor, &state, 1_500, true, None, 0);
+        assert!(!contributor.is_eligible_for_rewards);
+        assert_eq!(contributor.total_reports_submitted, 10);
+
+        // Eligibility is recomputed once the ban has expired
+        update_statuses(&mut contributor, &state, 2_000);
+        assert!(contributor.is_eligible_for_rewards);
+    }
+
+    #[test]
+    fn test_split_registration_fee() {
+        assert_eq!(
+            split_registration_fee(REGISTRATION_ENTRANCE_FEE_IN_LAMPORTS, 0),
+            (REGISTRATION_ENTRANCE_FEE_IN_LAMPORTS, 0)
+        );
+        assert_eq!(
+            split_registration_fee(REGISTRATION_ENTRANCE_FEE_IN_LAMPORTS, 2_000),
+            (8_000_000, 2_000_000)
+        );
+        assert_eq!(
+            split_registration_fee(REGISTRATION_ENTRANCE_FEE_IN_LAMPORTS, MAX_FEE_SPLIT_BPS),
+            (0, REGISTRATION_ENTRANCE_FEE_IN_LAMPORTS)
+        );
+        // The treasury share rounds down, so the two shares always add back up to the fee
+        assert_eq!(split_registration_fee(999, 3_333), (667, 332));
+    }
+
+    #[test]
+    fn test_consensus_runs_once_per_slot() {
+        let mut state = OracleContractState::default();
+        let mut temp_report_account = TempTxStatusReportAccount {
+            reports: Vec::new(),
+            common_reports: Vec::new(),
+        };
+        let mut aggregated_data_account = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        let mut consensus_summary_account = ConsensusSummaryAccount::default();
+        let mut contributor_data_account = ContributorDataAccount {
+            contributors: Vec::new(),
+        };
+        for _ in 0..3 {
+            let contributor = Contributor {
+                reward_address: Pubkey::new_unique(),
+                last_active_timestamp: 1_000,
+                ..Default::default()
+            };
+            let report = test_report(
+                "abc123",
+                TxidStatus::MinedActivated,
+                "aaaaaa",
+                contributor.reward_address,
+            );
+            contributor_data_account
+                .insert_contributor(contributor)
+                .unwrap();
+            submit_test_report(
+                &mut temp_report_account,
+                &mut aggregated_data_account,
+                &report,
+                ONE,
+            );
+        }
+
+        // The submission-triggered path and finalize_consensus both fire in slot 42
+        let mut run_consensus = |slot| {
+            calculate_consensus(
+                &mut state,
+                &mut aggregated_data_account,
+                &mut consensus_summary_account,
+                &temp_report_account,
+                &mut contributor_data_account,
+                "abc123",
+                2_000,
+                slot,
+            )
+            .unwrap()
+        };
+        assert!(run_consensus(42));
+        assert!(!run_consensus(42));
+
+        assert_eq!(
+            aggregated_data_account.consensus_data[0].last_consensus_slot,
+            42
+        );
+        assert!(contributor_data_account
+            .contributors
+            .iter()
+            .all(|c| c.total_reports_submitted == 1 && c.accurate_reports_count == 1));
+    }
+
+    #[test]
+    fn test_idle_contributors_leave_the_active_count() {
+        let state = OracleContractState {
+            min_quorum: 1,
+            quorum_auto_scaling_enabled: true,
+            quorum_fraction: FIVE_TENTH,
+            contributor_idle_period: 86_400,
+            ..Default::default()
+        };
+        let mut contributor_data = ContributorDataAccount {
+            contributors: (0..4)
+                .map(|_| Contributor {
+                    reward_address: Pubkey::new_unique(),
+                    last_active_timestamp: 1_000,
+                    ..Default::default()
+                })
+                .collect(),
+        };
+        contributor_data
+            .contributors
+            .sort_by_key(|c| c.reward_address);
+        contributor_data.contributors[0].last_submission_timestamp = 50_000;
+
+        // A day later only the contributor that reported since is still active
+        let a_day_later = 1_000 + 86_400;
+        for contributor in contributor_data.contributors.iter_mut() {
+            update_statuses(contributor, &state, a_day_later);
+        }
+        assert_eq!(contributor_data.active_contributor_count(), 1);
+        assert_eq!(
+            effective_quorum(&state, contributor_data.active_contributor_count()),
+            1
+        );
+        // Deactivation is not a ban
+        assert!(contributor_data
+            .contributors
+            .iter()
+            .all(|c| !c.calculate_is_banned(a_day_later)));
+
+        // A heartbeat reactivates an idle contributor
+        record_heartbeat(&mut contributor_data.contributors[1], &state, a_day_later).unwrap();
+        assert!(!contributor_data.contributors[1].is_deactivated);
+        assert_eq!(contributor_data.active_contributor_count(), 2);
+    }
+
+    #[test]
+    fn test_injected_clock_drives_ban_expiry() {
+        let mut state = OracleContractState {
+            min_quorum: MIN_NUMBER_OF_ORACLES,
+            ..Default::default()
+        };
+        let mut submission_counts_account = TxidSubmissionCountsAccount {
+            submission_counts: Vec::new(),
+        };
+        let mut temp_report_account = TempTxStatusReportAccount {
+            reports: Vec::new(),
+            common_reports: Vec::new(),
+        };
+        let mut aggregated_data_account = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        let mut consensus_summary_account = ConsensusSummaryAccount::default();
+        let contributor = Contributor {
+            reward_address: Pubkey::new_unique(),
+            last_active_timestamp: 1_000,
+            ban_expiry: 1_000 + TEMPORARY_BAN_DURATION,
+            ..Default::default()
+        };
+        let report = test_report(
+            "abc123",
+            TxidStatus::MinedActivated,
+            "aaaaaa",
+            contributor.reward_address,
+        );
+        let mut contributor_data_account = ContributorDataAccount {
+            contributors: vec![contributor],
+        };
+        let mut submit = |unix_timestamp| {
+            set_test_clock(unix_timestamp, 1);
+            process_data_report(
+                &mut state,
+                &mut submission_counts_account,
+                &mut temp_report_account,
+                &mut contributor_data_account,
+                &mut aggregated_data_account,
+                &mut consensus_summary_account,
+                report.clone(),
+                &[0; 32],
+            )
+        };
+
+        assert_eq!(
+            submit(1_000 + TEMPORARY_BAN_DURATION - 1).unwrap_err(),
+            OracleError::ContributorBanned.into()
+        );
+        submit(1_000 + TEMPORARY_BAN_DURATION).unwrap();
+        assert_eq!(
+            contributor_data_account.contributors[0].last_submission_timestamp,
+            1_000 + TEMPORARY_BAN_DURATION
+        );
+        assert_eq!(
+            aggregated_data_account.consensus_data[0].first_seen,
+            1_000 + TEMPORARY_BAN_DURATION
+        );
+    }
+
+    #[test]
+    fn test_injected_clock_drives_score_decay() {
+        let mut state = OracleContractState {
+            min_quorum: 2,
+            compliance_weight_coefficient: ONE,
+            aggregated_data_retention_period: 86_400,
+            logistic_steepness: ONE_TENTH,
+            logistic_midpoint: MID_COMPLIANCE_SCORE,
+            ..Default::default()
+        };
+        let mut submission_counts_account = TxidSubmissionCountsAccount {
+            submission_counts: Vec::new(),
+        };
+        let mut temp_report_account = TempTxStatusReportAccount {
+            reports: Vec::new(),
+            common_reports: Vec::new(),
+        };
+        let mut aggregated_data_account = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        let mut consensus_summary_account = ConsensusSummaryAccount::default();
+        // One contributor was last active an hour before the round, the other a month before
+        let recently_active = Pubkey::new_unique();
+        let long_inactive = Pubkey::new_unique();
+        let round_time = 40 * 86_400;
+        let mut contributor_data_account = ContributorDataAccount {
+            contributors: Vec::new(),
+        };
+        for (reward_address, last_active_timestamp) in [
+            (recently_active, round_time - 3_600),
+            (long_inactive, round_time - 30 * 86_400),
+        ] {
+            contributor_data_account
+                .insert_contributor(Contributor {
+                    reward_address,
+                    compliance_score: 50 * ONE,
+                    reliability_score: ONE,
+                    last_active_timestamp,
+                    ..Default::default()
+                })
+                .unwrap();
+        }
+
+        set_test_clock(round_time, 7);
+        for reward_address in [recently_active, long_inactive] {
+            process_data_report(
+                &mut state,
+                &mut submission_counts_account,
+                &mut temp_report_account,
+                &mut contributor_data_account,
+                &mut aggregated_data_account,
+                &mut consensus_summary_account,
+                test_report(
+                    "abc123",
+                    TxidStatus::MinedActivated,
+                    "aaaaaa",
+                    reward_address,
+                ),
+                &[0; 32],
+            )
+            .unwrap();
+        }
+
+        // The second report completed the quorum, so consensus ran at the injected time and slot
+        assert_eq!(
+            aggregated_data_account.consensus_data[0].last_consensus_slot,
+            7
+        );
+        let score = |reward_address| {
+            contributor_data_account
+                .find_contributor(reward_address)
+                .unwrap()
+                .compliance_score
+        };
+        assert!(score(&long_inactive) < score(&recently_active));
+    }
+
+    #[test]
+    fn test_empty_hashes_never_match_consensus() {
+        let mut temp_report_account = TempTxStatusReportAccount {
+            reports: Vec::new(),
+            common_reports: Vec::new(),
+        };
+        let mut aggregated_data_account = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        let hashed_report = test_report(
+            "abc123",
+            TxidStatus::MinedActivated,
+            "aaaaaa",
+            Pubkey::new_unique(),
+        );
+        for _ in 0..3 {
+            submit_test_report(
+                &mut temp_report_account,
+                &mut aggregated_data_account,
+                &hashed_report,
+                ONE,
+            );
+        }
+        let mut unhashed_report = hashed_report.clone();
+        unhashed_report.first_6_characters_of_sha3_256_hash_of_corresponding_file = None;
+        let mut empty_hash_report = hashed_report.clone();
+        empty_hash_report.first_6_characters_of_sha3_256_hash_of_corresponding_file =
+            Some(String::new());
+        for report in [&unhashed_report, &empty_hash_report] {
+            submit_test_report(
+                &mut temp_report_account,
+                &mut aggregated_data_account,
+                report,
+                ONE,
+            );
+        }
+
+        // Reports without a hash neither blank the stored hash nor add an empty hash candidate
+        let aggregated_data = &aggregated_data_account.consensus_data[0];
+        assert_eq!(
+            aggregated_data.first_6_characters_of_sha3_256_hash_of_corresponding_file,
+            "aaaaaa"
+        );
+        assert_eq!(aggregated_data.hash_weights.len(), 1);
+        let (consensus_status, consensus_hash) =
+            compute_consensus(aggregated_data, TieBreakStrategy::default()).unwrap();
+        assert_eq!(consensus_hash, "aaaaaa");
+
+        let accurate = |report: &PastelTxStatusReport, consensus_hash: &str| {
+            let common_data = CommonReportData {
+                txid: report.txid.clone(),
+                txid_status: report.txid_status,
+                pastel_ticket_type: report.pastel_ticket_type,
+                first_6_characters_of_sha3_256_hash_of_corresponding_file: report
+                    .first_6_characters_of_sha3_256_hash_of_corresponding_file
+                    .clone(),
+            };
+            is_report_accurate(
+                &common_data,
+                consensus_status,
+                consensus_hash,
+                AccuracyPolicy::StatusAndHash,
+            )
+        };
+        assert!(accurate(&hashed_report, &consensus_hash));
+        assert!(!accurate(&unhashed_report, &consensus_hash));
+        assert!(!accurate(&empty_hash_report, &consensus_hash));
+
+        // Without any hashed reports the consensus hash is empty: only an omitted hash matches it
+        assert!(accurate(&unhashed_report, ""));
+        assert!(!accurate(&empty_hash_report, ""));
+    }
+
+    #[test]
+    fn test_narrow_margin_reports_runner_up() {
+        let mut temp_report_account = TempTxStatusReportAccount {
+            reports: Vec::new(),
+            common_reports: Vec::new(),
+        };
+        let mut aggregated_data_account = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        for (status, weight) in [
+            (TxidStatus::MinedActivated, 5 * ONE),
+            (TxidStatus::MinedPendingActivation, 4 * ONE),
+            (TxidStatus::PendingMining, ONE),
+        ] {
+            let report = test_report("abc123", status, "aaaaaa", Pubkey::new_unique());
+            submit_test_report(
+                &mut temp_report_account,
+                &mut aggregated_data_account,
+                &report,
+                weight,
+            );
+        }
+        let aggregated_data = &aggregated_data_account.consensus_data[0];
+        let (consensus_status, _) =
+            compute_consensus(aggregated_data, TieBreakStrategy::default()).unwrap();
+        assert_eq!(consensus_status, TxidStatus::MinedActivated);
+
+        // The runner-up trails by a fifth of the winning weight, inside a 25% margin
+        let runner_up = aggregated_data
+            .runner_up(consensus_status, 250_000_000)
+            .unwrap();
+        assert_eq!(runner_up.status, TxidStatus::MinedPendingActivation);
+        assert_eq!(
+            runner_up.weight,
+            aggregated_data.status_weights[TxidStatus::MinedPendingActivation as usize]
+        );
+
+        // A 10% margin or a disabled margin reports the winner alone
+        assert!(aggregated_data
+            .runner_up(consensus_status, ONE_TENTH)
+            .is_none());
+        assert!(aggregated_data.runner_up(consensus_status, 0).is_none());
+    }
+
+    #[test]
+    fn test_auto_pay_rewards_on_eligibility() {
+        set_test_clock(1_000, 1);
+        let mut state = OracleContractState {
+            min_reports_for_reward: 5,
+            reliability_bonus_factor: ONE,
+            auto_pay_rewards: true,
+            ..Default::default()
+        };
+        let reward_address = Pubkey::new_unique();
+        let mut contributor_data = ContributorDataAccount {
+            contributors: vec![Contributor {
+                reward_address,
+                total_reports_submitted: 4,
+                accurate_reports_count: 4,
+                ..Default::default()
+            }],
+        };
+        let eligible_before = auto_pay_snapshot(&state, &contributor_data).unwrap();
+        assert!(eligible_before.is_empty());
+
+        // The fifth accurate report carries the contributor over the eligibility threshold
+        update_contributor(
+            &mut contributor_data.contributors[0],
+            &state,
+            1_000,
+            true,
+            None,
+            0,
+        );
+        assert!(contributor_data.contributors[0].is_eligible_for_rewards);
+        assert_eq!(
+            newly_eligible_rewards(&state, &contributor_data, &eligible_before),
+            vec![(reward_address, BASE_REWARD_AMOUNT_IN_LAMPORTS)]
+        );
+
+        let reward_pool_key = Pubkey::new_unique();
+        let system_program_key = system_program::ID;
+        let native_loader_key = Pubkey::default();
+        let (mut pool_lamports, mut payee_lamports, mut program_lamports) =
+            (10 * BASE_REWARD_AMOUNT_IN_LAMPORTS, 0, 0);
+        let (mut pool_data, mut payee_data, mut program_data) = (vec![], vec![], vec![]);
+        let reward_pool_account = AccountInfo::new(
+            &reward_pool_key,
+            false,
+            true,
+            &mut pool_lamports,
+            &mut pool_data,
+            &system_program_key,
+            false,
+            0,
+        );
+        let payee_account = AccountInfo::new(
+            &reward_address,
+            true,
+            true,
+            &mut payee_lamports,
+            &mut payee_data,
+            &system_program_key,
+            false,
+            0,
+        );
+        let system_program_account = AccountInfo::new(
+            &system_program_key,
+            false,
+            false,
+            &mut program_lamports,
+            &mut program_data,
+            &native_loader_key,
+            true,
+            0,
+        );
+        auto_pay_newly_eligible(
+            &mut state,
+            &mut contributor_data,
+            &eligible_before,
+            &reward_pool_account,
+            255,
+            std::slice::from_ref(&payee_account),
+            &system_program_account,
+        )
+        .unwrap();
+        assert_eq!(payee_account.lamports(), BASE_REWARD_AMOUNT_IN_LAMPORTS);
+        assert_eq!(
+            reward_pool_account.lamports(),
+            9 * BASE_REWARD_AMOUNT_IN_LAMPORTS
+        );
+        assert_eq!(
+            state.total_rewards_paid_lamports,
+            BASE_REWARD_AMOUNT_IN_LAMPORTS
+        );
+        assert_eq!(
+            contributor_data.contributors[0].total_rewards_earned_lamports,
+            BASE_REWARD_AMOUNT_IN_LAMPORTS
+        );
+
+        // Contributors that were already eligible are left to request_reward
+        let eligible_before = auto_pay_snapshot(&state, &contributor_data).unwrap();
+        assert!(newly_eligible_rewards(&state, &contributor_data, &eligible_before).is_empty());
+
+        // Paused rewards are not auto-paid either
+        let paused_state = OracleContractState {
+            rewards_paused: true,
+            ..state.clone()
+        };
+        assert_eq!(auto_pay_snapshot(&paused_state, &contributor_data), None);
+
+        // With auto-pay off there is no snapshot, so nothing is paid
+        let state = OracleContractState {
+            auto_pay_rewards: false,
+            ..state
+        };
+        assert_eq!(auto_pay_snapshot(&state, &contributor_data), None);
+    }
+
+    #[test]
+    fn test_submit_data_report_before_initialize() {
+        set_test_clock(1_000, 1);
+        let pda = |seed: &[u8]| Pubkey::find_program_address(&[seed], &crate::ID).0;
+        let user = Pubkey::new_unique();
+        let txid = "abc123";
+        let report = test_report(txid, TxidStatus::MinedActivated, "abcdef", user);
+        let report_seed = create_seed("pastel_tx_status_report", txid, &user);
+
+        // The report account already exists, so validation reaches the constraints without a CPI
+        let mut report_account = TestAccount::program_owned(
+            pda(report_seed.as_ref()),
+            &PastelTxStatusReportAccount {
+                report: report.clone(),
+                last_submission_slot: 0,
+            },
+        );
+        report_account
+            .data
+            .resize(8 + (64 + 1 + 2 + 7 + 8 + 32 + 128), 0);
+        report_account.lamports = Rent::default().minimum_balance(report_account.data.len());
+
+        let mut user_account = TestAccount::new(user, system_program::ID, vec![]);
+        user_account.is_signer = true;
+        let mut system_program_account =
+            TestAccount::new(system_program::ID, Pubkey::default(), vec![]);
+        system_program_account.is_writable = false;
+        system_program_account.executable = true;
+
+        let mut accounts = [
+            TestAccount::program_owned(
+                pda(b"txid_submission_counts"),
+                &TxidSubmissionCountsAccount {
+                    submission_counts: Vec::new(),
+                },
+            ),
+            report_account,
+            // A state account whose initialize never completed
+            TestAccount::program_owned(Pubkey::new_unique(), &OracleContractState::default()),
+            user_account,
+            TestAccount::program_owned(
+                pda(b"temp_tx_status_report"),
+                &TempTxStatusReportAccount {
+                    reports: Vec::new(),
+                    common_reports: Vec::new(),
+                },
+            ),
+            TestAccount::program_owned(
+                pda(b"contributor_data"),
+                &ContributorDataAccount {
+                    contributors: Vec::new(),
+                },
+            ),
+            TestAccount::program_owned(
+                pda(b"aggregated_consensus_data"),
+                &AggregatedConsensusDataAccount {
+                    consensus_data: Vec::new(),
+                    ticket_type_volumes: Default::default(),
+                },
+            ),
+            TestAccount::program_owned(
+                pda(b"consensus_summary"),
+                &ConsensusSummaryAccount::default(),
+            ),
+            TestAccount::new(pda(b"reward_pool"), system_program::ID, vec![]),
+            TestAccount::new(slot_hashes::ID, Pubkey::default(), vec![]),
+            system_program_account,
+        ];
+        let account_infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+        let instruction_data = (
+            report.txid,
+            report.txid_status,
+            PastelTicketType::Sense,
+            "abcdef".to_string(),
+            user,
+        )
+            .try_to_vec()
+            .unwrap();
+
+        let result = SubmitDataReport::try_accounts(
+            &crate::ID,
+            &mut &account_infos[..],
+            &instruction_data,
+            &mut SubmitDataReportBumps::default(),
+            &mut std::collections::BTreeSet::new(),
+        );
+        assert_eq!(result.err().unwrap(), OracleError::NotInitialized.into());
+    }
+
+    #[test]
+    fn test_packed_report_is_smaller_than_per_field_arguments() {
+        let contributor = Pubkey::new_unique();
+        let txid = "ABC123".repeat(10);
+        let packed = PackedTxStatusReport {
+            txid: txid.clone(),
+            txid_status: TxidStatus::MinedActivated,
+            pastel_ticket_type: PastelTicketType::Sense,
+            first_6_characters_hash: "abcdef".to_string(),
+        };
+        let per_field_arguments = (
+            txid.clone(),
+            TxidStatus::MinedActivated,
+            PastelTicketType::Sense,
+            "abcdef".to_string(),
+            contributor,
+        );
+        assert!(
+            packed.try_to_vec().unwrap().len() < per_field_arguments.try_to_vec().unwrap().len()
+        );
+
+        // Both instructions build the same report
+        let report = packed.into_report(contributor, 1_000);
+        assert_eq!(report.txid, normalize_txid(&txid));
+        assert_eq!(report.pastel_ticket_type, Some(PastelTicketType::Sense));
+        assert_eq!(
+            report.first_6_characters_of_sha3_256_hash_of_corresponding_file,
+            Some("abcdef".to_string())
+        );
+        assert_eq!(report.timestamp, 1_000);
+        assert_eq!(report.contributor_reward_address, contributor);
+        let without_hash = PackedTxStatusReport {
+            txid,
+            txid_status: TxidStatus::PendingMining,
+            pastel_ticket_type: PastelTicketType::InferenceApi,
+            first_6_characters_hash: String::new(),
+        }
+        .into_report(contributor, 1_000);
+        assert_eq!(
+            without_hash.first_6_characters_of_sha3_256_hash_of_corresponding_file,
+            None
+        );
+    }
+
+    #[test]
+    fn test_process_payment_needs_only_the_bridge_signature() {
+        let txid = "abc123";
+        let source = Pubkey::new_unique();
+        let bridge = Pubkey::new_unique();
+        let pending_payment_key = Pubkey::find_program_address(
+            &[create_seed("pending_payment", txid, &source).as_ref()],
+            &crate::ID,
+        )
+        .0;
+
+        let mut bridge_account = TestAccount::new(bridge, system_program::ID, vec![]);
+        bridge_account.is_signer = true;
+        let mut system_program_account =
+            TestAccount::new(system_program::ID, Pubkey::default(), vec![]);
+        system_program_account.is_writable = false;
+        system_program_account.executable = true;
+        let mut accounts = [
+            // The payer the payment was recorded for does not sign
+            TestAccount::new(source, system_program::ID, vec![]),
+            bridge_account,
+            TestAccount::program_owned(
+                Pubkey::new_unique(),
+                &OracleContractState {
+                    is_initialized: true,
+                    bridge_contract_pubkeys: vec![bridge],
+                    ..Default::default()
+                },
+            ),
+            TestAccount::program_owned(
+                pending_payment_key,
+                &PendingPaymentAccount {
+                    pending_payment: PendingPayment {
+                        txid: txid.to_string(),
+                        expected_amount: 1_000,
+                        payment_status: PaymentStatus::Pending,
+                        payer: source,
+                        created_at: 0,
+                    },
+                },
+            ),
+            system_program_account,
+        ];
+        let instruction_data = (txid.to_string(), 1_000u64).try_to_vec().unwrap();
+        let try_accounts = |accounts: &mut [TestAccount]| {
+            let account_infos: Vec<AccountInfo> =
+                accounts.iter_mut().map(TestAccount::info).collect();
+            ProcessPayment::try_accounts(
+                &crate::ID,
+                &mut &account_infos[..],
+                &instruction_data,
+                &mut ProcessPaymentBumps::default(),
+                &mut std::collections::BTreeSet::new(),
+            )
+            .map(|process_payment| process_payment.caller.key())
+        };
+        assert_eq!(try_accounts(&mut accounts).unwrap(), bridge);
+
+        // The bridge's signature is still required
+        accounts[1].is_signer = false;
+        assert_eq!(
+            try_accounts(&mut accounts).err().unwrap(),
+            ErrorCode::AccountNotSigner.into()
+        );
+    }
+
+    #[test]
+    fn test_consensus_retry_hint_backs_off() {
+        assert_eq!(consensus_retry_delay(0), 0);
+        assert_eq!(consensus_retry_delay(1), CONSENSUS_RETRY_BASE_DELAY_SECONDS);
+        assert_eq!(
+            consensus_retry_delay(2),
+            2 * CONSENSUS_RETRY_BASE_DELAY_SECONDS
+        );
+        assert_eq!(
+            consensus_retry_delay(3),
+            4 * CONSENSUS_RETRY_BASE_DELAY_SECONDS
+        );
+        assert_eq!(consensus_retry_delay(8), CONSENSUS_RETRY_MAX_DELAY_SECONDS);
+        assert_eq!(
+            consensus_retry_delay(u32::MAX),
+            CONSENSUS_RETRY_MAX_DELAY_SECONDS
+        );
+
+        let mut state = OracleContractState {
+            min_quorum: MIN_NUMBER_OF_ORACLES,
+            min_consensus_weight_sum: 100 * ONE,
+            ..Default::default()
+        };
+        let mut txid_submission_counts_account = TxidSubmissionCountsAccount {
+            submission_counts: Vec::new(),
+        };
+        let mut temp_report_account = TempTxStatusReportAccount {
+            reports: Vec::new(),
+            common_reports: Vec::new(),
+        };
+        let mut aggregated_data_account = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        let mut consensus_summary_account = ConsensusSummaryAccount::default();
+        let mut contributor_data_account = ContributorDataAccount {
+            contributors: Vec::new(),
+        };
+        for _ in 0..MIN_NUMBER_OF_ORACLES {
+            let contributor = Contributor {
+                reward_address: Pubkey::new_unique(),
+                last_active_timestamp: 1_000,
+                ..Default::default()
+            };
+            let report = test_report(
+                "abc123",
+                TxidStatus::MinedActivated,
+                "aaaaaa",
+                contributor.reward_address,
+            );
+            contributor_data_account
+                .insert_contributor(contributor)
+                .unwrap();
+            txid_submission_counts_account.record_submission("abc123", 1_000);
+            submit_test_report(
+                &mut temp_report_account,
+                &mut aggregated_data_account,
+                &report,
+                ONE,
+            );
+        }
+        let readiness = |state: &OracleContractState,
+                         aggregated_data_account: &AggregatedConsensusDataAccount,
+                         current_timestamp| {
+            consensus_readiness(
+                state,
+                &txid_submission_counts_account,
+                &temp_report_account,
+                aggregated_data_account,
+                MIN_NUMBER_OF_ORACLES as usize,
+                "abc123",
+                current_timestamp,
+            )
+        };
+        let before = readiness(&state, &aggregated_data_account, 2_000);
+        assert!(!before.is_ready);
+        assert_eq!(before.submission_count, MIN_NUMBER_OF_ORACLES);
+        assert_eq!(before.next_retry_after, 0);
+
+        // Each attempt that defers for lack of weight pushes the retry hint further out
+        let mut previous_delay = 0;
+        for (attempt, current_timestamp) in [(1, 2_000), (2, 2_100), (3, 2_300)] {
+            assert!(!calculate_consensus(
+                &mut state,
+                &mut aggregated_data_account,
+                &mut consensus_summary_account,
+                &temp_report_account,
+                &mut contributor_data_account,
+                "abc123",
+                current_timestamp,
+                attempt as u64,
+            )
+            .unwrap());
+            let hint = readiness(&state, &aggregated_data_account, current_timestamp);
+            assert!(!hint.is_ready);
+            assert_eq!(hint.failed_consensus_attempts, attempt);
+            let delay = hint.next_retry_after - current_timestamp;
+            assert_eq!(delay, consensus_retry_delay(attempt));
+            assert!(delay > previous_delay);
+            previous_delay = delay;
+        }
+
+        // With enough weight the txid is ready once the hint has passed, and finalizing clears it
+        state.min_consensus_weight_sum = MIN_NUMBER_OF_ORACLES as u64 * ONE;
+        assert!(!readiness(&state, &aggregated_data_account, 2_300).is_ready);
+        assert!(readiness(&state, &aggregated_data_account, 2_300 + previous_delay).is_ready);
+        assert!(calculate_consensus(
+            &mut state,
+            &mut aggregated_data_account,
+            &mut consensus_summary_account,
+            &temp_report_account,
+            &mut contributor_data_account,
+            "abc123",
+            2_300 + previous_delay,
+            4,
+        )
+        .unwrap());
+        let data = &aggregated_data_account.consensus_data[0];
+        assert_eq!(data.failed_consensus_attempts, 0);
+        assert_eq!(data.next_retry_after, 0);
+    }
+
+    #[test]
+    fn test_report_weight_ceiling_limits_a_whale() {
+        let mut state = OracleContractState {
+            compliance_weight_coefficient: ONE,
+            ..Default::default()
+        };
+        let whale = Contributor {
+            reward_address: Pubkey::new_unique(),
+            compliance_score: 100 * ONE,
+            ..Default::default()
+        };
+        let others: Vec<Contributor> = (0..3)
+            .map(|_| Contributor {
+                reward_address: Pubkey::new_unique(),
+                compliance_score: ONE,
+                ..Default::default()
+            })
+            .collect();
+
+        let consensus_status = |state: &OracleContractState| {
+            let mut aggregated_data_account = AggregatedConsensusDataAccount {
+                consensus_data: Vec::new(),
+                ticket_type_volumes: Default::default(),
+            };
+            let reports = std::iter::once((&whale, TxidStatus::Invalid))
+                .chain(others.iter().map(|c| (c, TxidStatus::MinedActivated)));
+            for (contributor, status) in reports {
+                let report = test_report("abc123", status, "aaaaaa", contributor.reward_address);
+                aggregate_consensus_data(
+                    state,
+                    &mut aggregated_data_account,
+                    &report,
+                    contributor_report_weight(state, contributor, 1_000),
+                    "abc123",
+                    1_000,
+                )
+                .unwrap();
+            }
+            compute_consensus(
+                &aggregated_data_account.consensus_data[0],
+                TieBreakStrategy::default(),
+            )
+            .unwrap()
+            .0
+        };
+
+        // Unbounded, 100x the weight of each other contributor decides consensus alone
+        assert_eq!(consensus_status(&state), TxidStatus::Invalid);
+
+        // Capped at twice a regular contributor's weight, the whale is outvoted by the other three
+        state.max_report_weight = 2 * ONE;
+        assert_eq!(clamp_report_weight(&state, 100 * ONE), 2 * ONE);
+        assert_eq!(clamp_report_weight(&state, ONE), ONE);
+        assert_eq!(consensus_status(&state), TxidStatus::MinedActivated);
+
+        // The floor lifts a zero-weight report to a minimal voice
+        state.min_report_weight = ONE_TENTH;
+        assert_eq!(clamp_report_weight(&state, 0), ONE_TENTH);
+        assert_eq!(clamp_report_weight(&state, 100 * ONE), 2 * ONE);
+    }
+
+    #[test]
+    fn test_common_report_data_dedup_uses_full_equality() {
+        let mut temp_report_account = TempTxStatusReportAccount {
+            reports: Vec::new(),
+            common_reports: Vec::new(),
+        };
+        let common_data = |txid_status, hash: &str| CommonReportData {
+            txid: "abc123".to_string(),
+            txid_status,
+            pastel_ticket_type: Some(PastelTicketType::Sense),
+            first_6_characters_of_sha3_256_hash_of_corresponding_file: Some(hash.to_string()),
+        };
+
+        let activated = common_data(TxidStatus::MinedActivated, "aaaaaa");
+        let invalid = common_data(TxidStatus::Invalid, "aaaaaa");
+        let other_hash = common_data(TxidStatus::MinedActivated, "bbbbbb");
+        let refs: Vec<u64> = [&activated, &invalid, &other_hash, &activated]
+            .into_iter()
+            .map(|data| find_or_add_common_report_data(&mut temp_report_account, data))
+            .collect();
+
+        // Same txid with a different status or hash gets its own entry; identical data is shared
+        assert_eq!(refs, vec![0, 1, 2, 0]);
+        assert_eq!(
+            temp_report_account.common_reports,
+            vec![activated, invalid, other_hash]
+        );
+    }
+
+    #[test]
+    fn test_pda_transfer_keeps_source_rent_exempt() {
+        let rent = Rent::default();
+        let reserve = rent.minimum_balance(0);
+        let mut reward_pool = TestAccount::new(Pubkey::new_unique(), system_program::ID, vec![]);
+        reward_pool.lamports = reserve + BASE_REWARD_AMOUNT_IN_LAMPORTS;
+        let reward_pool_account = reward_pool.info();
+
+        assert_eq!(
+            transferable_lamports(&reward_pool_account, &rent),
+            BASE_REWARD_AMOUNT_IN_LAMPORTS
+        );
+        assert!(require_rent_exempt_after_transfer(
+            &reward_pool_account,
+            BASE_REWARD_AMOUNT_IN_LAMPORTS,
+            &rent
+        )
+        .is_ok());
+
+        // One lamport more would leave the pool below its rent-exempt minimum
+        assert_eq!(
+            require_rent_exempt_after_transfer(
+                &reward_pool_account,
+                BASE_REWARD_AMOUNT_IN_LAMPORTS + 1,
+                &rent
+            )
+            .unwrap_err(),
+            OracleError::InsufficientFunds.into()
+        );
+        assert_eq!(
+            require_rent_exempt_after_transfer(
+                &reward_pool_account,
+                reward_pool_account.lamports(),
+                &rent
+            )
+            .unwrap_err(),
+            OracleError::InsufficientFunds.into()
+        );
+    }
+
+    #[test]
+    fn test_consensus_summary_outlives_aggregated_data() {
+        let mut state = OracleContractState {
+            aggregated_data_retention_period: 100,
+            ..Default::default()
+        };
+        let mut temp_report_account = TempTxStatusReportAccount {
+            reports: Vec::new(),
+            common_reports: Vec::new(),
+        };
+        let mut aggregated_data_account = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        let mut consensus_summary_account = ConsensusSummaryAccount::default();
+        let mut contributor_data_account = ContributorDataAccount {
+            contributors: Vec::new(),
+        };
+        for status in [
+            TxidStatus::MinedActivated,
+            TxidStatus::MinedActivated,
+            TxidStatus::MinedActivated,
+            TxidStatus::Invalid,
+        ] {
+            let report = test_report("abc123", status, "aaaaaa", Pubkey::new_unique());
+            submit_test_report(
+                &mut temp_report_account,
+                &mut aggregated_data_account,
+                &report,
+                ONE,
+            );
+        }
+        assert!(calculate_consensus(
+            &mut state,
+            &mut aggregated_data_account,
+            &mut consensus_summary_account,
+            &temp_report_account,
+            &mut contributor_data_account,
+            "abc123",
+            1_000,
+            1,
+        )
+        .unwrap());
+
+        let expected = ConsensusSummary {
+            txid: "abc123".to_string(),
+            consensus_status: TxidStatus::MinedActivated,
+            consensus_hash: "aaaaaa".to_string(),
+            confidence: 750_000_000,
+            finalized_at: 1_000,
+        };
+        assert_eq!(
+            consensus_summary_account.find_consensus_summary("abc123"),
+            Some(&expected)
+        );
+
+        // Past the retention period the aggregated entry is pruned, but the summary remains
+        post_consensus_tasks(
+            &state,
+            &mut TxidSubmissionCountsAccount {
+                submission_counts: Vec::new(),
+            },
+            &mut aggregated_data_account,
+            &mut temp_report_account,
+            &mut contributor_data_account,
+            "abc123",
+            1_000 + state.aggregated_data_retention_period,
+        )
+        .unwrap();
+        assert!(get_aggregated_data(&aggregated_data_account, "abc123").is_none());
+        assert_eq!(
+            consensus_summary_account.find_consensus_summary("abc123"),
+            Some(&expected)
+        );
+
+        // Once full, a new txid's summary evicts the oldest one
+        for index in 1..MAX_CONSENSUS_SUMMARIES {
+            consensus_summary_account.record_consensus_summary(ConsensusSummary {
+                txid: format!("{:064x}", index),
+                finalized_at: 1_000 + index as u32,
+                ..expected.clone()
+            });
+        }
+        assert_eq!(
+            consensus_summary_account.summaries.len(),
+            MAX_CONSENSUS_SUMMARIES
+        );
+        consensus_summary_account.record_consensus_summary(ConsensusSummary {
+            txid: "def456".to_string(),
+            finalized_at: 5_000,
+            ..expected.clone()
+        });
+        assert_eq!(
+            consensus_summary_account.summaries.len(),
+            MAX_CONSENSUS_SUMMARIES
+        );
+        assert!(consensus_summary_account
+            .find_consensus_summary("abc123")
+            .is_none());
+        assert!(consensus_summary_account
+            .find_consensus_summary("def456")
+            .is_some());
+
+        // A full summary account fits in the space initialize allocates, so it never needs reallocating
+        let mut serialized = Vec::new();
+        consensus_summary_account
+            .try_serialize(&mut serialized)
+            .unwrap();
+        assert!(serialized.len() <= INITIAL_ACCOUNT_SPACE);
+    }
+
+    #[test]
+    fn test_reallocation_plan_is_all_or_nothing() {
+        let rent = Rent::default();
+        let well_funded = rent.minimum_balance(MAX_REALLOCATED_ACCOUNT_SIZE);
+        let current_size = 10_240;
+        let mut accounts = vec![
+            (
+                ReallocatableAccount::TempReportAccount,
+                current_size,
+                current_size,
+                well_funded,
+            ),
+            (
+                ReallocatableAccount::ContributorDataAccount,
+                current_size,
+                0,
+                well_funded,
+            ),
+            (
+                ReallocatableAccount::SubmissionCountsAccount,
+                current_size,
+                current_size,
+                well_funded,
+            ),
+            (
+                ReallocatableAccount::AggregatedConsensusDataAccount,
+                current_size,
+                current_size,
+                well_funded,
+            ),
+        ];
+        let grown = Some(current_size + REALLOCATION_INCREMENT);
+        assert_eq!(
+            plan_reallocations(&accounts, &rent).unwrap(),
+            vec![grown, None, grown, grown]
+        );
+
+        // The last account is already at the maximum size, so no account gets a planned size at all
+        accounts[3] = (
+            ReallocatableAccount::AggregatedConsensusDataAccount,
+            MAX_REALLOCATED_ACCOUNT_SIZE,
+            MAX_REALLOCATED_ACCOUNT_SIZE,
+            well_funded,
+        );
+        assert_eq!(
+            plan_reallocations(&accounts, &rent).unwrap_err(),
+            OracleError::AggregatedConsensusDataAccountMaxSizeExceeded.into()
+        );
+    }
+
+    #[test]
+    fn test_streak_restarts_after_decay_window() {
+        let state = OracleContractState {
+            streak_decay_window: 3_600,
+            logistic_steepness: ONE_TENTH,
+            logistic_midpoint: MID_COMPLIANCE_SCORE,
+            ..Default::default()
+        };
+        let mut contributor = Contributor {
+            reward_address: Pubkey::new_unique(),
+            compliance_score: ONE,
+            last_active_timestamp: 1_000,
+            ..Default::default()
+        };
+
+        for offset in 0..3 {
+            update_scores(&mut contributor, &state, 1_000 + offset * 600, true);
+        }
+        assert_eq!(contributor.current_streak, 3);
+
+        // Still within the window after the last activity, so the streak keeps growing
+        update_scores(&mut contributor, &state, 1_000 + 3_600, true);
+        assert_eq!(contributor.current_streak, 4);
+
+        // Past the window the streak starts again from the next accurate report
+        update_scores(&mut contributor, &state, 1_000 + 3_601, true);
+        assert_eq!(contributor.current_streak, 1);
+
+        // With the window disabled a stale streak is kept
+        let state = OracleContractState {
+            streak_decay_window: 0,
+            ..state
+        };
+        contributor.current_streak = 3;
+        update_scores(&mut contributor, &state, 1_000 + 86_400, true);
+        assert_eq!(contributor.current_streak, 4);
+    }
+
+    #[test]
+    fn test_active_aggregations_page() {
+        let mut state = OracleContractState::default();
+        let mut temp_report_account = TempTxStatusReportAccount {
+            reports: Vec::new(),
+            common_reports: Vec::new(),
+        };
+        let mut aggregated_data_account = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        let mut consensus_summary_account = ConsensusSummaryAccount::default();
+        let mut txid_submission_counts_account = TxidSubmissionCountsAccount {
+            submission_counts: Vec::new(),
+        };
+        let mut contributor_data_account = ContributorDataAccount {
+            contributors: Vec::new(),
+        };
+        let txids = ["aaa001", "aaa002", "aaa003", "aaa004"];
+        for (index, txid) in txids.iter().enumerate() {
+            for _ in 0..=index {
+                let report = test_report(
+                    txid,
+                    TxidStatus::MinedActivated,
+                    "abcdef",
+                    Pubkey::new_unique(),
+                );
+                submit_test_report(
+                    &mut temp_report_account,
+                    &mut aggregated_data_account,
+                    &report,
+                    ONE,
+                );
+                txid_submission_counts_account.record_submission(txid, 1_000);
+            }
+        }
+
+        // Finalizing one txid drops it from the active set
+        assert!(calculate_consensus(
+            &mut state,
+            &mut aggregated_data_account,
+            &mut consensus_summary_account,
+            &temp_report_account,
+            &mut contributor_data_account,
+            "aaa002",
+            1_000,
+            1,
+        )
+        .unwrap());
+
+        let first_page = active_aggregations_page(
+            &aggregated_data_account,
+            &txid_submission_counts_account,
+            0,
+            2,
+        );
+        let txids_of =
+            |page: &[ActiveAggregation]| page.iter().map(|a| a.txid.clone()).collect::<Vec<_>>();
+        assert_eq!(txids_of(&first_page), ["aaa001", "aaa003"]);
+        assert_eq!(first_page[1].submission_count, 3);
+        assert_eq!(first_page[1].total_status_weight, 3 * ONE);
+        assert_eq!(
+            first_page[1].total_status_weight,
+            get_aggregated_data(&aggregated_data_account, "aaa003")
+                .unwrap()
+                .total_weight()
+        );
+        assert!(first_page[1].total_status_weight > first_page[0].total_status_weight);
+
+        let second_page = active_aggregations_page(
+            &aggregated_data_account,
+            &txid_submission_counts_account,
+            2,
+            2,
+        );
+        assert_eq!(txids_of(&second_page), ["aaa004"]);
+        assert_eq!(second_page[0].submission_count, 4);
+        assert!(active_aggregations_page(
+            &aggregated_data_account,
+            &txid_submission_counts_account,
+            3,
+            2
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn test_near_miss_hash_is_not_penalized() {
+        let run_consensus = |near_miss_hash_prefix_length: u8| {
+            let mut state = OracleContractState {
+                near_miss_hash_prefix_length,
+                logistic_steepness: ONE_TENTH,
+                logistic_midpoint: MID_COMPLIANCE_SCORE,
+                ..Default::default()
+            };
+            let mut temp_report_account = TempTxStatusReportAccount {
+                reports: Vec::new(),
+                common_reports: Vec::new(),
+            };
+            let mut aggregated_data_account = AggregatedConsensusDataAccount {
+                consensus_data: Vec::new(),
+                ticket_type_volumes: Default::default(),
+            };
+            let mut consensus_summary_account = ConsensusSummaryAccount::default();
+            let mut contributors: Vec<Contributor> = (0..4)
+                .map(|_| Contributor {
+                    reward_address: Pubkey::new_unique(),
+                    compliance_score: 60 * ONE,
+                    total_reports_submitted: 4,
+                    accurate_reports_count: 4,
+                    current_streak: 4,
+                    last_active_timestamp: 1_000,
+                    ..Default::default()
+                })
+                .collect();
+            contributors.sort_by_key(|c| c.reward_address);
+            // The last contributor's hash agrees with consensus on 4 of 6 characters
+            for (index, contributor) in contributors.iter().enumerate() {
+                let hash = if index == 3 { "abcd12" } else { "abcdef" };
+                let report = test_report(
+                    "abc123",
+                    TxidStatus::MinedActivated,
+                    hash,
+                    contributor.reward_address,
+                );
+                submit_test_report(
+                    &mut temp_report_account,
+                    &mut aggregated_data_account,
+                    &report,
+                    ONE,
+                );
+            }
+            let near_miss_address = contributors[3].reward_address;
+            let mut contributor_data_account = ContributorDataAccount { contributors };
+            assert!(calculate_consensus(
+                &mut state,
+                &mut aggregated_data_account,
+                &mut consensus_summary_account,
+                &temp_report_account,
+                &mut contributor_data_account,
+                "abc123",
+                1_000,
+                1,
+            )
+            .unwrap());
+            contributor_data_account
+                .find_contributor_mut(&near_miss_address)
+                .unwrap()
+                .clone()
+        };
+
+        let near_miss = run_consensus(4);
+        assert_eq!(near_miss.compliance_score, 60 * ONE);
+        assert_eq!(near_miss.consensus_failures, 0);
+        assert_eq!(near_miss.current_streak, 4);
+        assert_eq!(near_miss.total_reports_submitted, 4);
+        assert_eq!(near_miss.distinct_hash_mismatches, 0);
+        assert_eq!(near_miss.recent_outcomes().count(), 0);
+
+        // A longer required prefix, or the policy disabled, scores the report as inaccurate
+        for prefix_length in [5, 0] {
+            let penalized = run_consensus(prefix_length);
+            assert!(penalized.compliance_score < 60 * ONE);
+            assert_eq!(penalized.consensus_failures, 1);
+            assert_eq!(penalized.current_streak, 0);
+            assert!(penalized
+                .recent_outcomes()
+                .map(|outcome| outcome.was_accurate)
+                .eq([false]));
+        }
+    }
+
+    #[test]
+    fn test_bridge_contract_allowlist() {
+        let admin = Pubkey::new_unique();
+        let legacy_bridge = Pubkey::new_unique();
+        let second_bridge = Pubkey::new_unique();
+        let unauthorized = Pubkey::new_unique();
+        let mut state = OracleContractState {
+            admin_pubkey: admin,
+            bridge_contract_pubkey: legacy_bridge,
+            ..Default::default()
+        };
+
+        // A bridge set before the allowlist stays authorized and is migrated on the next change
+        assert!(state.is_authorized_bridge(&legacy_bridge));
+        state.add_bridge_contract(second_bridge).unwrap();
+        assert_eq!(state.bridge_contract_pubkey, Pubkey::default());
+        assert_eq!(
+            state.bridge_contract_pubkeys,
+            [legacy_bridge, second_bridge]
+        );
+        assert!(state.is_authorized_bridge(&legacy_bridge));
+        assert!(state.is_authorized_bridge(&second_bridge));
+        assert!(!state.is_authorized_bridge(&unauthorized));
+        assert!(!state.is_authorized_bridge(&Pubkey::default()));
+
+        assert_eq!(
+            state.add_bridge_contract(admin).unwrap_err(),
+            OracleError::ConflictingRoleAssignment.into()
+        );
+        assert_eq!(
+            state.remove_bridge_contract(&unauthorized).unwrap_err(),
+            OracleError::BridgeContractNotFound.into()
+        );
+
+        state.remove_bridge_contract(&legacy_bridge).unwrap();
+        assert!(!state.is_authorized_bridge(&legacy_bridge));
+        assert!(state.is_authorized_bridge(&second_bridge));
+
+        while state.bridge_contract_pubkeys.len() < MAX_BRIDGE_CONTRACTS {
+            state.add_bridge_contract(Pubkey::new_unique()).unwrap();
+        }
+        assert_eq!(
+            state.add_bridge_contract(unauthorized).unwrap_err(),
+            OracleError::TooManyBridgeContracts.into()
+        );
+        // Re-adding an authorized bridge is a no-op, even at capacity
+        state.add_bridge_contract(second_bridge).unwrap();
+    }
+
+    #[test]
+    fn test_consensus_cool_down_collects_late_reports() {
+        let mut state = OracleContractState {
+            min_quorum: 2,
+            consensus_cool_down: 60,
+            compliance_weight_coefficient: ONE,
+            aggregated_data_retention_period: 86_400,
+            ..Default::default()
+        };
+        let mut submission_counts_account = TxidSubmissionCountsAccount {
+            submission_counts: Vec::new(),
+        };
+        let mut temp_report_account = TempTxStatusReportAccount {
+            reports: Vec::new(),
+            common_reports: Vec::new(),
+        };
+        let mut aggregated_data_account = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        let mut consensus_summary_account = ConsensusSummaryAccount::default();
+        let mut contributor_data_account = ContributorDataAccount {
+            contributors: Vec::new(),
+        };
+        let reward_addresses: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        for &reward_address in &reward_addresses {
+            contributor_data_account
+                .insert_contributor(Contributor {
+                    reward_address,
+                    compliance_score: 50 * ONE,
+                    last_active_timestamp: 1_000,
+                    ..Default::default()
+                })
+                .unwrap();
+        }
+
+        // The second report meets the quorum and the third arrives within the cool-down; each one passes the
+        // SubmitDataReport constraint first
+        for (reward_address, unix_timestamp) in reward_addresses.iter().zip([1_000, 1_000, 1_030]) {
+            set_test_clock(unix_timestamp, 1);
+            assert!(accepts_submission_now(
+                &state,
+                &submission_counts_account,
+                &temp_report_account,
+                &contributor_data_account,
+                "ABC123",
+            )
+            .unwrap());
+            process_data_report(
+                &mut state,
+                &mut submission_counts_account,
+                &mut temp_report_account,
+                &mut contributor_data_account,
+                &mut aggregated_data_account,
+                &mut consensus_summary_account,
+                test_report(
+                    "abc123",
+                    TxidStatus::MinedActivated,
+                    "aaaaaa",
+                    *reward_address,
+                ),
+                &[0; 32],
+            )
+            .unwrap();
+            assert_eq!(
+                aggregated_data_account.consensus_data[0].last_consensus_slot,
+                0
+            );
+        }
+        assert_eq!(
+            submission_counts_account
+                .find_submission_count("abc123")
+                .unwrap()
+                .quorum_reached_at,
+            1_000
+        );
+        let pending_at = |current_timestamp| {
+            is_consensus_pending(
+                &state,
+                &submission_counts_account,
+                &temp_report_account,
+                &aggregated_data_account,
+                3,
+                "abc123",
+                current_timestamp,
+            )
+        };
+        assert!(!pending_at(1_059));
+        assert!(pending_at(1_060));
+        // Once the cool-down has run out, further reports are turned away
+        let accepts_at = |current_timestamp| {
+            accepts_submission(
+                &state,
+                &submission_counts_account,
+                &temp_report_account,
+                3,
+                "abc123",
+                current_timestamp,
+            )
+        };
+        assert!(accepts_at(1_059));
+        assert!(!accepts_at(1_060));
+        // Reports already in can still be corrected during the cool-down
+        assert!(!is_consensus_finalized(&aggregated_data_account, "abc123"));
+
+        // Without a cool-down, the report that meets the quorum is the last one accepted
+        assert!(!accepts_submission(
+            &OracleContractState {
+                consensus_cool_down: 0,
+                ..state.clone()
+            },
+            &submission_counts_account,
+            &temp_report_account,
+            3,
+            "abc123",
+            1_030,
+        ));
+
+        set_test_clock(1_060, 2);
+        finalize_consensus(
+            &mut state,
+            &mut submission_counts_account,
+            &mut temp_report_account,
+            &mut contributor_data_account,
+            &mut aggregated_data_account,
+            &mut consensus_summary_account,
+            "abc123",
+            &[0; 32],
+        )
+        .unwrap();
+        assert_eq!(
+            aggregated_data_account.consensus_data[0].last_consensus_slot,
+            2
+        );
+        assert!(is_consensus_finalized(&aggregated_data_account, "abc123"));
+        // Every report, including the late one, was scored in the round
+        for reward_address in &reward_addresses {
+            let contributor = contributor_data_account
+                .find_contributor(reward_address)
+                .unwrap();
+            assert_eq!(contributor.total_reports_submitted, 1);
+        }
+    }
+
+    #[test]
+    fn test_logistic_scale_is_reproducible_and_accurate() {
+        let grid: Vec<(u64, u64, u64)> = [ONE_TENTH, 300000000, 400000000]
+            .iter()
+            .flat_map(|&steepness| {
+                [30 * ONE, MID_COMPLIANCE_SCORE, 70 * ONE]
+                    .into_iter()
+                    .flat_map(move |midpoint| {
+                        (0..=40).map(move |step| (step * 2_500000000, steepness, midpoint))
+                    })
+            })
+            .filter(|&(_, steepness, midpoint)| {
+                validate_logistic_config(steepness, midpoint).is_ok()
+            })
+            .collect();
+        let scale_grid = || {
+            grid.iter()
+                .map(|&(score, steepness, midpoint)| {
+                    logistic_scale(score, MAX_COMPLIANCE_SCORE, steepness, midpoint)
+                })
+                .collect::<Vec<u64>>()
+        };
+
+        let first_run = scale_grid();
+        assert_eq!(first_run, scale_grid());
+
+        // Within 1e-6 of the floating-point logistic curve, which the program itself no longer evaluates
+        for (&(score, steepness, midpoint), &scaled) in grid.iter().zip(&first_run) {
+            let exponent = -(steepness as f64 / 1e9) * ((score as f64 - midpoint as f64) / 1e9);
+            let reference = (MAX_COMPLIANCE_SCORE as f64 / 1e9) / (1.0 + exponent.exp());
+            let difference = (scaled as f64 / 1e9 - reference).abs();
+            assert!(
+                difference < 1e-6,
+                "logistic_scale({}, {}, {}) = {} differs from {} by {}",
+                score,
+                steepness,
+                midpoint,
+                scaled,
+                reference,
+                difference
+            );
+        }
+    }
+
+    #[test]
+    fn test_contributor_serialized_len_matches_borsh() {
+        let contributors: Vec<Contributor> = [0, 1, 64, MAX_TRANSACTION_SIGNATURE_LENGTH]
+            .iter()
+            .map(|&signature_length| Contributor {
+                reward_address: Pubkey::new_unique(),
+                registration_entrance_fee_transaction_signature: "x".repeat(signature_length),
+                compliance_score: 50 * ONE,
+                is_reliable: true,
+                ..Default::default()
+            })
+            .collect();
+        for contributor in &contributors {
+            assert_eq!(
+                contributor.serialized_len(),
+                contributor.try_to_vec().unwrap().len()
+            );
+        }
+
+        let contributor_data_account = ContributorDataAccount { contributors };
+        let serialized_contributors: usize = contributor_data_account
+            .contributors
+            .iter()
+            .map(|c| c.try_to_vec().unwrap().len())
+            .sum();
+        assert_eq!(
+            contributor_data_account_usage(&contributor_data_account),
+            serialized_contributors
+        );
+    }
+
+    #[test]
+    fn test_fresh_registrations_do_not_raise_quorum() {
+        let state = OracleContractState {
+            min_quorum: 3,
+            quorum_auto_scaling_enabled: true,
+            quorum_fraction: FIVE_TENTH,
+            contributor_activation_delay: 86_400,
+            ..Default::default()
+        };
+        let now = 10 * 86_400;
+        let mut contributor_data_account = ContributorDataAccount {
+            contributors: Vec::new(),
+        };
+        for _ in 0..10 {
+            contributor_data_account
+                .insert_contributor(Contributor {
+                    reward_address: Pubkey::new_unique(),
+                    registered_at: now - 5 * 86_400,
+                    ..Default::default()
+                })
+                .unwrap();
+        }
+        let quorum_now = |contributor_data_account: &ContributorDataAccount| {
+            effective_quorum(
+                &state,
+                contributor_data_account.mature_contributor_count(&state, now),
+            )
+        };
+        let quorum_before = quorum_now(&contributor_data_account);
+        assert_eq!(quorum_before, 5);
+
+        for _ in 0..100 {
+            contributor_data_account
+                .insert_contributor(Contributor {
+                    reward_address: Pubkey::new_unique(),
+                    registered_at: now,
+                    ..Default::default()
+                })
+                .unwrap();
+        }
+        assert_eq!(contributor_data_account.active_contributor_count(), 110);
+        assert_eq!(quorum_now(&contributor_data_account), quorum_before);
+
+        // Once the activation delay has passed they count like everyone else
+        assert_eq!(
+            effective_quorum(
+                &state,
+                contributor_data_account.mature_contributor_count(&state, now + 86_400),
+            ),
+            55
+        );
+    }
+
+    #[test]
+    fn test_report_pda_matches_independent_derivation() {
+        let contributor_reward_address = Pubkey::new_unique();
+        let txid = "ABC123def456";
+        let (report_account, bump) =
+            get_report_account_pda(&crate::ID, txid, &contributor_reward_address);
+
+        // Same preimage a client builds: preamble, lowercase txid and base58 reward address, hashed with SHA-256
+        let preimage = format!(
+            "pastel_tx_status_report{}{}",
+            txid.to_lowercase(),
+            contributor_reward_address
+        );
+        let seed_hash = anchor_lang::solana_program::hash::hash(preimage.as_bytes());
+        let expected = Pubkey::find_program_address(&[seed_hash.as_ref()], &crate::ID);
+        assert_eq!((report_account, bump), expected);
+        assert_eq!(
+            Pubkey::create_program_address(&[seed_hash.as_ref(), &[bump]], &crate::ID).unwrap(),
+            report_account
+        );
+    }
+
+    #[test]
+    fn test_stale_common_data_ref_is_a_clean_error() {
+        let mut state = OracleContractState {
+            temp_report_retention_period: 86_400,
+            ..Default::default()
+        };
+        let mut temp_report_account = TempTxStatusReportAccount {
+            reports: Vec::new(),
+            common_reports: Vec::new(),
+        };
+        let mut aggregated_data_account = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        let mut consensus_summary_account = ConsensusSummaryAccount::default();
+        let mut contributor_data_account = ContributorDataAccount {
+            contributors: Vec::new(),
+        };
+        let mut submission_counts_account = TxidSubmissionCountsAccount {
+            submission_counts: Vec::new(),
+        };
+        let report = test_report(
+            "abc123",
+            TxidStatus::MinedActivated,
+            "aaaaaa",
+            Pubkey::new_unique(),
+        );
+        submit_test_report(
+            &mut temp_report_account,
+            &mut aggregated_data_account,
+            &report,
+            ONE,
+        );
+        // A report whose shared data is gone, as after a botched migration
+        let mut stale_report = temp_report_account.reports[0].clone();
+        stale_report.common_data_ref = temp_report_account.common_reports.len() as u64;
+        temp_report_account.reports.push(stale_report.clone());
+
+        assert_eq!(
+            temp_report_account
+                .common_data_of(&stale_report)
+                .unwrap_err(),
+            OracleError::InconsistentConsensusState.into()
+        );
+        assert_eq!(
+            calculate_consensus(
+                &mut state,
+                &mut aggregated_data_account,
+                &mut consensus_summary_account,
+                &temp_report_account,
+                &mut contributor_data_account,
+                "abc123",
+                1_000,
+                1,
+            )
+            .unwrap_err(),
+            OracleError::InconsistentConsensusState.into()
+        );
+        assert_eq!(
+            post_consensus_tasks(
+                &state,
+                &mut submission_counts_account,
+                &mut aggregated_data_account,
+                &mut temp_report_account,
+                &mut contributor_data_account,
+                "abc123",
+                1_000,
+            )
+            .unwrap_err(),
+            OracleError::InconsistentConsensusState.into()
+        );
+        assert_eq!(temp_report_account.reports.len(), 2);
+    }
+
+    #[test]
+    fn test_under_reported_ticket_type_earns_boosted_participation_reward() {
+        let mut state = OracleContractState {
+            participation_reward_lamports: 1_000,
+            under_reported_volume_threshold: 3,
+            under_reported_reward_boost: 2 * ONE,
+            ..Default::default()
+        };
+        let mut temp_report_account = TempTxStatusReportAccount {
+            reports: Vec::new(),
+            common_reports: Vec::new(),
+        };
+        let mut aggregated_data_account = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        let mut consensus_summary_account = ConsensusSummaryAccount::default();
+        let mut contributors: Vec<Contributor> = (0..4)
+            .map(|_| Contributor {
+                reward_address: Pubkey::new_unique(),
+                compliance_score: 50 * ONE,
+                ..Default::default()
+            })
+            .collect();
+        contributors.sort_by_key(|c| c.reward_address);
+
+        // Every contributor reports on a Cascade txid, only the first one on a Sense txid
+        for contributor in &contributors {
+            let report = PastelTxStatusReport {
+                pastel_ticket_type: Some(PastelTicketType::Cascade),
+                ..test_report(
+                    "cascade",
+                    TxidStatus::MinedActivated,
+                    "abcdef",
+                    contributor.reward_address,
+                )
+            };
+            submit_test_report(
+                &mut temp_report_account,
+                &mut aggregated_data_account,
+                &report,
+                ONE,
+            );
+        }
+        let rare_reporter = contributors[0].reward_address;
+        let report = test_report("sense", TxidStatus::MinedActivated, "abcdef", rare_reporter);
+        submit_test_report(
+            &mut temp_report_account,
+            &mut aggregated_data_account,
+            &report,
+            ONE,
+        );
+        assert_eq!(
+            aggregated_data_account.recent_ticket_type_volume(PastelTicketType::Cascade, 1_000),
+            4
+        );
+        assert_eq!(
+            aggregated_data_account.recent_ticket_type_volume(PastelTicketType::Sense, 1_000),
+            1
+        );
+        // Volume counted in an earlier window no longer applies
+        assert_eq!(
+            aggregated_data_account.recent_ticket_type_volume(
+                PastelTicketType::Cascade,
+                1_000 + TICKET_TYPE_VOLUME_WINDOW
+            ),
+            0
+        );
+
+        let saturated_reporter = contributors[1].reward_address;
+        let mut contributor_data_account = ContributorDataAccount { contributors };
+        for txid in ["cascade", "sense"] {
+            assert!(calculate_consensus(
+                &mut state,
+                &mut aggregated_data_account,
+                &mut consensus_summary_account,
+                &temp_report_account,
+                &mut contributor_data_account,
+                txid,
+                1_000,
+                1,
+            )
+            .unwrap());
+        }
+
+        // The Sense report earns the boosted reward on top of the unboosted Cascade one
+        let owed = |address: &Pubkey, account: &mut ContributorDataAccount| {
+            account
+                .find_contributor_mut(address)
+                .unwrap()
+                .owed_participation_rewards_lamports
+        };
+        assert_eq!(
+            owed(&saturated_reporter, &mut contributor_data_account),
+            1_000
+        );
+        assert_eq!(owed(&rare_reporter, &mut contributor_data_account), 3_000);
+    }
+
+    #[test]
+    fn test_duplicate_submission_in_same_slot_is_rejected() {
+        let mut report_account = PastelTxStatusReportAccount {
+            report: test_report(
+                "abc123",
+                TxidStatus::MinedActivated,
+                "abcdef",
+                Pubkey::new_unique(),
+            ),
+            last_submission_slot: 0,
+        };
+
+        report_account.record_submission_slot(10).unwrap();
+        assert_eq!(
+            report_account.record_submission_slot(10).unwrap_err(),
+            OracleError::DuplicateReport.into()
+        );
+        assert_eq!(report_account.last_submission_slot, 10);
+
+        // A resubmission in a later slot is not a replay
+        report_account.record_submission_slot(11).unwrap();
+        assert_eq!(report_account.last_submission_slot, 11);
+    }
+
+    #[test]
+    fn test_unbanned_contributor_serves_probation() {
+        let state = OracleContractState {
+            compliance_weight_coefficient: ONE,
+            probation_period: 1_000,
+            probation_weight_factor: FIVE_TENTH,
+            ..Default::default()
+        };
+        let mut contributor = Contributor {
+            reward_address: Pubkey::new_unique(),
+            compliance_score: 60 * ONE,
+            total_reports_submitted: 20,
+            consensus_failures: 5,
+            ban_expiry: 5_000,
+            last_active_timestamp: 2_000,
+            ..Default::default()
+        };
+        let full_weight = contributor_report_weight(&state, &contributor, 2_000);
+
+        unban_contributor(&mut contributor, &state, 2_000).unwrap();
+        assert!(!contributor.calculate_is_banned(2_000));
+        assert_eq!(contributor.consensus_failures, 0);
+        assert_eq!(contributor.probation_until, 3_000);
+        assert_eq!(
+            unban_contributor(&mut contributor, &state, 2_000).unwrap_err(),
+            OracleError::ContributorNotBanned.into()
+        );
+
+        // Reduced weight while on probation, full weight once it ends
+        assert_eq!(
+            contributor_report_weight(&state, &contributor, 2_999),
+            full_weight / 2
+        );
+        assert_eq!(
+            contributor_report_weight(&state, &contributor, 3_000),
+            full_weight
+        );
+
+        // Failures on probation count double, so the temporary ban returns after three instead of five
+        for _ in 0..3 {
+            assert!(!contributor.calculate_is_banned(2_500));
+            update_scores(&mut contributor, &state, 2_500, false);
+            apply_bans(&mut contributor, 2_500, false);
+        }
+        assert_eq!(contributor.consensus_failures, 6);
+        assert!(contributor.calculate_is_banned(2_500));
+    }
+
+    #[test]
+    fn test_consensus_chain_hash_links_finalizations() {
+        let mut state = OracleContractState {
+            compliance_weight_coefficient: ONE,
+            ..Default::default()
+        };
+        let mut temp_report_account = TempTxStatusReportAccount {
+            reports: Vec::new(),
+            common_reports: Vec::new(),
+        };
+        let mut aggregated_data_account = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        let mut consensus_summary_account = ConsensusSummaryAccount::default();
+        let contributor = Contributor {
+            reward_address: Pubkey::new_unique(),
+            compliance_score: 50 * ONE,
+            ..Default::default()
+        };
+        let finalizations = [
+            ("aaa111", TxidStatus::MinedActivated, "abcdef", 1_000),
+            ("bbb222", TxidStatus::Invalid, "123456", 1_100),
+            ("ccc333", TxidStatus::PendingMining, "fedcba", 1_200),
+        ];
+        for (txid, status, hash, _) in finalizations {
+            let report = test_report(txid, status, hash, contributor.reward_address);
+            submit_test_report(
+                &mut temp_report_account,
+                &mut aggregated_data_account,
+                &report,
+                ONE,
+            );
+        }
+        let mut contributor_data_account = ContributorDataAccount {
+            contributors: vec![contributor],
+        };
+
+        let mut expected_chain_hash = [0u8; 32];
+        for (slot, (txid, status, hash, finalized_at)) in finalizations.into_iter().enumerate() {
+            assert!(calculate_consensus(
+                &mut state,
+                &mut aggregated_data_account,
+                &mut consensus_summary_account,
+                &temp_report_account,
+                &mut contributor_data_account,
+                txid,
+                finalized_at,
+                slot as u64 + 1,
+            )
+            .unwrap());
+
+            let mut preimage = expected_chain_hash.to_vec();
+            preimage.extend_from_slice(txid.as_bytes());
+            preimage.push(status as u8);
+            preimage.extend_from_slice(hash.as_bytes());
+            preimage.extend_from_slice(&finalized_at.to_le_bytes());
+            expected_chain_hash = anchor_lang::solana_program::hash::hash(&preimage).to_bytes();
+            assert_eq!(state.consensus_chain_hash, expected_chain_hash);
+        }
+
+        // Reordering the same decisions yields a different chain
+        let mut reordered = [0u8; 32];
+        for (txid, status, hash, finalized_at) in finalizations.into_iter().rev() {
+            reordered = next_consensus_chain_hash(&reordered, txid, status, hash, finalized_at);
+        }
+        assert_ne!(reordered, expected_chain_hash);
+    }
+
+    #[test]
+    fn test_pending_payment_lifecycle_follows_monitoring() {
+        let mut state = OracleContractState {
+            max_monitored_txids: 10,
+            payment_expiry_period: 1_000,
+            ..Default::default()
+        };
+        let pending_payment = |txid: &str| PendingPayment {
+            txid: txid.to_string(),
+            expected_amount: COST_IN_LAMPORTS_OF_ADDING_PASTEL_TXID_FOR_MONITORING,
+            payment_status: PaymentStatus::Pending,
+            payer: Pubkey::new_unique(),
+            created_at: 1_000,
+        };
+
+        // add -> expire -> auto-remove
+        add_monitored_txid(&mut state, "aa").unwrap();
+        let unpaid = pending_payment("aa");
+        assert_eq!(
+            expire_pending_payment(&mut state, &unpaid, 1_999).unwrap_err(),
+            OracleError::PaymentNotExpired.into()
+        );
+        expire_pending_payment(&mut state, &unpaid, 2_000).unwrap();
+        assert!(state.monitored_txids.is_empty());
+
+        // add -> pay -> remove -> close
+        add_monitored_txid(&mut state, "bb").unwrap();
+        let mut paid = pending_payment("bb");
+        paid.payment_status = PaymentStatus::Received;
+        assert_eq!(
+            expire_pending_payment(&mut state, &paid, 10_000).unwrap_err(),
+            OracleError::PaymentNotExpired.into()
+        );
+        assert_eq!(
+            remove_txid_and_resolve_payment(&mut state, "bb", Some(&pending_payment("cc")))
+                .unwrap_err(),
+            OracleError::PaymentNotFound.into()
+        );
+        assert_eq!(
+            remove_txid_and_resolve_payment(&mut state, "bb", Some(&paid)).unwrap(),
+            Some(PaymentResolution::Closed)
+        );
+        assert!(state.monitored_txids.is_empty());
+
+        // A still-pending payment is refunded when its txid is removed before expiry
+        add_monitored_txid(&mut state, "dd").unwrap();
+        assert_eq!(
+            remove_txid_and_resolve_payment(&mut state, "dd", Some(&pending_payment("dd")))
+                .unwrap(),
+            Some(PaymentResolution::Refunded)
+        );
+
+        // With expiry disabled an unpaid request never lapses
+        state.payment_expiry_period = 0;
+        assert!(!pending_payment("ee").is_expired(&state, u32::MAX));
+    }
+
+    #[test]
+    fn test_batch_register_contributors() {
+        let existing = Pubkey::new_unique();
+        let mut contributor_data_account = ContributorDataAccount {
+            contributors: vec![new_contributor(existing, String::new(), 500)],
+        };
+        let mut reward_addresses: Vec<Pubkey> = (0..10).map(|_| Pubkey::new_unique()).collect();
+
+        assert_eq!(
+            batch_register_contributors(
+                &OracleContractState::default(),
+                &mut contributor_data_account,
+                &reward_addresses,
+                1_000
+            )
+            .unwrap(),
+            10
+        );
+        assert_eq!(contributor_data_account.contributors.len(), 11);
+        assert!(contributor_data_account.contributors_sorted());
+        for reward_address in &reward_addresses {
+            let contributor = contributor_data_account
+                .find_contributor(reward_address)
+                .unwrap();
+            assert_eq!(contributor.registered_at, 1_000);
+            assert_eq!(contributor.compliance_score, ONE);
+        }
+
+        // Already registered addresses, including repeats within the batch, are skipped
+        reward_addresses.push(existing);
+        reward_addresses.push(reward_addresses[0]);
+        assert_eq!(
+            batch_register_contributors(
+                &OracleContractState::default(),
+                &mut contributor_data_account,
+                &reward_addresses,
+                2_000
+            )
+            .unwrap(),
+            0
+        );
+        assert_eq!(contributor_data_account.contributors.len(), 11);
+
+        let oversized = vec![Pubkey::new_unique(); MAX_BATCH_REGISTRATION_SIZE + 1];
+        assert_eq!(
+            batch_register_contributors(
+                &OracleContractState::default(),
+                &mut contributor_data_account,
+                &oversized,
+                3_000
+            )
+            .unwrap_err(),
+            OracleError::BatchTooLarge.into()
+        );
+    }
+
+    #[test]
+    fn test_hash_tally_weighted_independently_of_status_tally() {
+        // One heavy contributor against two light ones that disagree on both status and hash
+        let run_aggregation = |state: &OracleContractState| {
+            let mut aggregated_data_account = AggregatedConsensusDataAccount {
+                consensus_data: Vec::new(),
+                ticket_type_volumes: Default::default(),
+            };
+            let reports = [
+                (10 * ONE, TxidStatus::MinedActivated, "aaaaaa"),
+                (3 * ONE, TxidStatus::Invalid, "bbbbbb"),
+                (3 * ONE, TxidStatus::Invalid, "bbbbbb"),
+            ];
+            for (weight, status, hash) in reports {
+                let report = test_report("abc123", status, hash, Pubkey::new_unique());
+                aggregate_consensus_data(
+                    state,
+                    &mut aggregated_data_account,
+                    &report,
+                    weight,
+                    "abc123",
+                    1_000,
+                )
+                .unwrap();
+            }
+            compute_consensus(
+                &aggregated_data_account.consensus_data[0],
+                TieBreakStrategy::default(),
+            )
+            .unwrap()
+        };
+
+        let mut state = OracleContractState {
+            max_report_weight: 12 * ONE,
+            ..Default::default()
+        };
+        assert_eq!(
+            run_aggregation(&state),
+            (TxidStatus::MinedActivated, "aaaaaa".to_string())
+        );
+
+        // Tripling the hash tally pushes the heavy report into the ceiling, so the hash consensus
+        // follows the light majority while the status consensus is unchanged
+        state.hash_weight_multiplier = 3 * ONE;
+        assert_eq!(
+            run_aggregation(&state),
+            (TxidStatus::MinedActivated, "bbbbbb".to_string())
+        );
+
+        // The same asymmetry applied to the status tally flips the status instead
+        state.hash_weight_multiplier = 0;
+        state.status_weight_multiplier = 3 * ONE;
+        assert_eq!(
+            run_aggregation(&state),
+            (TxidStatus::Invalid, "aaaaaa".to_string())
+        );
+    }
+
+    #[test]
+    fn test_maximal_report_weight_aggregates_without_overflow() {
+        let state = OracleContractState {
+            compliance_weight_coefficient: MAX_WEIGHT_COEFFICIENT,
+            reliability_weight_coefficient: MAX_WEIGHT_COEFFICIENT,
+            stake_weight_coefficient: MAX_WEIGHT_COEFFICIENT,
+            early_bird_multiplier: MAX_EARLY_BIRD_MULTIPLIER,
+            early_bird_decay_period: 60,
+            status_weight_multiplier: MAX_WEIGHT_COEFFICIENT,
+            hash_weight_multiplier: MAX_WEIGHT_COEFFICIENT,
+            ..Default::default()
+        };
+        let contributor = Contributor {
+            compliance_score: MAX_COMPLIANCE_SCORE,
+            reliability_score: MAX_COMPLIANCE_SCORE,
+            stake_weight: MAX_STAKE_WEIGHT,
+            ..Default::default()
+        };
+        let weight = contributor_report_weight(&state, &contributor, 1_000);
+        let coefficient = MAX_WEIGHT_COEFFICIENT as u128 / ONE as u128;
+        let expected_weight =
+            (MAX_COMPLIANCE_SCORE as u128 * 2 + MAX_STAKE_WEIGHT as u128) * coefficient;
+        assert_eq!(weight as u128, expected_weight);
+
+        // Every report arrives when the txid is first seen, so each carries the full early-bird boost
+        // and both tally multipliers; the whole submission cap still aggregates at full precision
+        let mut aggregated_data_account = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        let submissions = DEFAULT_MAX_SUBMISSIONS_PER_TXID;
+        for _ in 0..submissions {
+            let report = test_report(
+                "abc123",
+                TxidStatus::MinedActivated,
+                "abcdef",
+                Pubkey::new_unique(),
+            );
+            aggregate_consensus_data(
+                &state,
+                &mut aggregated_data_account,
+                &report,
+                weight,
+                "abc123",
+                1_000,
+            )
+            .unwrap();
+        }
+        let scaled_report_weight = expected_weight
+            * (MAX_EARLY_BIRD_MULTIPLIER as u128 / ONE as u128)
+            * coefficient
+            * AGGREGATED_WEIGHT_SCALE as u128;
+        let aggregated_data = &aggregated_data_account.consensus_data[0];
+        assert_eq!(
+            aggregated_data.status_weights[TxidStatus::MinedActivated as usize] as u128,
+            scaled_report_weight * submissions as u128
+        );
+        assert_eq!(
+            aggregated_data.hash_weights[0].weight as u128,
+            scaled_report_weight * submissions as u128
+        );
+        assert_eq!(
+            aggregated_data.total_weight() as u128,
+            scaled_report_weight * submissions as u128 / AGGREGATED_WEIGHT_SCALE as u128
+        );
+        assert_eq!(
+            aggregated_data.status_confidence(TxidStatus::MinedActivated),
+            ONE
+        );
+
+        // An accumulation past u64::MAX is an error instead of wrapping
+        aggregated_data_account.consensus_data[0].status_weights
+            [TxidStatus::MinedActivated as usize] = u64::MAX - 1;
+        let report = test_report(
+            "abc123",
+            TxidStatus::MinedActivated,
+            "abcdef",
+            Pubkey::new_unique(),
+        );
+        assert_eq!(
+            aggregate_consensus_data(
+                &state,
+                &mut aggregated_data_account,
+                &report,
+                weight,
+                "abc123",
+                1_000,
+            )
+            .unwrap_err(),
+            OracleError::WeightOverflow.into()
+        );
+    }
+
+    #[test]
+    fn test_first_submission_waits_out_quiet_period() {
+        let mut state = OracleContractState {
+            min_quorum: 2,
+            submission_quiet_period: 3_600,
+            ..Default::default()
+        };
+        let mut submission_counts_account = TxidSubmissionCountsAccount {
+            submission_counts: Vec::new(),
+        };
+        let mut temp_report_account = TempTxStatusReportAccount {
+            reports: Vec::new(),
+            common_reports: Vec::new(),
+        };
+        let mut aggregated_data_account = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        let mut consensus_summary_account = ConsensusSummaryAccount::default();
+        let reward_address = Pubkey::new_unique();
+        let mut contributor_data_account = ContributorDataAccount {
+            contributors: vec![new_contributor(reward_address, String::new(), 1_000)],
+        };
+        let mut submit = |txid: &str, unix_timestamp: u32| {
+            set_test_clock(unix_timestamp, 1);
+            process_data_report(
+                &mut state,
+                &mut submission_counts_account,
+                &mut temp_report_account,
+                &mut contributor_data_account,
+                &mut aggregated_data_account,
+                &mut consensus_summary_account,
+                test_report(txid, TxidStatus::MinedActivated, "aaaaaa", reward_address),
+                &[0; 32],
+            )
+        };
+
+        // Registering and immediately submitting is rejected
+        assert_eq!(
+            submit("abc123", 1_000).unwrap_err(),
+            OracleError::ContributorNotYetActive.into()
+        );
+        assert_eq!(
+            submit("abc123", 4_599).unwrap_err(),
+            OracleError::ContributorNotYetActive.into()
+        );
+
+        // Once the quiet period has passed the first report is accepted, and later ones are unaffected
+        submit("abc123", 4_600).unwrap();
+        submit("def456", 4_601).unwrap();
+        assert!(!contributor_data_account
+            .find_contributor(&reward_address)
+            .unwrap()
+            .is_in_quiet_period(u32::MAX, 4_601));
+    }
+
+    #[test]
+    fn test_agreement_level_reflects_weight_distribution() {
+        let mut temp_report_account = TempTxStatusReportAccount {
+            reports: Vec::new(),
+            common_reports: Vec::new(),
+        };
+        let mut aggregated_data_account = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        for _ in 0..3 {
+            let report = test_report(
+                "abc123",
+                TxidStatus::MinedActivated,
+                "aaaaaa",
+                Pubkey::new_unique(),
+            );
+            submit_test_report(
+                &mut temp_report_account,
+                &mut aggregated_data_account,
+                &report,
+                ONE,
+            );
+        }
+        let data = &mut aggregated_data_account.consensus_data[0];
+        assert_eq!(
+            data.agreement_level(TxidStatus::MinedActivated),
+            AgreementLevel::Unanimous
+        );
+
+        let mut set_weights = |activated: u64, pending: u64, invalid: u64| {
+            data.status_weights = [0; TXID_STATUS_VARIANT_COUNT];
+            data.status_weights[TxidStatus::MinedActivated as usize] = activated;
+            data.status_weights[TxidStatus::PendingMining as usize] = pending;
+            data.status_weights[TxidStatus::Invalid as usize] = invalid;
+            data.agreement_level(TxidStatus::MinedActivated)
+        };
+        assert_eq!(set_weights(200, 100, 0), AgreementLevel::Supermajority);
+        assert_eq!(set_weights(199, 100, 0), AgreementLevel::Majority);
+        assert_eq!(set_weights(101, 100, 0), AgreementLevel::Majority);
+        assert_eq!(set_weights(100, 100, 0), AgreementLevel::Plurality);
+        assert_eq!(set_weights(100, 60, 60), AgreementLevel::Plurality);
     }
 
-    pub fn add_pending_payment(
-        ctx: Context<HandlePendingPayment>,
-        txid: String,
-        expected_amount: u64,
-        payment_status: PaymentStatus,
-    ) -> Result<()> {
-        let pending_payment = PendingPayment {
-            txid: txid.clone(),
-            expected_amount,
-            payment_status,
+    #[test]
+    fn test_sweep_orphaned_temp_reports() {
+        let mut temp_report_account = TempTxStatusReportAccount {
+            reports: Vec::new(),
+            common_reports: Vec::new(),
         };
+        let mut aggregated_data_account = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        for txid in ["abc123", "def456", "def456"] {
+            let report = test_report(
+                txid,
+                TxidStatus::MinedActivated,
+                "aaaaaa",
+                Pubkey::new_unique(),
+            );
+            submit_test_report(
+                &mut temp_report_account,
+                &mut aggregated_data_account,
+                &report,
+                ONE,
+            );
+        }
+        // def456 was finalized and its aggregated data cleaned up, but its temp reports were left behind,
+        // along with a report whose common data reference points past the end of common_reports
+        aggregated_data_account
+            .consensus_data
+            .retain(|data| data.txid != "def456");
+        let mut dangling = temp_report_account.reports[0].clone();
+        dangling.common_data_ref = temp_report_account.common_reports.len() as u64;
+        temp_report_account.reports.push(dangling);
+
+        // Nothing is swept while the orphans are still within the retention period
+        assert_eq!(
+            temp_report_account.sweep_orphaned_reports(&aggregated_data_account, 500, 1_499),
+            0
+        );
+        assert_eq!(temp_report_account.reports.len(), 4);
 
-        add_pending_payment_helper(ctx, txid, pending_payment).map_err(|e| e.into())
+        assert_eq!(
+            temp_report_account.sweep_orphaned_reports(&aggregated_data_account, 500, 1_500),
+            3
+        );
+        assert_eq!(temp_report_account.reports.len(), 1);
+        assert!(temp_report_account.reports_txid(&temp_report_account.reports[0], "abc123"));
     }
 
-    pub fn process_payment(ctx: Context<ProcessPayment>, txid: String, amount: u64) -> Result<()> {
-        process_payment_helper(ctx, txid, amount)
+    #[test]
+    fn test_reward_scaled_by_report_count() {
+        let mut state = OracleContractState {
+            reward_mode: RewardMode::ScaledByReportCount,
+            report_count_reward_cap: MAX_REPORT_COUNT_REWARD_CAP,
+            ..Default::default()
+        };
+        let payout = |state: &OracleContractState, total_reports_submitted: u32| {
+            contributor_reward_amount(
+                state,
+                &Contributor {
+                    is_eligible_for_rewards: true,
+                    total_reports_submitted,
+                    ..Default::default()
+                },
+            )
+        };
+
+        // base * (1 + ln(reports)), to within rounding
+        let payouts: Vec<u64> = [10, 100, 1_000]
+            .into_iter()
+            .map(|reports| {
+                let payout = payout(&state, reports);
+                let expected =
+                    BASE_REWARD_AMOUNT_IN_LAMPORTS as f64 * (1.0 + (reports as f64).ln());
+                assert!(
+                    (payout as f64 - expected).abs() <= 1.0,
+                    "{} reports",
+                    reports
+                );
+                payout
+            })
+            .collect();
+        // Each tenfold increase in reports adds the same ln(10) * base, so growth is sub-linear
+        assert!(payouts[0] < payouts[1] && payouts[1] < payouts[2]);
+        assert!((payouts[2] - payouts[1]).abs_diff(payouts[1] - payouts[0]) <= 1);
+
+        // The cap bounds the multiplier however many reports are submitted
+        state.report_count_reward_cap = 6 * ONE;
+        assert_eq!(payout(&state, 1_000), 6 * BASE_REWARD_AMOUNT_IN_LAMPORTS);
+        assert_eq!(payout(&state, u32::MAX), 6 * BASE_REWARD_AMOUNT_IN_LAMPORTS);
+        assert_eq!(payout(&state, 100), payouts[1]);
+
+        // Flat mode ignores the report count
+        state.reward_mode = RewardMode::Flat;
+        assert_eq!(payout(&state, 1_000), BASE_REWARD_AMOUNT_IN_LAMPORTS);
     }
 
-    pub fn submit_data_report(
-        ctx: Context<SubmitDataReport>,
-        txid: String,
-        txid_status: TxidStatus,
-        pastel_ticket_type: PastelTicketType,
-        first_6_characters_hash: String,
-        contributor_reward_address: Pubkey,
-    ) -> Result<()> {
-        msg!("In `submit_data_report` function -- Params: txid={}, txid_status={:?}, pastel_ticket_type={:?}, first_6_chars_hash={}, contributor_addr={}",
-            txid, txid_status, pastel_ticket_type, first_6_characters_hash, contributor_reward_address);
+    #[test]
+    fn test_blacklisted_address_cannot_register() {
+        let mut state = OracleContractState::default();
+        let blacklisted = Pubkey::new_unique();
+        let allowed = Pubkey::new_unique();
+        state.add_to_blacklist(blacklisted).unwrap();
+        // Blacklisting an address twice is a no-op
+        state.add_to_blacklist(blacklisted).unwrap();
+        assert_eq!(state.blacklist, vec![blacklisted]);
+
+        assert_eq!(
+            state.require_not_blacklisted(&blacklisted).unwrap_err(),
+            OracleError::AddressBlacklisted.into()
+        );
+        state.require_not_blacklisted(&allowed).unwrap();
 
-        let timestamp = Clock::get()?.unix_timestamp as u32;
+        // Batch registration skips the blacklisted address
+        let mut contributor_data_account = ContributorDataAccount {
+            contributors: Vec::new(),
+        };
+        assert_eq!(
+            batch_register_contributors(
+                &state,
+                &mut contributor_data_account,
+                &[blacklisted, allowed],
+                1_000
+            )
+            .unwrap(),
+            1
+        );
+        assert!(contributor_data_account
+            .find_contributor(&blacklisted)
+            .is_none());
+
+        // Once removed from the blacklist the address may register again
+        state.remove_from_blacklist(&blacklisted).unwrap();
+        state.require_not_blacklisted(&blacklisted).unwrap();
+        assert_eq!(
+            state.remove_from_blacklist(&blacklisted).unwrap_err(),
+            OracleError::BlacklistedAddressNotFound.into()
+        );
 
-        let report = PastelTxStatusReport {
-            txid: txid.clone(),
-            txid_status,
-            pastel_ticket_type: Some(pastel_ticket_type),
-            first_6_characters_of_sha3_256_hash_of_corresponding_file: Some(
-                first_6_characters_hash,
-            ),
-            timestamp,
-            contributor_reward_address,
+        for _ in 0..MAX_BLACKLISTED_ADDRESSES {
+            state.add_to_blacklist(Pubkey::new_unique()).unwrap();
+        }
+        assert_eq!(
+            state.add_to_blacklist(blacklisted).unwrap_err(),
+            OracleError::TooManyBlacklistedAddresses.into()
+        );
+    }
+
+    #[test]
+    fn test_finalization_bounty_paid_to_caller() {
+        set_test_clock(1_000, 1);
+        let bounty = BASE_REWARD_AMOUNT_IN_LAMPORTS / 2;
+        let mut state = OracleContractState {
+            finalization_bounty_lamports: bounty,
+            finalization_bounty_pool_reserve: BASE_REWARD_AMOUNT_IN_LAMPORTS,
+            ..Default::default()
         };
+        let rent_reserve = Rent::default().minimum_balance(0);
+        let mut reward_pool = TestAccount::new(Pubkey::new_unique(), system_program::ID, vec![]);
+        reward_pool.lamports = rent_reserve + BASE_REWARD_AMOUNT_IN_LAMPORTS + bounty;
+        let mut caller = TestAccount::new(Pubkey::new_unique(), system_program::ID, vec![]);
+        caller.lamports = 0;
+        caller.is_signer = true;
+        let mut system_program_account =
+            TestAccount::new(system_program::ID, Pubkey::default(), vec![]);
+        system_program_account.executable = true;
+        let (reward_pool, caller, system_program_account) = (
+            reward_pool.info(),
+            caller.info(),
+            system_program_account.info(),
+        );
+        let pay = |state: &OracleContractState| {
+            pay_finalization_bounty(state, &reward_pool, 255, &caller, &system_program_account)
+                .unwrap()
+        };
+
+        // The caller receives the bounty on a successful finalization
+        assert_eq!(pay(&state), bounty);
+        assert_eq!(caller.lamports(), bounty);
+        assert_eq!(
+            reward_pool.lamports(),
+            rent_reserve + BASE_REWARD_AMOUNT_IN_LAMPORTS
+        );
 
-        submit_data_report_helper(ctx, txid, report, contributor_reward_address)
+        // The pool is now at its bounty reserve, so the next finalization pays nothing
+        assert_eq!(pay(&state), 0);
+        assert_eq!(caller.lamports(), bounty);
+
+        // No bounty is paid while rewards are paused or the bounty is disabled
+        state.finalization_bounty_pool_reserve = 0;
+        state.rewards_paused = true;
+        assert_eq!(pay(&state), 0);
+        state.rewards_paused = false;
+        state.finalization_bounty_lamports = 0;
+        assert_eq!(pay(&state), 0);
+        assert_eq!(caller.lamports(), bounty);
     }
 
-    pub fn request_reward(ctx: Context<RequestReward>, contributor_address: Pubkey) -> Result<()> {
-        request_reward_helper(ctx, contributor_address)
+    #[test]
+    fn test_simulated_score_update_matches_actual_update() {
+        let state = OracleContractState {
+            min_reports_for_reward: 5,
+            min_compliance_score_for_reward: 50 * ONE,
+            min_reliability_score_for_reward: 50 * ONE,
+            logistic_steepness: ONE_TENTH,
+            logistic_midpoint: MID_COMPLIANCE_SCORE,
+            ..Default::default()
+        };
+        let contributor = Contributor {
+            reward_address: Pubkey::new_unique(),
+            compliance_score: 60 * ONE,
+            total_reports_submitted: 8,
+            accurate_reports_count: 7,
+            current_streak: 3,
+            last_active_timestamp: 1_000,
+            ..Default::default()
+        };
+
+        for would_be_accurate in [true, false] {
+            let projected = simulate_score_update(&contributor, &state, 1_600, would_be_accurate);
+
+            let mut updated = contributor.clone();
+            update_contributor(&mut updated, &state, 1_600, would_be_accurate, None, 0);
+            assert_eq!(projected.reward_address, contributor.reward_address);
+            assert_eq!(projected.would_be_accurate, would_be_accurate);
+            assert_eq!(projected.compliance_score, updated.compliance_score);
+            assert_eq!(projected.reliability_score, updated.reliability_score);
+            assert_eq!(
+                projected.is_eligible_for_rewards,
+                updated.is_eligible_for_rewards
+            );
+        }
+
+        // Simulating leaves the contributor untouched, and the two outcomes diverge
+        let accurate = simulate_score_update(&contributor, &state, 1_600, true);
+        let inaccurate = simulate_score_update(&contributor, &state, 1_600, false);
+        assert_eq!(contributor.total_reports_submitted, 8);
+        assert!(accurate.compliance_score > inaccurate.compliance_score);
+        assert!(accurate.reliability_score > inaccurate.reliability_score);
     }
 
-    pub fn set_bridge_contract(
-        ctx: Context<SetBridgeContract>,
-        bridge_contract_pubkey: Pubkey,
-    ) -> Result<()> {
-        SetBridgeContract::set_bridge_contract(ctx, bridge_contract_pubkey)
+    #[test]
+    fn test_temp_report_account_layout_has_no_dead_space() {
+        let mut temp_report_account = TempTxStatusReportAccount {
+            reports: Vec::new(),
+            common_reports: Vec::new(),
+        };
+        // An empty account is just the two vector length prefixes
+        assert_eq!(temp_report_account.try_to_vec().unwrap().len(), 8);
+
+        let mut aggregated_data_account = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        for (txid, hash) in [
+            ("abc123", "aaaaaa"),
+            ("abc123", "aaaaaa"),
+            ("def456", "bbbbbb"),
+        ] {
+            let report = test_report(txid, TxidStatus::MinedActivated, hash, Pubkey::new_unique());
+            submit_test_report(
+                &mut temp_report_account,
+                &mut aggregated_data_account,
+                &report,
+                ONE,
+            );
+        }
+        let mut without_hash = test_report(
+            "def456",
+            TxidStatus::PendingMining,
+            "",
+            Pubkey::new_unique(),
+        );
+        without_hash.first_6_characters_of_sha3_256_hash_of_corresponding_file = None;
+        without_hash.pastel_ticket_type = None;
+        submit_test_report(
+            &mut temp_report_account,
+            &mut aggregated_data_account,
+            &without_hash,
+            ONE,
+        );
+        assert_eq!(temp_report_account.reports.len(), 4);
+        assert_eq!(temp_report_account.common_reports.len(), 3);
+
+        for temp_report in &temp_report_account.reports {
+            assert_eq!(
+                temp_report.try_to_vec().unwrap().len(),
+                TempTxStatusReport::SERIALIZED_LEN
+            );
+        }
+        for common_data in &temp_report_account.common_reports {
+            assert_eq!(
+                common_data.try_to_vec().unwrap().len(),
+                common_data.serialized_len()
+            );
+        }
+        // Every serialized byte beyond the length prefixes is a report or its common data
+        assert_eq!(
+            temp_report_account.try_to_vec().unwrap().len(),
+            8 + temp_report_account_usage(&temp_report_account)
+        );
     }
 
-    pub fn withdraw_funds(
-        ctx: Context<WithdrawFunds>,
-        reward_pool_amount: u64,
-        fee_receiving_amount: u64,
-    ) -> Result<()> {
-        WithdrawFunds::execute(ctx, reward_pool_amount, fee_receiving_amount)
+    #[test]
+    fn test_submission_counts_usage_matches_serialized_len() {
+        set_test_clock(1_000, 1);
+        let mut submission_counts_account = TxidSubmissionCountsAccount {
+            submission_counts: Vec::new(),
+        };
+        // An empty account is just the vector length prefix
+        assert_eq!(submission_counts_account.try_to_vec().unwrap().len(), 4);
+
+        for txid in ["abc123", "abc123", &"f".repeat(64)] {
+            update_submission_count(&mut submission_counts_account, txid).unwrap();
+        }
+        assert_eq!(submission_counts_account.submission_counts.len(), 2);
+        for submission_count in &submission_counts_account.submission_counts {
+            assert_eq!(
+                submission_count.try_to_vec().unwrap().len(),
+                submission_count.serialized_len()
+            );
+        }
+        assert_eq!(
+            submission_counts_account.try_to_vec().unwrap().len(),
+            4 + submission_counts_account_usage(&submission_counts_account)
+        );
+    }
+
+    #[test]
+    fn test_reliability_decays_with_inactivity() {
+        let mut state = OracleContractState {
+            min_reports_for_reward: 5,
+            min_reliability_score_for_reward: 50 * ONE,
+            min_compliance_score_for_reward: 50 * ONE,
+            reliability_decay_enabled: true,
+            ..Default::default()
+        };
+        let mut contributor = Contributor {
+            reward_address: Pubkey::new_unique(),
+            compliance_score: 90 * ONE,
+            reliability_score: 100 * ONE,
+            total_reports_submitted: 10,
+            accurate_reports_count: 10,
+            last_active_timestamp: 1_000,
+            ..Default::default()
+        };
+        update_statuses(&mut contributor, &state, 1_000 + 86_399);
+        assert_eq!(contributor.reliability_score, 100 * ONE);
+        assert!(contributor.is_eligible_for_rewards);
+
+        // Thirty dormant days erode reliability to about 0.99^30, still above the threshold
+        let thirty_days_later = 1_000 + 30 * 86_400;
+        update_statuses(&mut contributor, &state, thirty_days_later);
+        let decayed = contributor.reliability_score;
+        assert!(decayed < 75 * ONE && decayed > 73 * ONE);
+        assert!(contributor.is_eligible_for_rewards);
+        // Recomputing at the same time does not compound the decay
+        update_statuses(&mut contributor, &state, thirty_days_later);
+        assert_eq!(contributor.reliability_score, decayed);
+
+        // Seventy dormant days take it below the threshold, and with it reward eligibility
+        update_statuses(&mut contributor, &state, 1_000 + 70 * 86_400);
+        assert!(contributor.reliability_score < 50 * ONE);
+        assert!(!contributor.is_eligible_for_rewards);
+
+        // A report restores it
+        contributor.last_submission_timestamp = 1_000 + 70 * 86_400;
+        update_statuses(&mut contributor, &state, 1_000 + 70 * 86_400);
+        assert_eq!(contributor.reliability_score, 100 * ONE);
+        assert!(contributor.is_eligible_for_rewards);
+
+        // With decay off, reliability is left as it was
+        state.reliability_decay_enabled = false;
+        update_statuses(&mut contributor, &state, 1_000 + 365 * 86_400);
+        assert_eq!(contributor.reliability_score, 100 * ONE);
+    }
+
+    #[test]
+    fn test_reports_counted_toward_eligibility_are_capped_per_window() {
+        let state = OracleContractState {
+            min_reports_for_reward: 20,
+            max_counted_reports_per_window: 10,
+            counted_reports_window: 3_600,
+            logistic_steepness: ONE_TENTH,
+            logistic_midpoint: MID_COMPLIANCE_SCORE,
+            ..Default::default()
+        };
+        let mut contributor = new_contributor(Pubkey::new_unique(), String::new(), 1_000);
+
+        // 100 accurate reports judged within an hour; only the first 10 advance the counters
+        for i in 0..100 {
+            update_contributor(&mut contributor, &state, 1_000 + i * 30, true, None, 0);
+        }
+        assert_eq!(contributor.total_reports_submitted, 10);
+        assert_eq!(contributor.accurate_reports_count, 10);
+        assert_eq!(contributor.report_window_count, 100);
+        assert_eq!(contributor.current_streak, 100);
+        assert!(!contributor.is_eligible_for_rewards);
+
+        // A new window counts again
+        update_contributor(&mut contributor, &state, 1_000 + 3_600, true, None, 0);
+        assert_eq!(contributor.total_reports_submitted, 11);
+        assert_eq!(contributor.report_window_count, 1);
+
+        // Without a cap every report counts
+        let uncapped = OracleContractState {
+            max_counted_reports_per_window: 0,
+            ..state
+        };
+        let mut contributor = new_contributor(Pubkey::new_unique(), String::new(), 1_000);
+        for i in 0..100 {
+            update_contributor(&mut contributor, &uncapped, 1_000 + i * 30, true, None, 0);
+        }
+        assert_eq!(contributor.total_reports_submitted, 100);
+    }
+
+    #[test]
+    fn test_zero_amount_payments_and_withdrawals_are_rejected() {
+        let mut pending_payment = PendingPayment {
+            txid: "abc123".to_string(),
+            expected_amount: 0,
+            payment_status: PaymentStatus::Pending,
+            payer: Pubkey::new_unique(),
+            created_at: 1_000,
+        };
+        // Even a zero expected amount cannot be settled by a zero payment
+        assert_eq!(
+            validate_payment_amount(&pending_payment, 0).unwrap_err(),
+            OracleError::ZeroAmount.into()
+        );
+
+        pending_payment.expected_amount = COST_IN_LAMPORTS_OF_ADDING_PASTEL_TXID_FOR_MONITORING;
+        assert_eq!(
+            validate_payment_amount(&pending_payment, 0).unwrap_err(),
+            OracleError::ZeroAmount.into()
+        );
+        assert_eq!(
+            validate_payment_amount(&pending_payment, 1).unwrap_err(),
+            OracleError::InvalidPaymentAmount.into()
+        );
+        validate_payment_amount(
+            &pending_payment,
+            COST_IN_LAMPORTS_OF_ADDING_PASTEL_TXID_FOR_MONITORING,
+        )
+        .unwrap();
+
+        assert_eq!(
+            validate_withdrawal_amounts(0, 0).unwrap_err(),
+            OracleError::ZeroAmount.into()
+        );
+        validate_withdrawal_amounts(1, 0).unwrap();
+        validate_withdrawal_amounts(0, 1).unwrap();
+        validate_withdrawal_amounts(1, 1).unwrap();
+    }
+
+    #[test]
+    fn test_aggregation_resolved_past_max_age() {
+        let mut state = OracleContractState {
+            min_quorum: 3,
+            compliance_weight_coefficient: ONE,
+            min_consensus_weight_sum: u64::MAX,
+            max_aggregation_age: 1_000,
+            aggregated_data_retention_period: 86_400,
+            ..Default::default()
+        };
+        let mut submission_counts_account = TxidSubmissionCountsAccount {
+            submission_counts: Vec::new(),
+        };
+        let mut temp_report_account = TempTxStatusReportAccount {
+            reports: Vec::new(),
+            common_reports: Vec::new(),
+        };
+        let mut aggregated_data_account = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        let mut consensus_summary_account = ConsensusSummaryAccount::default();
+        let mut contributor_data_account = ContributorDataAccount {
+            contributors: Vec::new(),
+        };
+        let weighted_contributor = Pubkey::new_unique();
+        let weightless_contributor = Pubkey::new_unique();
+        for (reward_address, compliance_score) in [
+            (weighted_contributor, 50 * ONE),
+            (weightless_contributor, 0),
+        ] {
+            contributor_data_account
+                .insert_contributor(Contributor {
+                    reward_address,
+                    compliance_score,
+                    last_active_timestamp: 1_000,
+                    ..Default::default()
+                })
+                .unwrap();
+        }
+
+        // A single report each, so neither txid ever meets the quorum or the weight floor
+        set_test_clock(1_000, 1);
+        for (txid, reward_address) in [
+            ("abc123", weighted_contributor),
+            ("def456", weightless_contributor),
+        ] {
+            process_data_report(
+                &mut state,
+                &mut submission_counts_account,
+                &mut temp_report_account,
+                &mut contributor_data_account,
+                &mut aggregated_data_account,
+                &mut consensus_summary_account,
+                test_report(txid, TxidStatus::MinedActivated, "aaaaaa", reward_address),
+                &[0; 32],
+            )
+            .unwrap();
+        }
+        let pending_at = |txid, current_timestamp| {
+            is_consensus_pending(
+                &state,
+                &submission_counts_account,
+                &temp_report_account,
+                &aggregated_data_account,
+                3,
+                txid,
+                current_timestamp,
+            )
+        };
+        assert!(!pending_at("abc123", 1_999));
+        assert!(pending_at("abc123", 2_000));
+        assert!(pending_at("def456", 2_000));
+
+        // The txid with weighted reports is force-finalized with the data it has
+        set_test_clock(2_000, 2);
+        for txid in ["abc123", "def456"] {
+            assert!(finalize_consensus(
+                &mut state,
+                &mut submission_counts_account,
+                &mut temp_report_account,
+                &mut contributor_data_account,
+                &mut aggregated_data_account,
+                &mut consensus_summary_account,
+                txid,
+                &[0; 32],
+            )
+            .unwrap());
+        }
+        let summary = consensus_summary_account
+            .find_consensus_summary("abc123")
+            .unwrap();
+        assert_eq!(summary.consensus_status, TxidStatus::MinedActivated);
+        assert_eq!(summary.finalized_at, 2_000);
+
+        // The txid with only weightless reports is invalidated instead
+        assert!(consensus_summary_account
+            .find_consensus_summary("def456")
+            .is_none());
+        assert!(get_aggregated_data(&aggregated_data_account, "def456").is_none());
+        assert!(submission_counts_account
+            .find_submission_count("def456")
+            .is_none());
+        assert!(temp_report_account.reports.is_empty());
+    }
+
+    #[test]
+    fn test_schema_version_readable_and_raised_by_migration() {
+        // A state written before versioning, with its bridge still in the legacy field
+        let legacy_bridge = Pubkey::new_unique();
+        let mut state = OracleContractState {
+            bridge_contract_pubkey: legacy_bridge,
+            ..Default::default()
+        };
+        let info = program_info(&state);
+        assert_eq!(info.schema_version, 0);
+        assert_eq!(info.current_schema_version, CURRENT_SCHEMA_VERSION);
+        assert_eq!(
+            state.require_schema_version(1).unwrap_err(),
+            OracleError::SchemaVersionTooOld.into()
+        );
+
+        assert!(state.migrate_schema());
+        assert_eq!(program_info(&state).schema_version, CURRENT_SCHEMA_VERSION);
+        assert_eq!(state.bridge_contract_pubkey, Pubkey::default());
+        assert_eq!(state.bridge_contract_pubkeys, vec![legacy_bridge]);
+        assert!(state.require_schema_version(CURRENT_SCHEMA_VERSION).is_ok());
+        assert_eq!(
+            state
+                .require_schema_version(CURRENT_SCHEMA_VERSION + 1)
+                .unwrap_err(),
+            OracleError::SchemaVersionTooOld.into()
+        );
+
+        // Migrating an up-to-date state is a no-op
+        assert!(!state.migrate_schema());
+        assert_eq!(state.schema_version, CURRENT_SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn test_migrate_schema_steps_from_previous_version() {
+        // A version 9 state whose lifetime payout total holds the stale bytes that followed the old layout
+        let mut state = OracleContractState {
+            schema_version: 9,
+            ticket_type_quorums: [5; PASTEL_TICKET_TYPE_VARIANT_COUNT],
+            recency_weight_floor: 200000000,
+            total_rewards_paid_lamports: 7,
+            ..Default::default()
+        };
+        assert!(state.migrate_schema());
+        assert_eq!(state.schema_version, CURRENT_SCHEMA_VERSION);
+        assert_eq!(state.total_rewards_paid_lamports, 0);
+        // Fields of earlier versions are left alone
+        assert_eq!(
+            state.ticket_type_quorums,
+            [5; PASTEL_TICKET_TYPE_VARIANT_COUNT]
+        );
+        assert_eq!(state.recency_weight_floor, 200000000);
+
+        // A version 10 aggregated account has its summaries moved out to the consensus summary account
+        let summary = ConsensusSummary {
+            txid: "abc123".to_string(),
+            consensus_status: TxidStatus::MinedActivated,
+            consensus_hash: "aaaaaa".to_string(),
+            confidence: ONE,
+            finalized_at: 1_000,
+        };
+        let legacy_aggregated_data = AggregatedConsensusDataAccountV10 {
+            consensus_data: Vec::new(),
+            summaries: vec![summary.clone()],
+            ticket_type_volumes: Default::default(),
+        };
+        let mut data = AggregatedConsensusDataAccount::DISCRIMINATOR.to_vec();
+        legacy_aggregated_data.serialize(&mut data).unwrap();
+        let (migrated, summaries) = migrate_aggregated_consensus_data(10, &data)
+            .unwrap()
+            .unwrap();
+        assert!(migrated.consensus_data.is_empty());
+        assert_eq!(summaries, vec![summary]);
+
+        // Layouts that have not changed since the previous version are not rewritten
+        assert!(migrate_contributor_data(10, &[]).unwrap().is_none());
+        assert!(migrate_aggregated_consensus_data(11, &[])
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_migrate_schema_rewrites_legacy_account_layouts() {
+        let reward_address = Pubkey::new_unique();
+        let legacy_contributor_data = ContributorDataAccountV5 {
+            contributors: vec![ContributorV5 {
+                reward_address,
+                registration_entrance_fee_transaction_signature: "sig".to_string(),
+                compliance_score: 42 * ONE,
+                last_active_timestamp: 1_000,
+                total_reports_submitted: 7,
+                accurate_reports_count: 6,
+                current_streak: 3,
+                reliability_score: 50 * ONE,
+                consensus_failures: 1,
+                ban_expiry: 0,
+                is_eligible_for_rewards: false,
+                is_recently_active: true,
+                is_reliable: false,
+                total_rewards_earned_lamports: 500,
+                distinct_hash_mismatches: 0,
+                stake_weight: ONE,
+                owed_participation_rewards_lamports: 0,
+                last_submission_timestamp: 1_000,
+                is_deactivated: false,
+                registered_at: 900,
+                probation_until: 0,
+                report_window_start: 1_000,
+                report_window_count: 2,
+            }],
+        };
+        let mut data = ContributorDataAccount::DISCRIMINATOR.to_vec();
+        legacy_contributor_data.serialize(&mut data).unwrap();
+        // The legacy layout does not decode as the current one
+        assert!(ContributorDataAccount::try_deserialize(&mut data.as_slice()).is_err());
+
+        let migrated = migrate_contributor_data(5, &data).unwrap().unwrap();
+        let mut migrated_data = Vec::new();
+        migrated.try_serialize(&mut migrated_data).unwrap();
+        let decoded =
+            ContributorDataAccount::try_deserialize(&mut migrated_data.as_slice()).unwrap();
+        let contributor = decoded.find_contributor(&reward_address).unwrap();
+        assert_eq!(contributor.compliance_score, 42 * ONE);
+        assert_eq!(contributor.total_reports_submitted, 7);
+        assert_eq!(contributor.report_window_count, 2);
+        assert_eq!(
+            contributor.recent_outcomes,
+            [ReportOutcome::default(); RECENT_OUTCOMES_CAPACITY]
+        );
+        assert_eq!(contributor.recent_outcomes_head, 0);
+
+        let legacy_aggregated_data = AggregatedConsensusDataAccountV2 {
+            consensus_data: vec![AggregatedConsensusDataV2 {
+                txid: "abc123".to_string(),
+                status_weights: [0, 0, 0, 300],
+                hash_weights: Vec::new(),
+                first_6_characters_of_sha3_256_hash_of_corresponding_file: "aaaaaa".to_string(),
+                last_updated: 1_000,
+                first_seen: 900,
+                status_first_seen: [0, 0, 0, 900],
+                flagged_for_audit: false,
+                last_consensus_slot: 0,
+                failed_consensus_attempts: 0,
+                next_retry_after: 0,
+            }],
+            summaries: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        let mut data = AggregatedConsensusDataAccount::DISCRIMINATOR.to_vec();
+        legacy_aggregated_data.serialize(&mut data).unwrap();
+        let (migrated, summaries) = migrate_aggregated_consensus_data(2, &data)
+            .unwrap()
+            .unwrap();
+        assert!(summaries.is_empty());
+        let mut migrated_data = Vec::new();
+        migrated.try_serialize(&mut migrated_data).unwrap();
+        let decoded =
+            AggregatedConsensusDataAccount::try_deserialize(&mut migrated_data.as_slice()).unwrap();
+        let aggregated_data = get_aggregated_data(&decoded, "abc123").unwrap();
+        assert_eq!(aggregated_data.status_weights, [0, 0, 0, 300]);
+        assert_eq!(aggregated_data.first_seen, 900);
+        assert!(aggregated_data.score_snapshots.is_empty());
+
+        // An account of another type is refused rather than misread
+        assert_eq!(
+            migrate_aggregated_consensus_data(2, &[0; 16])
+                .err()
+                .unwrap(),
+            ErrorCode::AccountDiscriminatorMismatch.into()
+        );
+    }
+
+    #[test]
+    fn test_ticket_type_quorum_overrides_global_quorum() {
+        let mut state = OracleContractState {
+            min_quorum: 3,
+            ..Default::default()
+        };
+        state.ticket_type_quorums[PastelTicketType::Nft as usize] = 5;
+        let mut submission_counts_account = TxidSubmissionCountsAccount {
+            submission_counts: Vec::new(),
+        };
+        let mut temp_report_account = TempTxStatusReportAccount {
+            reports: Vec::new(),
+            common_reports: Vec::new(),
+        };
+        let mut aggregated_data_account = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        let sense_report = test_report(
+            "abc123",
+            TxidStatus::MinedActivated,
+            "aaaaaa",
+            Pubkey::new_unique(),
+        );
+        let nft_report = PastelTxStatusReport {
+            pastel_ticket_type: Some(PastelTicketType::Nft),
+            ..test_report(
+                "def456",
+                TxidStatus::MinedActivated,
+                "aaaaaa",
+                Pubkey::new_unique(),
+            )
+        };
+        for report in [&sense_report, &nft_report] {
+            for _ in 0..3 {
+                submit_test_report(
+                    &mut temp_report_account,
+                    &mut aggregated_data_account,
+                    report,
+                    ONE,
+                );
+                submission_counts_account.record_submission(&report.txid, 1_000);
+            }
+        }
+        let ready =
+            |counts: &TxidSubmissionCountsAccount, temp: &TempTxStatusReportAccount, txid| {
+                should_calculate_consensus(&state, counts, temp, 0, txid, 1_000)
+            };
+
+        // The Sense txid falls back to the global quorum, while the NFT txid needs its own
+        assert_eq!(txid_quorum(&state, &temp_report_account, 0, "abc123"), 3);
+        assert_eq!(txid_quorum(&state, &temp_report_account, 0, "def456"), 5);
+        assert!(ready(
+            &submission_counts_account,
+            &temp_report_account,
+            "abc123"
+        ));
+        assert!(!ready(
+            &submission_counts_account,
+            &temp_report_account,
+            "def456"
+        ));
+
+        for _ in 0..2 {
+            submit_test_report(
+                &mut temp_report_account,
+                &mut aggregated_data_account,
+                &nft_report,
+                ONE,
+            );
+            submission_counts_account.record_submission("def456", 1_000);
+        }
+        assert!(ready(
+            &submission_counts_account,
+            &temp_report_account,
+            "def456"
+        ));
+    }
+
+    #[test]
+    fn test_submission_cap_respects_ticket_type_quorums() {
+        let mut state = OracleContractState {
+            min_quorum: 3,
+            ..Default::default()
+        };
+        assert!(validate_max_submissions_per_txid(&state, 3).is_ok());
+        assert_eq!(
+            validate_max_submissions_per_txid(&state, 2).unwrap_err(),
+            OracleError::InvalidConfigValue.into()
+        );
+
+        // An NFT override above the global quorum raises the lowest cap that still lets NFT txids finalize
+        state.ticket_type_quorums[PastelTicketType::Nft as usize] = 5;
+        assert_eq!(
+            validate_max_submissions_per_txid(&state, 4).unwrap_err(),
+            OracleError::InvalidConfigValue.into()
+        );
+        assert!(validate_max_submissions_per_txid(&state, 5).is_ok());
+        // Disabling the cap is always allowed
+        assert!(validate_max_submissions_per_txid(&state, 0).is_ok());
+    }
+
+    #[test]
+    fn test_reversed_consensus_restores_scores() {
+        let mut state = OracleContractState {
+            min_quorum: 3,
+            compliance_weight_coefficient: ONE,
+            aggregated_data_retention_period: 86_400,
+            ..Default::default()
+        };
+        let mut submission_counts_account = TxidSubmissionCountsAccount {
+            submission_counts: Vec::new(),
+        };
+        let mut temp_report_account = TempTxStatusReportAccount {
+            reports: Vec::new(),
+            common_reports: Vec::new(),
+        };
+        let mut aggregated_data_account = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        let mut consensus_summary_account = ConsensusSummaryAccount::default();
+        let mut contributor_data_account = ContributorDataAccount {
+            contributors: Vec::new(),
+        };
+        let reward_addresses: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        for &reward_address in &reward_addresses {
+            contributor_data_account
+                .insert_contributor(Contributor {
+                    reward_address,
+                    compliance_score: 50 * ONE,
+                    reliability_score: 80 * ONE,
+                    total_reports_submitted: 10,
+                    accurate_reports_count: 8,
+                    current_streak: 2,
+                    last_active_timestamp: 1_000,
+                    ..Default::default()
+                })
+                .unwrap();
+        }
+        let scores = |contributor_data_account: &ContributorDataAccount| -> Vec<_> {
+            reward_addresses
+                .iter()
+                .map(|reward_address| {
+                    ContributorScoreSnapshot::of(
+                        contributor_data_account
+                            .find_contributor(reward_address)
+                            .unwrap(),
+                    )
+                })
+                .collect()
+        };
+        let scores_before = scores(&contributor_data_account);
+
+        // Two contributors outweigh the honest third, and the quorum finalizes the wrong status
+        set_test_clock(1_000, 1);
+        for (reward_address, txid_status) in reward_addresses.iter().zip([
+            TxidStatus::Invalid,
+            TxidStatus::Invalid,
+            TxidStatus::MinedActivated,
+        ]) {
+            process_data_report(
+                &mut state,
+                &mut submission_counts_account,
+                &mut temp_report_account,
+                &mut contributor_data_account,
+                &mut aggregated_data_account,
+                &mut consensus_summary_account,
+                test_report("abc123", txid_status, "aaaaaa", *reward_address),
+                &[0; 32],
+            )
+            .unwrap();
+        }
+        assert_eq!(
+            consensus_summary_account
+                .find_consensus_summary("abc123")
+                .unwrap()
+                .consensus_status,
+            TxidStatus::Invalid
+        );
+        let honest = contributor_data_account
+            .find_contributor(&reward_addresses[2])
+            .unwrap();
+        assert_eq!(honest.consensus_failures, 1);
+        assert_ne!(scores(&contributor_data_account), scores_before);
+        // The snapshots count toward the account's usage at their serialized size
+        let aggregated_data = get_aggregated_data(&aggregated_data_account, "abc123").unwrap();
+        assert_eq!(aggregated_data.score_snapshots.len(), 3);
+        assert_eq!(
+            aggregated_data.serialized_len(),
+            aggregated_data.try_to_vec().unwrap().len()
+        );
+        assert_eq!(
+            aggregated_consensus_data_account_usage(&aggregated_data_account),
+            aggregated_data.serialized_len()
+        );
+
+        // Review rules the consensus wrong
+        assert_eq!(
+            reverse_consensus_effects(
+                &state,
+                &mut contributor_data_account,
+                &mut aggregated_data_account,
+                &mut consensus_summary_account,
+                "abc123",
+                1_100,
+            )
+            .unwrap(),
+            3
+        );
+        assert_eq!(scores(&contributor_data_account), scores_before);
+        assert!(consensus_summary_account
+            .find_consensus_summary("abc123")
+            .is_none());
+
+        // A round can only be reversed once
+        assert_eq!(
+            reverse_consensus_effects(
+                &state,
+                &mut contributor_data_account,
+                &mut aggregated_data_account,
+                &mut consensus_summary_account,
+                "abc123",
+                1_100,
+            )
+            .unwrap_err(),
+            OracleError::NoConsensusToReverse.into()
+        );
+    }
+
+    #[test]
+    fn test_contributor_at_active_report_cap_waits_for_consensus() {
+        let mut state = OracleContractState {
+            min_quorum: 2,
+            compliance_weight_coefficient: ONE,
+            max_active_reports_per_contributor: 2,
+            temp_report_retention_period: 86_400,
+            ..Default::default()
+        };
+        let mut submission_counts_account = TxidSubmissionCountsAccount {
+            submission_counts: Vec::new(),
+        };
+        let mut temp_report_account = TempTxStatusReportAccount {
+            reports: Vec::new(),
+            common_reports: Vec::new(),
+        };
+        let mut aggregated_data_account = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        let mut consensus_summary_account = ConsensusSummaryAccount::default();
+        let mut contributor_data_account = ContributorDataAccount {
+            contributors: Vec::new(),
+        };
+        let busy_contributor = Pubkey::new_unique();
+        let other_contributor = Pubkey::new_unique();
+        for reward_address in [busy_contributor, other_contributor] {
+            contributor_data_account
+                .insert_contributor(Contributor {
+                    reward_address,
+                    compliance_score: 50 * ONE,
+                    last_active_timestamp: 1_000,
+                    ..Default::default()
+                })
+                .unwrap();
+        }
+        set_test_clock(1_000, 1);
+        let mut submit = |txid: &str, reward_address: Pubkey| {
+            process_data_report(
+                &mut state,
+                &mut submission_counts_account,
+                &mut temp_report_account,
+                &mut contributor_data_account,
+                &mut aggregated_data_account,
+                &mut consensus_summary_account,
+                test_report(txid, TxidStatus::MinedActivated, "aaaaaa", reward_address),
+                &[0; 32],
+            )
+        };
+
+        submit("abc123", busy_contributor).unwrap();
+        submit("def456", busy_contributor).unwrap();
+        assert_eq!(
+            submit("abc789", busy_contributor).unwrap_err(),
+            OracleError::TooManyActiveReports.into()
+        );
+
+        // Another report completes the quorum for one of the txids, whose consensus frees a slot
+        submit("abc123", other_contributor).unwrap();
+        submit("abc789", busy_contributor).unwrap();
+        assert_eq!(
+            temp_report_account.active_report_count(&busy_contributor),
+            2
+        );
+    }
+
+    #[test]
+    fn test_withdrawal_rejects_same_account_for_both_pools() {
+        let (reward_pool, _) = Pubkey::find_program_address(&[b"reward_pool"], &crate::ID);
+        let (fee_receiving_contract, _) =
+            Pubkey::find_program_address(&[b"fee_receiving_contract"], &crate::ID);
+        assert!(validate_distinct_pool_accounts(&reward_pool, &fee_receiving_contract).is_ok());
+        assert_eq!(
+            validate_distinct_pool_accounts(&reward_pool, &reward_pool).unwrap_err(),
+            OracleError::DuplicatePoolAccounts.into()
+        );
+    }
+
+    #[test]
+    fn test_new_contributors_start_from_configured_compliance_score() {
+        let mut state = OracleContractState {
+            min_compliance_score_for_reward: MIN_COMPLIANCE_SCORE_FOR_REWARD,
+            ..Default::default()
+        };
+        let mut contributor_data_account = ContributorDataAccount {
+            contributors: Vec::new(),
+        };
+        let default_start = Pubkey::new_unique();
+        let head_start = Pubkey::new_unique();
+
+        batch_register_contributors(
+            &state,
+            &mut contributor_data_account,
+            &[default_start],
+            1_000,
+        )
+        .unwrap();
+        state.initial_compliance_score = 40 * ONE;
+        batch_register_contributors(&state, &mut contributor_data_account, &[head_start], 1_000)
+            .unwrap();
+
+        let compliance_score = |reward_address| {
+            contributor_data_account
+                .find_contributor(reward_address)
+                .unwrap()
+                .compliance_score
+        };
+        assert_eq!(compliance_score(&default_start), ONE);
+        assert_eq!(compliance_score(&head_start), 40 * ONE);
+        // The head start is not enough for rewards on its own
+        assert!(!contributor_data_account
+            .find_contributor(&head_start)
+            .unwrap()
+            .calculate_is_eligible_for_rewards(&state));
+
+        // An unset initial score still starts contributors at ONE, so the threshold must stay above it
+        assert!(validate_initial_compliance_score(0, ONE + 1).is_ok());
+        assert_eq!(
+            validate_initial_compliance_score(0, ONE).unwrap_err(),
+            OracleError::InvalidConfigValue.into()
+        );
+        assert_eq!(
+            validate_initial_compliance_score(40 * ONE, 40 * ONE).unwrap_err(),
+            OracleError::InvalidConfigValue.into()
+        );
+    }
+
+    #[test]
+    fn test_recent_outcomes_ring_buffer_rolls_over() {
+        let mut contributor = new_contributor(Pubkey::new_unique(), String::new(), 1_000);
+        assert_eq!(contributor.recent_outcomes().count(), 0);
+
+        let total_outcomes = RECENT_OUTCOMES_CAPACITY as u32 + 5;
+        for timestamp in 1..=total_outcomes {
+            contributor.record_outcome(timestamp, timestamp % 3 != 0);
+            if timestamp == 3 {
+                let outcomes: Vec<ReportOutcome> = contributor.recent_outcomes().copied().collect();
+                assert_eq!(
+                    outcomes,
+                    vec![
+                        ReportOutcome {
+                            timestamp: 1,
+                            was_accurate: true
+                        },
+                        ReportOutcome {
+                            timestamp: 2,
+                            was_accurate: true
+                        },
+                        ReportOutcome {
+                            timestamp: 3,
+                            was_accurate: false
+                        },
+                    ]
+                );
+            }
+        }
+
+        // Only the latest outcomes are kept, oldest first
+        let outcomes: Vec<ReportOutcome> = contributor.recent_outcomes().copied().collect();
+        assert_eq!(outcomes.len(), RECENT_OUTCOMES_CAPACITY);
+        for (outcome, timestamp) in outcomes.iter().zip(6..=total_outcomes) {
+            assert_eq!(outcome.timestamp, timestamp);
+            assert_eq!(outcome.was_accurate, timestamp % 3 != 0);
+        }
+        assert_eq!(
+            contributor.serialized_len(),
+            contributor.try_to_vec().unwrap().len()
+        );
+    }
+
+    #[test]
+    fn test_consensus_without_aggregated_data_judges_no_one() {
+        let mut state = OracleContractState {
+            min_quorum: 1,
+            ..Default::default()
+        };
+        let mut temp_report_account = TempTxStatusReportAccount {
+            reports: Vec::new(),
+            common_reports: Vec::new(),
+        };
+        let mut aggregated_data_account = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        let mut consensus_summary_account = ConsensusSummaryAccount::default();
+        let reward_address = Pubkey::new_unique();
+        let mut contributor_data_account = ContributorDataAccount {
+            contributors: vec![new_contributor(reward_address, String::new(), 1_000)],
+        };
+        submit_test_report(
+            &mut temp_report_account,
+            &mut aggregated_data_account,
+            &test_report(
+                "abc123",
+                TxidStatus::MinedActivated,
+                "aaaaaa",
+                reward_address,
+            ),
+            ONE,
+        );
+        // The aggregated entry is gone (e.g. pruned) while the temp report remains
+        aggregated_data_account.consensus_data.clear();
+        let scores_before = ContributorScoreSnapshot::of(
+            contributor_data_account
+                .find_contributor(&reward_address)
+                .unwrap(),
+        );
+
+        assert!(!calculate_consensus(
+            &mut state,
+            &mut aggregated_data_account,
+            &mut consensus_summary_account,
+            &temp_report_account,
+            &mut contributor_data_account,
+            "abc123",
+            1_060,
+            2,
+        )
+        .unwrap());
+        let contributor = contributor_data_account
+            .find_contributor(&reward_address)
+            .unwrap();
+        assert_eq!(ContributorScoreSnapshot::of(contributor), scores_before);
+        assert_eq!(contributor.recent_outcomes().count(), 0);
+        assert!(consensus_summary_account.summaries.is_empty());
+    }
+
+    #[test]
+    fn test_banned_contributor_outcome_not_recorded() {
+        let mut state = OracleContractState {
+            min_quorum: 2,
+            compliance_weight_coefficient: ONE,
+            ..Default::default()
+        };
+        let mut temp_report_account = TempTxStatusReportAccount {
+            reports: Vec::new(),
+            common_reports: Vec::new(),
+        };
+        let mut aggregated_data_account = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        let mut consensus_summary_account = ConsensusSummaryAccount::default();
+        let scored = Pubkey::new_unique();
+        let banned = Pubkey::new_unique();
+        let mut contributor_data_account = ContributorDataAccount {
+            contributors: Vec::new(),
+        };
+        for reward_address in [scored, banned] {
+            contributor_data_account
+                .insert_contributor(new_contributor(reward_address, String::new(), 1_000))
+                .unwrap();
+            submit_test_report(
+                &mut temp_report_account,
+                &mut aggregated_data_account,
+                &test_report(
+                    "abc123",
+                    TxidStatus::MinedActivated,
+                    "aaaaaa",
+                    reward_address,
+                ),
+                ONE,
+            );
+        }
+        // Banned by another txid's consensus after reporting on this one
+        contributor_data_account
+            .find_contributor_mut(&banned)
+            .unwrap()
+            .ban_expiry = 2_000;
+
+        set_test_clock(1_060, 2);
+        assert!(calculate_consensus(
+            &mut state,
+            &mut aggregated_data_account,
+            &mut consensus_summary_account,
+            &temp_report_account,
+            &mut contributor_data_account,
+            "abc123",
+            1_060,
+            2,
+        )
+        .unwrap());
+        let recent_outcomes = |reward_address| {
+            contributor_data_account
+                .find_contributor(reward_address)
+                .unwrap()
+                .recent_outcomes()
+                .count()
+        };
+        assert_eq!(recent_outcomes(&scored), 1);
+        assert_eq!(recent_outcomes(&banned), 0);
+    }
+
+    #[test]
+    fn test_submission_fee_collected_into_reward_pool() {
+        let mut state = OracleContractState::default();
+        let mut submitter = TestAccount::new(Pubkey::new_unique(), system_program::ID, vec![]);
+        submitter.lamports = BASE_REWARD_AMOUNT_IN_LAMPORTS;
+        submitter.is_signer = true;
+        let mut reward_pool = TestAccount::new(Pubkey::new_unique(), system_program::ID, vec![]);
+        reward_pool.lamports = 0;
+        let mut system_program_account =
+            TestAccount::new(system_program::ID, Pubkey::default(), vec![]);
+        system_program_account.executable = true;
+        let (submitter, reward_pool, system_program_account) = (
+            submitter.info(),
+            reward_pool.info(),
+            system_program_account.info(),
+        );
+
+        // Submitting stays free while no fee is configured
+        assert_eq!(
+            collect_submission_fee(
+                &mut state,
+                &submitter,
+                &reward_pool,
+                &system_program_account
+            )
+            .unwrap(),
+            0
+        );
+        assert_eq!(submitter.lamports(), BASE_REWARD_AMOUNT_IN_LAMPORTS);
+        assert_eq!(reward_pool.lamports(), 0);
+
+        let fee = BASE_REWARD_AMOUNT_IN_LAMPORTS / 10;
+        state.submission_fee_lamports = fee;
+        for _ in 0..2 {
+            assert_eq!(
+                collect_submission_fee(
+                    &mut state,
+                    &submitter,
+                    &reward_pool,
+                    &system_program_account
+                )
+                .unwrap(),
+                fee
+            );
+        }
+        assert_eq!(
+            submitter.lamports(),
+            BASE_REWARD_AMOUNT_IN_LAMPORTS - 2 * fee
+        );
+        assert_eq!(reward_pool.lamports(), 2 * fee);
+        assert_eq!(state.total_submission_fees_lamports, 2 * fee);
+    }
+
+    #[test]
+    fn test_reward_economics_reflect_registrations_fundings_and_payouts() {
+        let mut state = OracleContractState {
+            submission_fee_lamports: 1_000,
+            ..Default::default()
+        };
+        let mut contributor_data_account = ContributorDataAccount {
+            contributors: Vec::new(),
+        };
+        for _ in 0..2 {
+            contributor_data_account
+                .insert_contributor(new_contributor(Pubkey::new_unique(), String::new(), 1_000))
+                .unwrap();
+            state.record_registration_fee(REGISTRATION_ENTRANCE_FEE_IN_LAMPORTS);
+        }
+        state.record_pool_funding(5 * BASE_REWARD_AMOUNT_IN_LAMPORTS);
+        state.total_submission_fees_lamports = 3 * state.submission_fee_lamports;
+        state.record_reward_payout(BASE_REWARD_AMOUNT_IN_LAMPORTS);
+        state.record_reward_payout(2 * BASE_REWARD_AMOUNT_IN_LAMPORTS);
+        // A contributor removed by a permanent ban takes its own total along, but not the lifetime one
+        contributor_data_account.contributors.remove(1);
+
+        let reserve = Rent::default().minimum_balance(0);
+        let reward_pool_balance = reserve + 2 * BASE_REWARD_AMOUNT_IN_LAMPORTS + 1;
+        let economics = reward_economics(&state, reward_pool_balance, reserve);
+        assert_eq!(economics.reward_pool_balance, reward_pool_balance);
+        assert_eq!(economics.reward_amount, BASE_REWARD_AMOUNT_IN_LAMPORTS);
+        assert_eq!(economics.remaining_claims, 2);
+        assert_eq!(
+            economics.total_rewards_paid_lamports,
+            3 * BASE_REWARD_AMOUNT_IN_LAMPORTS
+        );
+        assert_eq!(
+            economics.total_pool_funding_lamports,
+            5 * BASE_REWARD_AMOUNT_IN_LAMPORTS
+        );
+        assert_eq!(
+            economics.total_registration_fees_lamports,
+            2 * REGISTRATION_ENTRANCE_FEE_IN_LAMPORTS
+        );
+        assert_eq!(economics.total_submission_fees_lamports, 3_000);
+        assert_eq!(
+            economics.total_fees_collected_lamports,
+            2 * REGISTRATION_ENTRANCE_FEE_IN_LAMPORTS + 3_000
+        );
+    }
+
+    #[test]
+    fn test_recency_decay_lets_later_reports_outweigh_earlier_ones() {
+        let state = OracleContractState {
+            recency_weight_floor: 200000000, // 0.2
+            recency_ramp_period: 600,
+            ..Default::default()
+        };
+        let mut aggregated_data_account = AggregatedConsensusDataAccount {
+            consensus_data: Vec::new(),
+            ticket_type_volumes: Default::default(),
+        };
+        let weight = 100_000000000;
+        let first_seen = 10_000;
+        let mut submit = |status: TxidStatus, timestamp: u32| {
+            let report = test_report("abc123", status, "aaaaaa", Pubkey::new_unique());
+            aggregate_consensus_data(
+                &state,
+                &mut aggregated_data_account,
+                &report,
+                weight,
+                "abc123",
+                timestamp,
+            )
+            .unwrap()
+        };
+
+        // Three early PendingMining reports, then two MinedActivated ones once the txid has matured
+        let pending_weights: Vec<u64> = (0..3)
+            .map(|i| submit(TxidStatus::PendingMining, first_seen + i * 60))
+            .collect();
+        let mined_weights: Vec<u64> = (0..2)
+            .map(|i| submit(TxidStatus::MinedActivated, first_seen + 600 + i * 60))
+            .collect();
+
+        assert_eq!(pending_weights[0], 20_000000000);
+        assert_eq!(pending_weights[2], 36_000000000); // 0.2 + 0.8 * (120 / 600)
+        assert_eq!(mined_weights, vec![weight, weight]);
+
+        let data = &aggregated_data_account.consensus_data[0];
+        assert!(
+            data.status_weights[TxidStatus::MinedActivated as usize]
+                > data.status_weights[TxidStatus::PendingMining as usize]
+        );
+        assert_eq!(
+            compute_consensus(data, state.tie_break_strategy).map(|(status, _)| status),
+            Some(TxidStatus::MinedActivated)
+        );
+
+        // With the default config every report keeps its base weight
+        assert_eq!(
+            recency_weight_multiplier(&OracleContractState::default(), 0),
+            ONE
+        );
     }
 }
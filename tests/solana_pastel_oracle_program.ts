@@ -16,6 +16,7 @@ anchor.setProvider(provider);
 const program = new Program<SolanaPastelOracleProgram>(IDL as any, provider);
 const admin = provider.wallet; // Use the provider's wallet
 const oracleContractState = web3.Keypair.generate();
+const bridgeContract = web3.Keypair.generate(); // Must be distinct from the admin
 let contributors = []; // Array to store contributor keypairs
 let trackedTxids = []; // Initialize an empty array to track TXIDs
 
@@ -87,6 +88,80 @@ const measureComputeUnitsAndStorage = async (txSignature: string) => {
 };
 
 describe("Initialization", () => {
+  it("Rejects an underfunded payer before creating any account", async () => {
+    const pdaSeeds = [
+      "temp_tx_status_report",
+      "contributor_data",
+      "txid_submission_counts",
+      "aggregated_consensus_data",
+      "consensus_summary",
+      "admin_audit_log",
+    ];
+    const [
+      tempReportAccountPDA,
+      contributorDataAccountPDA,
+      txidSubmissionCountsAccountPDA,
+      aggregatedConsensusDataAccountPDA,
+      consensusSummaryAccountPDA,
+      adminAuditLogPDA,
+    ] = pdaSeeds.map(
+      (seed) =>
+        web3.PublicKey.findProgramAddressSync(
+          [Buffer.from(seed)],
+          program.programId
+        )[0]
+    );
+    const underfundedPayer = web3.Keypair.generate();
+    const underfundedState = web3.Keypair.generate();
+
+    // Enough for one 10KB account, but not for all seven created by initialize
+    const fundTx = new anchor.web3.Transaction().add(
+      anchor.web3.SystemProgram.transfer({
+        fromPubkey: admin.publicKey,
+        toPubkey: underfundedPayer.publicKey,
+        lamports:
+          await provider.connection.getMinimumBalanceForRentExemption(10_240),
+      })
+    );
+    const fundTxSignature = await provider.sendAndConfirm(fundTx);
+    await measureComputeUnitsAndStorage(fundTxSignature);
+
+    try {
+      await program.methods
+        .initialize()
+        .accountsStrict({
+          payer: { user: underfundedPayer.publicKey },
+          oracleContractState: underfundedState.publicKey,
+          contributorDataAccount: contributorDataAccountPDA,
+          tempReportAccount: tempReportAccountPDA,
+          txidSubmissionCountsAccount: txidSubmissionCountsAccountPDA,
+          aggregatedConsensusDataAccount: aggregatedConsensusDataAccountPDA,
+          consensusSummaryAccount: consensusSummaryAccountPDA,
+          adminAuditLog: adminAuditLogPDA,
+          systemProgram: web3.SystemProgram.programId,
+        })
+        .signers([underfundedPayer, underfundedState])
+        .rpc();
+      assert.fail("Initialization with an underfunded payer should fail");
+    } catch (error) {
+      const anchorError = anchor.AnchorError.parse(error.logs);
+      assert.isNotNull(anchorError, `Unexpected error: ${error.toString()}`);
+      assert.equal(anchorError.error.errorCode.code, "InsufficientFunds");
+    }
+
+    for (const account of [
+      underfundedState.publicKey,
+      tempReportAccountPDA,
+      contributorDataAccountPDA,
+      txidSubmissionCountsAccountPDA,
+      aggregatedConsensusDataAccountPDA,
+      consensusSummaryAccountPDA,
+      adminAuditLogPDA,
+    ]) {
+      assert.isNull(await provider.connection.getAccountInfo(account));
+    }
+  });
+
   it("Initializes and expands the oracle contract state", async () => {
     // Find the PDAs for the RewardPoolAccount and FeeReceivingContractAccount
     const [rewardPoolAccountPDA] = web3.PublicKey.findProgramAddressSync(
@@ -119,12 +194,24 @@ describe("Initialization", () => {
         program.programId
       );
 
+    // Find the PDA for the ConsensusSummaryAccount
+    const [consensusSummaryAccountPDA] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("consensus_summary")],
+      program.programId
+    );
+
     // Find the PDA for the TempTxStatusReportAccount
     const [tempReportAccountPDA] = web3.PublicKey.findProgramAddressSync(
       [Buffer.from("temp_tx_status_report")],
       program.programId
     );
 
+    // Find the PDA for the AdminAuditLogAccount
+    const [adminAuditLogPDA] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("admin_audit_log")],
+      program.programId
+    );
+
     // Calculate the rent-exempt minimum balance for the account size
     const minBalanceForRentExemption =
       await provider.connection.getMinimumBalanceForRentExemption(100 * 1024); // 100KB
@@ -153,16 +240,34 @@ describe("Initialization", () => {
       .accountsStrict({
         oracleContractState: oracleContractState.publicKey,
         contributorDataAccount: contributorDataAccountPDA,
-        user: admin.publicKey,
+        payer: { user: admin.publicKey },
         tempReportAccount: tempReportAccountPDA,
         txidSubmissionCountsAccount: txidSubmissionCountsAccountPDA,
         aggregatedConsensusDataAccount: aggregatedConsensusDataAccountPDA,
+        consensusSummaryAccount: consensusSummaryAccountPDA,
+        adminAuditLog: adminAuditLogPDA,
         systemProgram: web3.SystemProgram.programId,
       })
       .signers([oracleContractState])
       .rpc();
     await measureComputeUnitsAndStorage(initTxSignature);
 
+    // The program never transfers the lamport-only PDAs below their rent-exempt minimum, so seed them with it
+    const pdaRentReserve =
+      await provider.connection.getMinimumBalanceForRentExemption(0);
+    const seedPdasTxSignature = await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        ...[rewardPoolAccountPDA, feeReceivingContractAccountPDA].map((pda) =>
+          anchor.web3.SystemProgram.transfer({
+            fromPubkey: admin.publicKey,
+            toPubkey: pda,
+            lamports: pdaRentReserve,
+          })
+        )
+      )
+    );
+    await measureComputeUnitsAndStorage(seedPdasTxSignature);
+
     let state = await program.account.oracleContractState.fetch(
       oracleContractState.publicKey
     );
@@ -194,6 +299,7 @@ describe("Initialization", () => {
           contributorDataAccount: contributorDataAccountPDA,
           txidSubmissionCountsAccount: txidSubmissionCountsAccountPDA,
           aggregatedConsensusDataAccount: aggregatedConsensusDataAccountPDA,
+          adminAuditLog: adminAuditLogPDA,
           systemProgram: web3.SystemProgram.programId,
         })
         .rpc();
@@ -220,29 +326,127 @@ describe("Initialization", () => {
   });
 });
 
-describe("Set Bridge Contract", () => {
-  it("Sets the bridge contract address to admin address", async () => {
-    const setBridgeTxSignature = await program.methods
-      .setBridgeContract(admin.publicKey)
+describe("Bridge Contract Allowlist", () => {
+  it("Rejects authorizing the admin address as a bridge contract", async () => {
+    try {
+      await program.methods
+        .addBridgeContract(admin.publicKey)
+        .accountsPartial({
+          oracleContractState: oracleContractState.publicKey,
+          adminPubkey: admin.publicKey,
+        })
+        .rpc();
+      assert.fail("Authorizing the admin as a bridge contract should fail");
+    } catch (error) {
+      const anchorError = anchor.AnchorError.parse(error.logs);
+      assert.isNotNull(anchorError, `Unexpected error: ${error.toString()}`);
+      assert.equal(
+        anchorError.error.errorCode.code,
+        "ConflictingRoleAssignment",
+        "Should throw ConflictingRoleAssignment error"
+      );
+    }
+  });
+
+  it("Authorizes the bridge contract address", async () => {
+    const addBridgeTxSignature = await program.methods
+      .addBridgeContract(bridgeContract.publicKey)
       .accountsPartial({
         oracleContractState: oracleContractState.publicKey,
         adminPubkey: admin.publicKey,
       })
       .rpc();
-    await measureComputeUnitsAndStorage(setBridgeTxSignature);
+    await measureComputeUnitsAndStorage(addBridgeTxSignature);
 
-    // Fetch the updated state to verify the bridge contract address
+    // Fetch the updated state to verify the bridge contract allowlist
     const state = await program.account.oracleContractState.fetch(
       oracleContractState.publicKey
     );
 
     // Assertions
-    assert.strictEqual(
-      state.bridgeContractPubkey.toString(),
-      admin.publicKey.toString(),
-      "The bridge contract pubkey should be set to the admin address"
+    assert.deepEqual(
+      state.bridgeContractPubkeys.map((pubkey) => pubkey.toString()),
+      [bridgeContract.publicKey.toString()],
+      "The bridge contract should be the only authorized bridge"
+    );
+    console.log("Bridge contract authorized");
+  });
+
+  it("Authorizes and removes a second bridge contract", async () => {
+    const secondBridge = web3.Keypair.generate();
+    await program.methods
+      .addBridgeContract(secondBridge.publicKey)
+      .accountsPartial({
+        oracleContractState: oracleContractState.publicKey,
+        adminPubkey: admin.publicKey,
+      })
+      .rpc();
+    let state = await program.account.oracleContractState.fetch(
+      oracleContractState.publicKey
+    );
+    assert.equal(state.bridgeContractPubkeys.length, 2);
+
+    await program.methods
+      .removeBridgeContract(secondBridge.publicKey)
+      .accountsPartial({
+        oracleContractState: oracleContractState.publicKey,
+        adminPubkey: admin.publicKey,
+      })
+      .rpc();
+    state = await program.account.oracleContractState.fetch(
+      oracleContractState.publicKey
+    );
+    assert.deepEqual(
+      state.bridgeContractPubkeys.map((pubkey) => pubkey.toString()),
+      [bridgeContract.publicKey.toString()]
+    );
+  });
+});
+
+describe("Admin Audit Log", () => {
+  it("records admin actions and pages through them in order", async () => {
+    const [adminAuditLogPDA] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("admin_audit_log")],
+      program.programId
+    );
+
+    const initialLog = await program.account.adminAuditLogAccount.fetch(
+      adminAuditLogPDA
+    );
+    const initialTotal = initialLog.totalRecords.toNumber();
+
+    const maxMonitoredTxidsValues = [50, 75, 100];
+    for (const maxMonitoredTxids of maxMonitoredTxidsValues) {
+      const txSignature = await program.methods
+        .setMaxMonitoredTxids(maxMonitoredTxids)
+        .accountsPartial({
+          oracleContractState: oracleContractState.publicKey,
+          adminPubkey: admin.publicKey,
+        })
+        .rpc();
+      await measureComputeUnitsAndStorage(txSignature);
+    }
+
+    const { events } = await program.methods
+      .getAdminAuditLog(initialTotal, 16)
+      .accountsPartial({ adminAuditLog: adminAuditLogPDA })
+      .simulate();
+    const page = events.find((e) => e.name === "adminAuditLogPage").data;
+
+    assert.equal(
+      page.totalRecords.toNumber(),
+      initialTotal + maxMonitoredTxidsValues.length,
+      "Every admin action should be appended to the audit log"
     );
-    console.log("Bridge contract address set to admin address");
+    assert.equal(page.records.length, maxMonitoredTxidsValues.length);
+    page.records.forEach((record, index) => {
+      assert.deepEqual(record.actionCode, { setMaxMonitoredTxids: {} });
+      assert.equal(record.actor.toBase58(), admin.publicKey.toBase58());
+      assert.equal(
+        record.paramSummary,
+        `max_monitored_txids=${maxMonitoredTxidsValues[index]}`
+      );
+    });
   });
 });
 
@@ -289,6 +493,7 @@ describe("Contributor Registration", () => {
           contributorAccount: contributor.publicKey,
           rewardPoolAccount: rewardPoolAccountPDA,
           feeReceivingContractAccount: feeReceivingContractAccountPDA,
+          oracleContractState: oracleContractState.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .signers([contributor])
@@ -326,6 +531,60 @@ describe("Contributor Registration", () => {
   });
 });
 
+describe("Consensus Config", () => {
+  it("reports the consensus configuration set by the admin", async () => {
+    const [contributorDataAccountPDA] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("contributor_data")],
+      program.programId
+    );
+    const earlyBirdDecayPeriod = 600;
+
+    const setQuorumTxSignature = await program.methods
+      .setQuorumConfig(MIN_NUMBER_OF_ORACLES, false, new BN(0))
+      .accountsPartial({
+        oracleContractState: oracleContractState.publicKey,
+        adminPubkey: admin.publicKey,
+      })
+      .rpc();
+    await measureComputeUnitsAndStorage(setQuorumTxSignature);
+
+    const setEarlyBirdTxSignature = await program.methods
+      .setEarlyBirdConfig(new BN(1_000000000), earlyBirdDecayPeriod)
+      .accountsPartial({
+        oracleContractState: oracleContractState.publicKey,
+        adminPubkey: admin.publicKey,
+      })
+      .rpc();
+    await measureComputeUnitsAndStorage(setEarlyBirdTxSignature);
+
+    const { events } = await program.methods
+      .getConsensusConfig()
+      .accountsPartial({
+        oracleContractState: oracleContractState.publicKey,
+        contributorDataAccount: contributorDataAccountPDA,
+      })
+      .simulate();
+    const config = events.find((e) => e.name === "consensusConfig").data;
+
+    assert.equal(config.registeredContributorCount, NUM_CONTRIBUTORS);
+    assert.equal(config.activeContributorCount, NUM_CONTRIBUTORS);
+    // Contributors registered in this run are still within the activation delay
+    assert.equal(config.matureContributorCount, 0);
+    assert.equal(config.contributorActivationDelay, 86_400);
+    assert.equal(config.minQuorum, MIN_NUMBER_OF_ORACLES);
+    assert.equal(config.effectiveQuorum, MIN_NUMBER_OF_ORACLES);
+    assert.isFalse(config.quorumAutoScalingEnabled);
+    assert.equal(config.earlyBirdMultiplier.toNumber(), 1_000000000);
+    assert.equal(config.earlyBirdDecayPeriod, earlyBirdDecayPeriod);
+    assert.deepEqual(config.tieBreakStrategy, { highestOrdinal: {} });
+    assert.equal(config.maxSubmissionsPerTxid, 64);
+    assert.equal(config.maxReportWeight.toNumber(), 0);
+    config.accuracyPolicies.forEach((policy) =>
+      assert.deepEqual(policy, { statusAndHash: {} })
+    );
+  });
+});
+
 describe("TXID Monitoring", () => {
   it("Adds multiple TXIDs for monitoring", async () => {
     // Define the number of TXIDs to add for monitoring
@@ -373,11 +632,12 @@ describe("TXID Monitoring", () => {
         .addTxidForMonitoring({ txid: txid })
         .accountsPartial({
           oracleContractState: oracleContractState.publicKey,
-          caller: admin.publicKey,
+          caller: bridgeContract.publicKey,
           pendingPaymentAccount: pendingPaymentAccountPDA,
           user: admin.publicKey,
           systemProgram: web3.SystemProgram.programId,
         })
+        .signers([bridgeContract])
         .rpc();
       await measureComputeUnitsAndStorage(addTxidTxSignature);
 
@@ -783,148 +1043,810 @@ describe("Data Report Submission", () => {
   });
 });
 
-describe("Data Cleanup Verification", () => {
-  it("Verifies that data is cleaned up post-consensus", async () => {
-    const txidsToCheck = trackedTxids;
+describe("Late Data Report Submission", () => {
+  it("rejects a report for a TXID that already hit quorum", async () => {
+    const seedPreamble = "pastel_tx_status_report";
+    const txid = trackedTxids[0];
 
-    const [tempReportAccountPDA] = web3.PublicKey.findProgramAddressSync(
-      [Buffer.from("temp_tx_status_report")],
+    const [contributorDataAccountPDA] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("contributor_data")],
       program.programId
     );
-
-    const tempReportAccountData =
-      await program.account.tempTxStatusReportAccount.fetch(
-        tempReportAccountPDA
-      );
-
-    txidsToCheck.forEach((txid) => {
-      const isTxidPresentInTempReport = tempReportAccountData.reports.some(
-        (report) => {
-          const commonDataIndex = report.commonDataRef.toNumber(); // Convert BN to number
-          const commonData =
-            tempReportAccountData.commonReports[commonDataIndex]; // Use converted index
-          return commonData.txid === txid;
-        }
-      );
-      assert.isFalse(
-        isTxidPresentInTempReport,
-        `TXID ${txid} should be cleaned up from TempTxStatusReportAccount`
+    const [txidSubmissionCountsAccountPDA] =
+      web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("txid_submission_counts")],
+        program.programId
       );
-    });
-
-    console.log(
-      "Data cleanup post-consensus verification completed successfully."
-    );
-  });
-});
-
-describe("Payment Processing by Bridge Contract", () => {
-  it("Processes payments for monitored TXIDs", async () => {
-    const COST_IN_LAMPORTS_OF_ADDING_PASTEL_TXID_FOR_MONITORING = new BN(
-      COST_IN_SOL_OF_ADDING_PASTEL_TXID_FOR_MONITORING * web3.LAMPORTS_PER_SOL
-    );
-    // Derive the FeeReceivingContractAccount PDA
-    const [feeReceivingContractAccountPDA] =
+    const [aggregatedConsensusDataAccountPDA] =
       web3.PublicKey.findProgramAddressSync(
-        [Buffer.from("fee_receiving_contract")],
+        [Buffer.from("aggregated_consensus_data")],
         program.programId
       );
-
-    // Fetch monitored TXIDs from the updated state
-    const state = await program.account.oracleContractState.fetch(
-      oracleContractState.publicKey
+    const [tempReportAccountPDA] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("temp_tx_status_report")],
+      program.programId
     );
-    const monitoredTxids = state.monitoredTxids;
-
-    for (const txid of monitoredTxids) {
-      // Transfer the payment for the TXID from the admin account to the fee-receiving contract
-      const transferTransaction = new anchor.web3.Transaction().add(
-        anchor.web3.SystemProgram.transfer({
-          fromPubkey: admin.publicKey,
-          toPubkey: feeReceivingContractAccountPDA,
-          lamports:
-            COST_IN_LAMPORTS_OF_ADDING_PASTEL_TXID_FOR_MONITORING.toNumber(),
-        })
-      );
-
-      const transferTxSignature = await provider.sendAndConfirm(
-        transferTransaction
-      );
-      await measureComputeUnitsAndStorage(transferTxSignature);
-      console.log(
-        `Transferred payment for TXID ${txid} to fee-receiving contract`
-      );
 
-      // Manually create the seed for pendingPaymentAccountPDA
-      const preimageString =
-        "pending_payment" + txid + admin.publicKey.toBase58();
-      const preimageBytes = Buffer.from(preimageString, "utf8");
+    // Find a contributor who has not yet submitted a report for this TXID
+    let lateContributor = null;
+    let reportAccountPDA = null;
+    for (const contributor of contributors) {
       const seedHash = crypto
         .createHash("sha256")
-        .update(preimageBytes)
+        .update(
+          Buffer.from(
+            seedPreamble + txid + contributor.publicKey.toBase58(),
+            "utf8"
+          )
+        )
         .digest();
-      const [pendingPaymentAccountPDA] = web3.PublicKey.findProgramAddressSync(
+      const [candidatePDA] = web3.PublicKey.findProgramAddressSync(
         [seedHash],
         program.programId
       );
+      if ((await provider.connection.getAccountInfo(candidatePDA)) === null) {
+        lateContributor = contributor;
+        reportAccountPDA = candidatePDA;
+        break;
+      }
+    }
+    assert.isNotNull(
+      lateContributor,
+      "At least one contributor should not have reported on this TXID"
+    );
 
-      // Process the payment in the oracle contract
+    try {
       await program.methods
-        .processPayment(
+        .submitDataReport(
           txid,
-          COST_IN_LAMPORTS_OF_ADDING_PASTEL_TXID_FOR_MONITORING
+          { minedActivated: {} } as unknown as any,
+          { nft: {} } as unknown as any,
+          "abcdef",
+          lateContributor.publicKey
         )
         .accountsPartial({
-          sourceAccount: admin.publicKey,
+          reportAccount: reportAccountPDA,
+          tempReportAccount: tempReportAccountPDA,
+          contributorDataAccount: contributorDataAccountPDA,
+          txidSubmissionCountsAccount: txidSubmissionCountsAccountPDA,
+          aggregatedConsensusDataAccount: aggregatedConsensusDataAccountPDA,
           oracleContractState: oracleContractState.publicKey,
-          pendingPaymentAccount: pendingPaymentAccountPDA,
+          user: lateContributor.publicKey,
           systemProgram: web3.SystemProgram.programId,
         })
+        .signers([lateContributor])
         .rpc();
-
-      // Fetch the updated PendingPayment struct to verify the payment status
-      const pendingPaymentData =
-        await program.account.pendingPaymentAccount.fetch(
-          pendingPaymentAccountPDA
-        );
-
-      // Convert paymentStatus to JSON and compare the stringified version
-      const paymentStatusJson = JSON.stringify(
-        pendingPaymentData.pendingPayment.paymentStatus
-      );
-      assert.strictEqual(
-        paymentStatusJson,
-        JSON.stringify({ received: {} }),
-        `The payment status for TXID: ${txid} should be 'Received'`
+      assert.fail("Late submission should have been rejected");
+    } catch (error) {
+      const anchorError = anchor.AnchorError.parse(error.logs);
+      assert.isNotNull(anchorError, `Unexpected error: ${error.toString()}`);
+      assert.equal(
+        anchorError.error.errorCode.code,
+        "EnoughReportsSubmittedForTxid",
+        "Should throw EnoughReportsSubmittedForTxid error"
       );
-
-      console.log(`Payment processed for TXID ${txid}`);
     }
+
+    const reportAccountInfo = await provider.connection.getAccountInfo(
+      reportAccountPDA
+    );
+    assert.isNull(
+      reportAccountInfo,
+      "No report account should be created for a rejected late submission"
+    );
   });
 });
 
-describe("Eligibility for Rewards", () => {
-  it("should check if contributors meet reward eligibility criteria", async () => {
-    // Fetch the ContributorDataAccount
-    const [contributorDataAccountPDA] = web3.PublicKey.findProgramAddressSync(
-      [Buffer.from("contributor_data")],
-      program.programId
-    );
+describe("Oversized TXID Rejection", () => {
+  const oversizedTxid = "a".repeat(65);
 
-    const contributorData = await program.account.contributorDataAccount.fetch(
-      contributorDataAccountPDA
+  const expectInvalidTxid = (error) => {
+    const anchorError = anchor.AnchorError.parse(error.logs);
+    assert.isNotNull(anchorError, `Unexpected error: ${error.toString()}`);
+    assert.equal(
+      anchorError.error.errorCode.code,
+      "InvalidTxid",
+      "Should throw InvalidTxid error"
     );
+  };
+
+  const derivePendingPaymentPDA = (txid: string) => {
+    const seedHash = crypto
+      .createHash("sha256")
+      .update(
+        Buffer.from(
+          "pending_payment" + txid + admin.publicKey.toBase58(),
+          "utf8"
+        )
+      )
+      .digest();
+    return web3.PublicKey.findProgramAddressSync(
+      [seedHash],
+      program.programId
+    )[0];
+  };
 
-    // Loop through each contributor and check their eligibility
-    for (const contributor of contributors) {
-      const currentContributorData = contributorData.contributors.find((c) =>
-        c.rewardAddress.equals(contributor.publicKey)
-      );
-
-      // Check if the contributor data exists
-      if (!currentContributorData) {
-        throw new Error(
-          `Contributor data not found for address ${contributor.publicKey.toBase58()}`
+  it("rejects an oversized TXID in add_pending_payment", async () => {
+    try {
+      await program.methods
+        .addPendingPayment(oversizedTxid, new BN(100000), { pending: {} })
+        .accountsPartial({
+          pendingPaymentAccount: derivePendingPaymentPDA(oversizedTxid),
+          oracleContractState: oracleContractState.publicKey,
+          user: admin.publicKey,
+          systemProgram: web3.SystemProgram.programId,
+        })
+        .rpc();
+      assert.fail("Oversized TXID should have been rejected");
+    } catch (error) {
+      expectInvalidTxid(error);
+    }
+  });
+
+  it("rejects an oversized TXID in add_txid_for_monitoring", async () => {
+    try {
+      await program.methods
+        .addTxidForMonitoring({ txid: oversizedTxid })
+        .accountsPartial({
+          oracleContractState: oracleContractState.publicKey,
+          caller: bridgeContract.publicKey,
+          pendingPaymentAccount: derivePendingPaymentPDA(trackedTxids[0]),
+          user: admin.publicKey,
+          systemProgram: web3.SystemProgram.programId,
+        })
+        .signers([bridgeContract])
+        .rpc();
+      assert.fail("Oversized TXID should have been rejected");
+    } catch (error) {
+      expectInvalidTxid(error);
+    }
+  });
+
+  it("rejects an oversized TXID in submit_data_report", async () => {
+    const contributor = contributors[0];
+    const seedHash = crypto
+      .createHash("sha256")
+      .update(
+        Buffer.from(
+          "pastel_tx_status_report" +
+            oversizedTxid +
+            contributor.publicKey.toBase58(),
+          "utf8"
+        )
+      )
+      .digest();
+    const [reportAccountPDA] = web3.PublicKey.findProgramAddressSync(
+      [seedHash],
+      program.programId
+    );
+    const [tempReportAccountPDA] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("temp_tx_status_report")],
+      program.programId
+    );
+    const [contributorDataAccountPDA] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("contributor_data")],
+      program.programId
+    );
+    const [txidSubmissionCountsAccountPDA] =
+      web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("txid_submission_counts")],
+        program.programId
+      );
+    const [aggregatedConsensusDataAccountPDA] =
+      web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("aggregated_consensus_data")],
+        program.programId
+      );
+
+    try {
+      await program.methods
+        .submitDataReport(
+          oversizedTxid,
+          { minedActivated: {} } as unknown as any,
+          { nft: {} } as unknown as any,
+          "abcdef",
+          contributor.publicKey
+        )
+        .accountsPartial({
+          reportAccount: reportAccountPDA,
+          tempReportAccount: tempReportAccountPDA,
+          contributorDataAccount: contributorDataAccountPDA,
+          txidSubmissionCountsAccount: txidSubmissionCountsAccountPDA,
+          aggregatedConsensusDataAccount: aggregatedConsensusDataAccountPDA,
+          oracleContractState: oracleContractState.publicKey,
+          user: contributor.publicKey,
+          systemProgram: web3.SystemProgram.programId,
+        })
+        .signers([contributor])
+        .rpc();
+      assert.fail("Oversized TXID should have been rejected");
+    } catch (error) {
+      expectInvalidTxid(error);
+    }
+  });
+});
+
+describe("Packed Data Report Submission", () => {
+  it("stores the same report state as the per-field instruction", async () => {
+    const submitter = contributors[2];
+    const unpackedTxid = crypto.randomBytes(32).toString("hex");
+    const packedTxid = crypto.randomBytes(32).toString("hex");
+
+    const deriveReportAccountPDA = (txid: string) => {
+      const seedHash = crypto
+        .createHash("sha256")
+        .update(
+          Buffer.from(
+            "pastel_tx_status_report" + txid + submitter.publicKey.toBase58(),
+            "utf8"
+          )
+        )
+        .digest();
+      return web3.PublicKey.findProgramAddressSync(
+        [seedHash],
+        program.programId
+      )[0];
+    };
+    const [tempReportAccountPDA] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("temp_tx_status_report")],
+      program.programId
+    );
+    const [contributorDataAccountPDA] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("contributor_data")],
+      program.programId
+    );
+    const [txidSubmissionCountsAccountPDA] =
+      web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("txid_submission_counts")],
+        program.programId
+      );
+    const [aggregatedConsensusDataAccountPDA] =
+      web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("aggregated_consensus_data")],
+        program.programId
+      );
+    const sharedAccounts = {
+      tempReportAccount: tempReportAccountPDA,
+      contributorDataAccount: contributorDataAccountPDA,
+      txidSubmissionCountsAccount: txidSubmissionCountsAccountPDA,
+      aggregatedConsensusDataAccount: aggregatedConsensusDataAccountPDA,
+      oracleContractState: oracleContractState.publicKey,
+      user: submitter.publicKey,
+      systemProgram: web3.SystemProgram.programId,
+    };
+
+    const unpackedTxSignature = await program.methods
+      .submitDataReport(
+        unpackedTxid,
+        { minedActivated: {} } as unknown as any,
+        { sense: {} } as unknown as any,
+        "abcdef",
+        submitter.publicKey
+      )
+      .accountsPartial({
+        ...sharedAccounts,
+        reportAccount: deriveReportAccountPDA(unpackedTxid),
+      })
+      .signers([submitter])
+      .rpc();
+    await measureComputeUnitsAndStorage(unpackedTxSignature);
+
+    const packedTxSignature = await program.methods
+      .submitPackedDataReport({
+        txid: packedTxid,
+        txidStatus: { minedActivated: {} } as unknown as any,
+        pastelTicketType: { sense: {} } as unknown as any,
+        first6CharactersHash: "abcdef",
+      })
+      .accountsPartial({
+        ...sharedAccounts,
+        reportAccount: deriveReportAccountPDA(packedTxid),
+      })
+      .signers([submitter])
+      .rpc();
+    await measureComputeUnitsAndStorage(packedTxSignature);
+
+    const tempReportAccountData =
+      await program.account.tempTxStatusReportAccount.fetch(
+        tempReportAccountPDA
+      );
+    const findReport = (txid: string) => {
+      const report = tempReportAccountData.reports.find(
+        (r) =>
+          tempReportAccountData.commonReports[r.commonDataRef.toNumber()]
+            .txid === txid
+      );
+      const { txid: _, ...commonData } =
+        tempReportAccountData.commonReports[report.commonDataRef.toNumber()];
+      return { commonData, specificData: report.specificData };
+    };
+    const unpackedReport = findReport(unpackedTxid);
+    const packedReport = findReport(packedTxid);
+    assert.deepEqual(packedReport.commonData, unpackedReport.commonData);
+    assert.equal(
+      packedReport.specificData.weight.toString(),
+      unpackedReport.specificData.weight.toString()
+    );
+    assert.isTrue(
+      packedReport.specificData.contributorRewardAddress.equals(
+        unpackedReport.specificData.contributorRewardAddress
+      )
+    );
+
+    const aggregatedData =
+      await program.account.aggregatedConsensusDataAccount.fetch(
+        aggregatedConsensusDataAccountPDA
+      );
+    const findWeights = (txid: string) => {
+      const data = aggregatedData.consensusData.find((d) => d.txid === txid);
+      return {
+        statusWeights: data.statusWeights.map((w) => w.toString()),
+        hashWeights: data.hashWeights.map((h) => [h.hash, h.weight.toString()]),
+      };
+    };
+    assert.deepEqual(findWeights(packedTxid), findWeights(unpackedTxid));
+  });
+});
+
+describe("Report PDA Derivation", () => {
+  it("emits the same report PDA a client derives locally", async () => {
+    const contributor = contributors[0];
+    const txid = crypto.randomBytes(32).toString("hex");
+    const seedHash = crypto
+      .createHash("sha256")
+      .update(
+        Buffer.from(
+          "pastel_tx_status_report" + txid + contributor.publicKey.toBase58(),
+          "utf8"
+        )
+      )
+      .digest();
+    const [expectedPDA, expectedBump] = web3.PublicKey.findProgramAddressSync(
+      [seedHash],
+      program.programId
+    );
+
+    const { events } = await program.methods
+      .deriveReportPda(txid, contributor.publicKey)
+      .simulate();
+    const derived = events.find((e) => e.name === "reportPdaDerived").data;
+
+    assert.equal(derived.reportAccount.toBase58(), expectedPDA.toBase58());
+    assert.equal(derived.bump, expectedBump);
+  });
+});
+
+describe("Deferred Consensus", () => {
+  it("leaves consensus to a permissionless finalize_consensus call", async () => {
+    const txid = crypto.randomBytes(32).toString("hex");
+    const [tempReportAccountPDA] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("temp_tx_status_report")],
+      program.programId
+    );
+    const [contributorDataAccountPDA] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("contributor_data")],
+      program.programId
+    );
+    const [txidSubmissionCountsAccountPDA] =
+      web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("txid_submission_counts")],
+        program.programId
+      );
+    const [aggregatedConsensusDataAccountPDA] =
+      web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("aggregated_consensus_data")],
+        program.programId
+      );
+    const countPendingReports = async () => {
+      const tempReportAccountData =
+        await program.account.tempTxStatusReportAccount.fetch(
+          tempReportAccountPDA
+        );
+      return tempReportAccountData.reports.filter(
+        (r) =>
+          tempReportAccountData.commonReports[r.commonDataRef.toNumber()]
+            .txid === txid
+      ).length;
+    };
+    const setDeferredConsensus = async (deferredConsensus: boolean) => {
+      const txSignature = await program.methods
+        .setDeferredConsensus(deferredConsensus)
+        .accountsPartial({
+          oracleContractState: oracleContractState.publicKey,
+          adminPubkey: admin.publicKey,
+        })
+        .rpc();
+      await measureComputeUnitsAndStorage(txSignature);
+    };
+
+    await setDeferredConsensus(true);
+
+    const submitters = contributors.slice(0, MIN_NUMBER_OF_ORACLES);
+    for (const submitter of submitters) {
+      const seedHash = crypto
+        .createHash("sha256")
+        .update(
+          Buffer.from(
+            "pastel_tx_status_report" + txid + submitter.publicKey.toBase58(),
+            "utf8"
+          )
+        )
+        .digest();
+      const [reportAccountPDA] = web3.PublicKey.findProgramAddressSync(
+        [seedHash],
+        program.programId
+      );
+      const txSignature = await program.methods
+        .submitDataReport(
+          txid,
+          { minedActivated: {} } as unknown as any,
+          { sense: {} } as unknown as any,
+          "abcdef",
+          submitter.publicKey
+        )
+        .accountsPartial({
+          reportAccount: reportAccountPDA,
+          tempReportAccount: tempReportAccountPDA,
+          contributorDataAccount: contributorDataAccountPDA,
+          txidSubmissionCountsAccount: txidSubmissionCountsAccountPDA,
+          aggregatedConsensusDataAccount: aggregatedConsensusDataAccountPDA,
+          oracleContractState: oracleContractState.publicKey,
+          user: submitter.publicKey,
+          systemProgram: web3.SystemProgram.programId,
+        })
+        .signers([submitter])
+        .rpc();
+      await measureComputeUnitsAndStorage(txSignature);
+    }
+
+    // The quorum is met, but the reports wait for finalize_consensus
+    assert.equal(await countPendingReports(), submitters.length);
+
+    // Any signer may finalize, not just a contributor or the admin
+    const caller = web3.Keypair.generate();
+    const finalizeTxSignature = await program.methods
+      .finalizeConsensus(txid)
+      .accountsPartial({
+        caller: caller.publicKey,
+        oracleContractState: oracleContractState.publicKey,
+        txidSubmissionCountsAccount: txidSubmissionCountsAccountPDA,
+        tempReportAccount: tempReportAccountPDA,
+        contributorDataAccount: contributorDataAccountPDA,
+        aggregatedConsensusDataAccount: aggregatedConsensusDataAccountPDA,
+      })
+      .signers([caller])
+      .rpc();
+    await measureComputeUnitsAndStorage(finalizeTxSignature);
+
+    assert.equal(await countPendingReports(), 0);
+
+    try {
+      await program.methods
+        .finalizeConsensus(txid)
+        .accountsPartial({
+          caller: caller.publicKey,
+          oracleContractState: oracleContractState.publicKey,
+          txidSubmissionCountsAccount: txidSubmissionCountsAccountPDA,
+          tempReportAccount: tempReportAccountPDA,
+          contributorDataAccount: contributorDataAccountPDA,
+          aggregatedConsensusDataAccount: aggregatedConsensusDataAccountPDA,
+        })
+        .signers([caller])
+        .rpc();
+      assert.fail("Finalizing an already finalized txid should fail");
+    } catch (error) {
+      const anchorError = anchor.AnchorError.parse(error.logs);
+      assert.isNotNull(anchorError, `Unexpected error: ${error.toString()}`);
+      assert.equal(anchorError.error.errorCode.code, "ConsensusNotReady");
+    }
+
+    await setDeferredConsensus(false);
+  });
+});
+
+describe("Unauthorized Submitter Rejection", () => {
+  it("rejects a report attributed to a different contributor", async () => {
+    const submitter = contributors[1];
+    const impersonatedContributor = contributors[0];
+    const txid = crypto.randomBytes(32).toString("hex");
+
+    const seedHash = crypto
+      .createHash("sha256")
+      .update(
+        Buffer.from(
+          "pastel_tx_status_report" + txid + submitter.publicKey.toBase58(),
+          "utf8"
+        )
+      )
+      .digest();
+    const [reportAccountPDA] = web3.PublicKey.findProgramAddressSync(
+      [seedHash],
+      program.programId
+    );
+    const [tempReportAccountPDA] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("temp_tx_status_report")],
+      program.programId
+    );
+    const [contributorDataAccountPDA] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("contributor_data")],
+      program.programId
+    );
+    const [txidSubmissionCountsAccountPDA] =
+      web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("txid_submission_counts")],
+        program.programId
+      );
+    const [aggregatedConsensusDataAccountPDA] =
+      web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("aggregated_consensus_data")],
+        program.programId
+      );
+
+    try {
+      await program.methods
+        .submitDataReport(
+          txid,
+          { minedActivated: {} } as unknown as any,
+          { nft: {} } as unknown as any,
+          "abcdef",
+          impersonatedContributor.publicKey
+        )
+        .accountsPartial({
+          reportAccount: reportAccountPDA,
+          tempReportAccount: tempReportAccountPDA,
+          contributorDataAccount: contributorDataAccountPDA,
+          txidSubmissionCountsAccount: txidSubmissionCountsAccountPDA,
+          aggregatedConsensusDataAccount: aggregatedConsensusDataAccountPDA,
+          oracleContractState: oracleContractState.publicKey,
+          user: submitter.publicKey,
+          systemProgram: web3.SystemProgram.programId,
+        })
+        .signers([submitter])
+        .rpc();
+      assert.fail("Submission on behalf of another contributor should fail");
+    } catch (error) {
+      const anchorError = anchor.AnchorError.parse(error.logs);
+      assert.isNotNull(anchorError, `Unexpected error: ${error.toString()}`);
+      assert.equal(
+        anchorError.error.errorCode.code,
+        "UnauthorizedSubmitter",
+        "Should throw UnauthorizedSubmitter error"
+      );
+    }
+  });
+});
+
+describe("Data Cleanup Verification", () => {
+  it("Verifies that data is cleaned up post-consensus", async () => {
+    const txidsToCheck = trackedTxids;
+
+    const [tempReportAccountPDA] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("temp_tx_status_report")],
+      program.programId
+    );
+
+    const tempReportAccountData =
+      await program.account.tempTxStatusReportAccount.fetch(
+        tempReportAccountPDA
+      );
+
+    txidsToCheck.forEach((txid) => {
+      const isTxidPresentInTempReport = tempReportAccountData.reports.some(
+        (report) => {
+          const commonDataIndex = report.commonDataRef.toNumber(); // Convert BN to number
+          const commonData =
+            tempReportAccountData.commonReports[commonDataIndex]; // Use converted index
+          return commonData.txid === txid;
+        }
+      );
+      assert.isFalse(
+        isTxidPresentInTempReport,
+        `TXID ${txid} should be cleaned up from TempTxStatusReportAccount`
+      );
+    });
+
+    console.log(
+      "Data cleanup post-consensus verification completed successfully."
+    );
+  });
+});
+
+describe("Aggregated Data Breakdown", () => {
+  it("labels aggregated status weights with their status", async () => {
+    const [aggregatedConsensusDataAccountPDA] =
+      web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("aggregated_consensus_data")],
+        program.programId
+      );
+    const txid = trackedTxids[0];
+
+    const aggregatedData =
+      await program.account.aggregatedConsensusDataAccount.fetch(
+        aggregatedConsensusDataAccountPDA
+      );
+    const rawData = aggregatedData.consensusData.find(
+      (data) => data.txid === txid
+    );
+
+    const { events } = await program.methods
+      .getAggregatedData(txid)
+      .accountsPartial({
+        aggregatedConsensusDataAccount: aggregatedConsensusDataAccountPDA,
+      })
+      .simulate();
+    const breakdown = events.find(
+      (e) => e.name === "aggregatedDataBreakdown"
+    ).data;
+
+    assert.equal(breakdown.txid, txid);
+    assert.deepEqual(
+      breakdown.statusWeights.map((entry) => Object.keys(entry.status)[0]),
+      ["invalid", "pendingMining", "minedPendingActivation", "minedActivated"]
+    );
+    breakdown.statusWeights.forEach((entry, index) =>
+      assert.equal(
+        entry.weight.toString(),
+        rawData.statusWeights[index].toString()
+      )
+    );
+  });
+});
+
+describe("Payment Processing by Bridge Contract", () => {
+  it("Processes payments for monitored TXIDs", async () => {
+    const COST_IN_LAMPORTS_OF_ADDING_PASTEL_TXID_FOR_MONITORING = new BN(
+      COST_IN_SOL_OF_ADDING_PASTEL_TXID_FOR_MONITORING * web3.LAMPORTS_PER_SOL
+    );
+    // Derive the FeeReceivingContractAccount PDA
+    const [feeReceivingContractAccountPDA] =
+      web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("fee_receiving_contract")],
+        program.programId
+      );
+
+    // Fetch monitored TXIDs from the updated state
+    const state = await program.account.oracleContractState.fetch(
+      oracleContractState.publicKey
+    );
+    const monitoredTxids = state.monitoredTxids;
+
+    for (const txid of monitoredTxids) {
+      // Transfer the payment for the TXID from the admin account to the fee-receiving contract
+      const transferTransaction = new anchor.web3.Transaction().add(
+        anchor.web3.SystemProgram.transfer({
+          fromPubkey: admin.publicKey,
+          toPubkey: feeReceivingContractAccountPDA,
+          lamports:
+            COST_IN_LAMPORTS_OF_ADDING_PASTEL_TXID_FOR_MONITORING.toNumber(),
+        })
+      );
+
+      const transferTxSignature = await provider.sendAndConfirm(
+        transferTransaction
+      );
+      await measureComputeUnitsAndStorage(transferTxSignature);
+      console.log(
+        `Transferred payment for TXID ${txid} to fee-receiving contract`
+      );
+
+      // Manually create the seed for pendingPaymentAccountPDA
+      const preimageString =
+        "pending_payment" + txid + admin.publicKey.toBase58();
+      const preimageBytes = Buffer.from(preimageString, "utf8");
+      const seedHash = crypto
+        .createHash("sha256")
+        .update(preimageBytes)
+        .digest();
+      const [pendingPaymentAccountPDA] = web3.PublicKey.findProgramAddressSync(
+        [seedHash],
+        program.programId
+      );
+
+      // Process the payment in the oracle contract
+      await program.methods
+        .processPayment(
+          txid,
+          COST_IN_LAMPORTS_OF_ADDING_PASTEL_TXID_FOR_MONITORING
+        )
+        .accountsPartial({
+          sourceAccount: admin.publicKey,
+          caller: bridgeContract.publicKey,
+          oracleContractState: oracleContractState.publicKey,
+          pendingPaymentAccount: pendingPaymentAccountPDA,
+          systemProgram: web3.SystemProgram.programId,
+        })
+        .signers([bridgeContract])
+        .rpc();
+
+      // Fetch the updated PendingPayment struct to verify the payment status
+      const pendingPaymentData =
+        await program.account.pendingPaymentAccount.fetch(
+          pendingPaymentAccountPDA
+        );
+
+      // Convert paymentStatus to JSON and compare the stringified version
+      const paymentStatusJson = JSON.stringify(
+        pendingPaymentData.pendingPayment.paymentStatus
+      );
+      assert.strictEqual(
+        paymentStatusJson,
+        JSON.stringify({ received: {} }),
+        `The payment status for TXID: ${txid} should be 'Received'`
+      );
+
+      console.log(`Payment processed for TXID ${txid}`);
+    }
+  });
+
+  it("Rejects payment processing by a caller that is not an authorized bridge", async () => {
+    const state = await program.account.oracleContractState.fetch(
+      oracleContractState.publicKey
+    );
+    const txid = state.monitoredTxids[0];
+    const seedHash = crypto
+      .createHash("sha256")
+      .update(
+        Buffer.from("pending_payment" + txid + admin.publicKey.toBase58(), "utf8")
+      )
+      .digest();
+    const [pendingPaymentAccountPDA] = web3.PublicKey.findProgramAddressSync(
+      [seedHash],
+      program.programId
+    );
+    const unauthorizedCaller = web3.Keypair.generate();
+
+    try {
+      await program.methods
+        .processPayment(
+          txid,
+          new BN(
+            COST_IN_SOL_OF_ADDING_PASTEL_TXID_FOR_MONITORING *
+              web3.LAMPORTS_PER_SOL
+          )
+        )
+        .accountsPartial({
+          sourceAccount: admin.publicKey,
+          caller: unauthorizedCaller.publicKey,
+          oracleContractState: oracleContractState.publicKey,
+          pendingPaymentAccount: pendingPaymentAccountPDA,
+          systemProgram: web3.SystemProgram.programId,
+        })
+        .signers([unauthorizedCaller])
+        .rpc();
+      assert.fail("An unauthorized caller should not process payments");
+    } catch (error) {
+      const anchorError = anchor.AnchorError.parse(error.logs);
+      assert.isNotNull(anchorError, `Unexpected error: ${error.toString()}`);
+      assert.equal(
+        anchorError.error.errorCode.code,
+        "NotBridgeContractAddress"
+      );
+    }
+  });
+});
+
+describe("Eligibility for Rewards", () => {
+  it("should check if contributors meet reward eligibility criteria", async () => {
+    // Fetch the ContributorDataAccount
+    const [contributorDataAccountPDA] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("contributor_data")],
+      program.programId
+    );
+
+    const contributorData = await program.account.contributorDataAccount.fetch(
+      contributorDataAccountPDA
+    );
+
+    // Loop through each contributor and check their eligibility
+    for (const contributor of contributors) {
+      const currentContributorData = contributorData.contributors.find((c) =>
+        c.rewardAddress.equals(contributor.publicKey)
+      );
+
+      // Check if the contributor data exists
+      if (!currentContributorData) {
+        throw new Error(
+          `Contributor data not found for address ${contributor.publicKey.toBase58()}`
         );
       }
 
@@ -1077,6 +1999,333 @@ describe("Reward Distribution for Eligible Contributor", () => {
   });
 });
 
+describe("Reward Pool Account Validation", () => {
+  it("rejects a substituted reward pool account", async () => {
+    // A program-owned account in place of the reward pool PDA
+    const fakeRewardPoolAccount = oracleContractState.publicKey;
+
+    try {
+      await program.methods
+        .fundRewardPool(new BN(BASE_REWARD_AMOUNT_IN_LAMPORTS))
+        .accountsPartial({
+          funder: admin.publicKey,
+          rewardPoolAccount: fakeRewardPoolAccount,
+          oracleContractState: oracleContractState.publicKey,
+          systemProgram: web3.SystemProgram.programId,
+        })
+        .rpc();
+      assert.fail("Funding a substituted reward pool account should fail");
+    } catch (error) {
+      const anchorError = anchor.AnchorError.parse(error.logs);
+      assert.isNotNull(anchorError, `Unexpected error: ${error.toString()}`);
+      assert.equal(anchorError.error.errorCode.code, "ConstraintSeeds");
+    }
+  });
+});
+
+describe("Reward Pool Runway", () => {
+  it("tracks the number of remaining claims as the pool is funded and drawn down", async () => {
+    const [rewardPoolAccountPDA] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("reward_pool")],
+      program.programId
+    );
+    const [contributorDataAccountPDA] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("contributor_data")],
+      program.programId
+    );
+
+    const getRemainingClaims = async () => {
+      const { events } = await program.methods
+        .getRewardRunway()
+        .accountsPartial({
+          rewardPoolAccount: rewardPoolAccountPDA,
+          oracleContractState: oracleContractState.publicKey,
+        })
+        .simulate();
+      const runwayEvent = events.find((e) => e.name === "rewardRunway");
+      return runwayEvent.data.remainingClaims.toNumber();
+    };
+
+    const initialRunway = await getRemainingClaims();
+    console.log(`Initial reward pool runway: ${initialRunway} claims`);
+
+    // Funding the pool with enough for 10 more claims extends the runway by 10
+    const fundTxSignature = await program.methods
+      .fundRewardPool(new BN(10 * BASE_REWARD_AMOUNT_IN_LAMPORTS))
+      .accountsPartial({
+        funder: admin.publicKey,
+        rewardPoolAccount: rewardPoolAccountPDA,
+        oracleContractState: oracleContractState.publicKey,
+        systemProgram: web3.SystemProgram.programId,
+      })
+      .rpc();
+    await measureComputeUnitsAndStorage(fundTxSignature);
+
+    const fundedRunway = await getRemainingClaims();
+    assert.equal(
+      fundedRunway,
+      initialRunway + 10,
+      "Runway should increase after funding the reward pool"
+    );
+
+    // Paying out a reward shortens the runway by one claim
+    const eligibleContributor = contributors[0];
+    const requestRewardTxSignature = await program.methods
+      .requestReward(eligibleContributor.publicKey)
+      .accountsPartial({
+        rewardPoolAccount: rewardPoolAccountPDA,
+        oracleContractState: oracleContractState.publicKey,
+        contributorDataAccount: contributorDataAccountPDA,
+        contributor: eligibleContributor.publicKey,
+        systemProgram: web3.SystemProgram.programId,
+      })
+      .rpc();
+    await measureComputeUnitsAndStorage(requestRewardTxSignature);
+
+    const drawnDownRunway = await getRemainingClaims();
+    assert.equal(
+      drawnDownRunway,
+      fundedRunway - 1,
+      "Runway should decrease after a reward is paid out"
+    );
+  });
+});
+
+describe("Reward Pool Auto-Replenishment", () => {
+  it("tops up a low reward pool from the fee account before paying out", async () => {
+    const [rewardPoolAccountPDA] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("reward_pool")],
+      program.programId
+    );
+    const [feeReceivingContractAccountPDA] =
+      web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("fee_receiving_contract")],
+        program.programId
+      );
+    const [contributorDataAccountPDA] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("contributor_data")],
+      program.programId
+    );
+    const replenishAmount = 2 * BASE_REWARD_AMOUNT_IN_LAMPORTS;
+
+    // A threshold above the current balance makes the pool count as low
+    const initialRewardPoolBalance = await provider.connection.getBalance(
+      rewardPoolAccountPDA
+    );
+    const initialFeeAccountBalance = await provider.connection.getBalance(
+      feeReceivingContractAccountPDA
+    );
+    const setConfigTxSignature = await program.methods
+      .setAutoReplenishConfig(
+        new BN(initialRewardPoolBalance + 1),
+        new BN(replenishAmount),
+        new BN(0)
+      )
+      .accountsPartial({
+        oracleContractState: oracleContractState.publicKey,
+        adminPubkey: admin.publicKey,
+      })
+      .rpc();
+    await measureComputeUnitsAndStorage(setConfigTxSignature);
+
+    const eligibleContributor = contributors[0];
+    const requestRewardTxSignature = await program.methods
+      .requestReward(eligibleContributor.publicKey)
+      .accountsPartial({
+        rewardPoolAccount: rewardPoolAccountPDA,
+        oracleContractState: oracleContractState.publicKey,
+        contributorDataAccount: contributorDataAccountPDA,
+        contributor: eligibleContributor.publicKey,
+        feeReceivingContractAccount: feeReceivingContractAccountPDA,
+        systemProgram: web3.SystemProgram.programId,
+      })
+      .rpc();
+    await measureComputeUnitsAndStorage(requestRewardTxSignature);
+
+    const updatedRewardPoolBalance = await provider.connection.getBalance(
+      rewardPoolAccountPDA
+    );
+    const updatedFeeAccountBalance = await provider.connection.getBalance(
+      feeReceivingContractAccountPDA
+    );
+    assert.equal(
+      initialFeeAccountBalance - updatedFeeAccountBalance,
+      replenishAmount,
+      "Fee account should fund the replenishment"
+    );
+    assert.equal(
+      updatedRewardPoolBalance - initialRewardPoolBalance,
+      replenishAmount - BASE_REWARD_AMOUNT_IN_LAMPORTS,
+      "Reward pool should be topped up before the reward is paid out"
+    );
+
+    // Restore the default so later tests see an unchanged reward pool
+    const resetConfigTxSignature = await program.methods
+      .setAutoReplenishConfig(new BN(0), new BN(0), new BN(0))
+      .accountsPartial({
+        oracleContractState: oracleContractState.publicKey,
+        adminPubkey: admin.publicKey,
+      })
+      .rpc();
+    await measureComputeUnitsAndStorage(resetConfigTxSignature);
+  });
+});
+
+describe("Contributor Earnings Tracking", () => {
+  it("accumulates the total rewards earned across claims", async () => {
+    const eligibleContributor = contributors[0];
+    const [rewardPoolAccountPDA] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("reward_pool")],
+      program.programId
+    );
+    const [contributorDataAccountPDA] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("contributor_data")],
+      program.programId
+    );
+
+    const getTotalRewardsEarned = async () => {
+      const { events } = await program.methods
+        .getContributor(eligibleContributor.publicKey)
+        .accountsPartial({ contributorDataAccount: contributorDataAccountPDA })
+        .simulate();
+      const detailsEvent = events.find((e) => e.name === "contributorDetails");
+      return detailsEvent.data.contributor.totalRewardsEarnedLamports.toNumber();
+    };
+
+    const initialTotal = await getTotalRewardsEarned();
+
+    for (let i = 0; i < 2; i++) {
+      const requestRewardTxSignature = await program.methods
+        .requestReward(eligibleContributor.publicKey)
+        .accountsPartial({
+          rewardPoolAccount: rewardPoolAccountPDA,
+          oracleContractState: oracleContractState.publicKey,
+          contributorDataAccount: contributorDataAccountPDA,
+          contributor: eligibleContributor.publicKey,
+          systemProgram: web3.SystemProgram.programId,
+        })
+        .rpc();
+      await measureComputeUnitsAndStorage(requestRewardTxSignature);
+    }
+
+    const finalTotal = await getTotalRewardsEarned();
+    assert.equal(
+      finalTotal,
+      initialTotal + 2 * BASE_REWARD_AMOUNT_IN_LAMPORTS,
+      "Total rewards earned should reflect both claims"
+    );
+  });
+});
+
+describe("Reward Claim Pause", () => {
+  it("blocks reward claims while submissions continue", async () => {
+    const contributor = contributors[0];
+    const [rewardPoolAccountPDA] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("reward_pool")],
+      program.programId
+    );
+    const [contributorDataAccountPDA] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("contributor_data")],
+      program.programId
+    );
+
+    const setRewardsPaused = async (paused: boolean) => {
+      const txSignature = await program.methods
+        .setRewardsPaused(paused)
+        .accountsPartial({
+          oracleContractState: oracleContractState.publicKey,
+          adminPubkey: admin.publicKey,
+        })
+        .rpc();
+      await measureComputeUnitsAndStorage(txSignature);
+    };
+
+    await setRewardsPaused(true);
+
+    // Reports are still accepted while payouts are frozen
+    const txid = crypto.randomBytes(32).toString("hex");
+    const seedHash = crypto
+      .createHash("sha256")
+      .update(
+        Buffer.from(
+          "pastel_tx_status_report" + txid + contributor.publicKey.toBase58(),
+          "utf8"
+        )
+      )
+      .digest();
+    const [reportAccountPDA] = web3.PublicKey.findProgramAddressSync(
+      [seedHash],
+      program.programId
+    );
+    const submitTxSignature = await program.methods
+      .submitDataReport(
+        txid,
+        { minedActivated: {} } as unknown as any,
+        { nft: {} } as unknown as any,
+        "abcdef",
+        contributor.publicKey
+      )
+      .accountsPartial({
+        reportAccount: reportAccountPDA,
+        oracleContractState: oracleContractState.publicKey,
+        user: contributor.publicKey,
+      })
+      .signers([contributor])
+      .rpc();
+    await measureComputeUnitsAndStorage(submitTxSignature);
+    const report = await program.account.pastelTxStatusReportAccount.fetch(
+      reportAccountPDA
+    );
+    assert.equal(report.report.txid, txid);
+
+    try {
+      await program.methods
+        .requestReward(contributor.publicKey)
+        .accountsPartial({
+          rewardPoolAccount: rewardPoolAccountPDA,
+          oracleContractState: oracleContractState.publicKey,
+          contributorDataAccount: contributorDataAccountPDA,
+          contributor: contributor.publicKey,
+          systemProgram: web3.SystemProgram.programId,
+        })
+        .rpc();
+      assert.fail("Reward claims should be rejected while paused");
+    } catch (error) {
+      const anchorError = anchor.AnchorError.parse(error.logs);
+      assert.isNotNull(anchorError, `Unexpected error: ${error.toString()}`);
+      assert.equal(anchorError.error.errorCode.code, "RewardsPaused");
+    } finally {
+      await setRewardsPaused(false);
+    }
+  });
+});
+
+describe("Contributor Snapshot", () => {
+  it("snapshots contributor standings at a single slot", async () => {
+    const [contributorDataAccountPDA] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("contributor_data")],
+      program.programId
+    );
+
+    const { events } = await program.methods
+      .snapshotContributors(0, 5)
+      .accountsPartial({ contributorDataAccount: contributorDataAccountPDA })
+      .simulate();
+    const snapshot = events.find((e) => e.name === "contributorSnapshot").data;
+
+    assert.isAbove(snapshot.slot.toNumber(), 0);
+    assert.isAtLeast(snapshot.totalContributors, 5);
+    assert.equal(snapshot.standings.length, 5);
+
+    const registeredAddresses = contributors.map((c) => c.publicKey.toBase58());
+    snapshot.standings.forEach((standing) => {
+      assert.include(registeredAddresses, standing.rewardAddress.toBase58());
+      assert.isTrue(standing.complianceScore.gtn(0));
+      assert.isTrue(standing.reliabilityScore.gtn(0));
+    });
+  });
+});
+
 describe("Request Reward for Ineligible Contributor", () => {
   it("should not allow reward requests from ineligible contributors", async () => {
     // Choose an ineligible contributor
@@ -1124,6 +2373,93 @@ describe("Request Reward for Ineligible Contributor", () => {
   });
 });
 
+describe("Registration Fee Split", () => {
+  it("routes the configured share of the registration fee to the treasury", async () => {
+    const [rewardPoolAccountPDA] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("reward_pool")],
+      program.programId
+    );
+    const [feeReceivingContractAccountPDA] =
+      web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("fee_receiving_contract")],
+        program.programId
+      );
+    const [treasuryAccountPDA] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury")],
+      program.programId
+    );
+    const [contributorDataAccountPDA] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("contributor_data")],
+      program.programId
+    );
+    const registrationFeeLamports = 10_000_000;
+    const setFeeSplit = async (feeSplitBps: number) => {
+      const txSignature = await program.methods
+        .setFeeSplit(feeSplitBps)
+        .accountsPartial({
+          oracleContractState: oracleContractState.publicKey,
+          adminPubkey: admin.publicKey,
+        })
+        .rpc();
+      await measureComputeUnitsAndStorage(txSignature);
+    };
+
+    await setFeeSplit(2_000);
+
+    const transferTxSignature = await provider.sendAndConfirm(
+      new web3.Transaction().add(
+        web3.SystemProgram.transfer({
+          fromPubkey: admin.publicKey,
+          toPubkey: feeReceivingContractAccountPDA,
+          lamports: REGISTRATION_ENTRANCE_FEE_SOL * web3.LAMPORTS_PER_SOL,
+        })
+      )
+    );
+    await measureComputeUnitsAndStorage(transferTxSignature);
+
+    const rewardPoolBalanceBefore = await provider.connection.getBalance(
+      rewardPoolAccountPDA
+    );
+    const treasuryBalanceBefore = await provider.connection.getBalance(
+      treasuryAccountPDA
+    );
+
+    // Registered last so the contributors used by earlier tests are unchanged
+    const contributor = web3.Keypair.generate();
+    const registerTxSignature = await program.methods
+      .registerNewDataContributor()
+      .accountsPartial({
+        contributorDataAccount: contributorDataAccountPDA,
+        contributorAccount: contributor.publicKey,
+        rewardPoolAccount: rewardPoolAccountPDA,
+        feeReceivingContractAccount: feeReceivingContractAccountPDA,
+        treasuryAccount: treasuryAccountPDA,
+        oracleContractState: oracleContractState.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([contributor])
+      .rpc();
+    await measureComputeUnitsAndStorage(registerTxSignature);
+
+    const rewardPoolBalanceAfter = await provider.connection.getBalance(
+      rewardPoolAccountPDA
+    );
+    const treasuryBalanceAfter = await provider.connection.getBalance(
+      treasuryAccountPDA
+    );
+    assert.equal(
+      treasuryBalanceAfter - treasuryBalanceBefore,
+      registrationFeeLamports * 0.2
+    );
+    assert.equal(
+      rewardPoolBalanceAfter - rewardPoolBalanceBefore,
+      registrationFeeLamports * 0.8
+    );
+
+    await setFeeSplit(0);
+  });
+});
+
 // After all tests
 after(async function () {
   console.log(`Total compute units used: ${totalComputeUnitsUsed}`);